@@ -0,0 +1,147 @@
+use super::compat;
+use super::helpers;
+use super::parser::{ Parser };
+use super::parser::lock::{ Lock };
+use super::parser::store::{ Store };
+use super::{CtrlArg, EArgumentsNames, EArgumentsValues};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+mod keys {
+    pub const CHECK_COMPAT: &str = "check-compat";
+    pub const OLD: &str = "--old";
+    pub const NEW: &str = "--new";
+}
+
+pub struct ArgsCheckCompat {
+    _requested: bool,
+    _old: Option<PathBuf>,
+    _new: Option<PathBuf>,
+    _err: Option<String>,
+}
+
+impl ArgsCheckCompat {
+
+    fn parse(&self, src: PathBuf) -> Result<Store, String> {
+        let mut parser: Parser = Parser::new(src.clone());
+        let lock_path: PathBuf = Lock::get_path(&src);
+        if lock_path.exists() {
+            // Lock file is only read to get actual IDs; it's never updated by check
+            parser.set_lock(Lock::load(&lock_path)?);
+        }
+        match parser.parse() {
+            Ok(store) => Ok(store),
+            Err(errs) => Err(format!("Fail to parse {:?}:\n{}", src, errs.iter().map(|e| e.to_string()).collect::<Vec<String>>().join("\n"))),
+        }
+    }
+
+}
+
+impl CtrlArg for ArgsCheckCompat {
+
+    fn new(pwd: &Path, args: Vec<String>, mut _ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> Self {
+        let requested: bool = args.iter().any(|arg| arg == keys::CHECK_COMPAT);
+        let mut old: Option<PathBuf> = None;
+        let mut new: Option<PathBuf> = None;
+        let mut err: Option<String> = None;
+        if let Some(index) = args.iter().position(|arg| arg == keys::OLD) {
+            if let Some(arg_str) = args.get(index + 1) {
+                old = Some(Path::new(pwd).join(arg_str));
+            }
+        }
+        if let Some(index) = args.iter().position(|arg| arg == keys::NEW) {
+            if let Some(arg_str) = args.get(index + 1) {
+                new = Some(Path::new(pwd).join(arg_str));
+            }
+        }
+        if requested {
+            if let (Some(old_path_buf), Some(new_path_buf)) = (old.clone(), new.clone()) {
+                for path_buf in [old_path_buf, new_path_buf].iter() {
+                    if !path_buf.exists() {
+                        err = Some(format!(
+                            "Protocol file doesn't exist. Path: {}",
+                            path_buf.as_path().display()
+                        ));
+                    }
+                }
+            } else {
+                err = Some(format!("Both versions of protocol have to be defined. Use keys {} and {} to set files", keys::OLD, keys::NEW));
+            }
+        }
+        ArgsCheckCompat {
+            _requested: requested,
+            _old: old,
+            _new: new,
+            _err: err,
+        }
+    }
+
+    fn name(&self) -> EArgumentsNames {
+        EArgumentsNames::CheckCompat
+    }
+
+    fn value(&self) -> EArgumentsValues {
+        EArgumentsValues::CheckCompat(self._requested)
+    }
+
+    fn get_err(&self) -> Option<String> {
+        self._err.clone()
+    }
+
+    fn is_action_available(&self) -> bool {
+        self._requested && self._err.is_none()
+    }
+
+    fn action(&self, mut _ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> Result<(), String> {
+        if !self.is_action_available() {
+            return Ok(());
+        }
+        if let (Some(old), Some(new)) = (self._old.clone(), self._new.clone()) {
+            let old_store = self.parse(old)?;
+            let new_store = self.parse(new)?;
+            let changes = compat::check(&old_store, &new_store);
+            for change in changes.iter() {
+                println!("{}", change);
+            }
+            let breaking = changes.iter().filter(|c| c.is_breaking()).count();
+            if breaking > 0 {
+                Err(format!("Found {} breaking change(s)", breaking))
+            } else {
+                println!("[OK] protocols are compatible");
+                Ok(())
+            }
+        } else {
+            Err(String::from("protocol files aren't defined"))
+        }
+    }
+
+    fn get_help(&self) -> String {
+        format!("{}{}",
+            helpers::output::keys(&format!("{} {} <file> {} <file>", keys::CHECK_COMPAT, keys::OLD, keys::NEW)),
+            helpers::output::desk("checks compatibility of two versions of protocol. Exits with error if breaking changes were found."),
+        )
+    }
+
+}
+
+pub fn get_cleaner() -> impl Fn(Vec<String>) -> Vec<String> {
+    move |mut args: Vec<String>| {
+        if let Some(index) = args.iter().position(|arg| arg == keys::CHECK_COMPAT) {
+            args.remove(index);
+        }
+        for key in [keys::OLD, keys::NEW].iter() {
+            if let Some(index) = args.iter().position(|arg| arg == key) {
+                match args.get(index + 1) {
+                    Some(_) => {
+                        args.remove(index + 1);
+                        args.remove(index);
+                    }
+                    None => {
+                        args.remove(index);
+                    }
+                }
+            }
+        }
+        args
+    }
+}
@@ -1,6 +1,8 @@
 use super::helpers;
 use super::parser::{ Parser };
+use super::parser::lock::{ Lock };
 use super::parser::store::{ Store };
+use super::render::producer::ProducerRender;
 use super::render::rust::RustRender;
 use super::render::typescript::TypescriptRender;
 use super::render::Render;
@@ -21,12 +23,16 @@ mod keys {
     pub const DESTINATION_TS: &str = "--destination-ts";
     pub const DEST_TS: &str = "--dest-ts";
     pub const TS: &str = "-ts";
+    pub const DESTINATION_PRODUCER_RS: &str = "--destination-producer-rs";
+    pub const DEST_PRODUCER_RS: &str = "--dest-producer-rs";
+    pub const PRS: &str = "-prs";
 }
 
 pub struct ArgsOptionFiles {
     _src: Option<PathBuf>,
     _dest_rs: Option<PathBuf>,
     _dest_ts: Option<PathBuf>,
+    _dest_producer_rs: Option<PathBuf>,
     _err: Option<String>,
 }
 
@@ -56,12 +62,37 @@ impl ArgsOptionFiles {
         }
     }
 
+    fn get_no_lock_flag(&self, ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> bool {
+        if let Some(arg) = ctrls.get(&EArgumentsNames::OptionNoLock) {
+            if let EArgumentsValues::OptionNoLock(no_lock) = arg.value() {
+                no_lock
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
+    fn get_check_compat_flag(&self, ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> bool {
+        if let Some(arg) = ctrls.get(&EArgumentsNames::CheckCompat) {
+            if let EArgumentsValues::CheckCompat(requested) = arg.value() {
+                requested
+            } else {
+                false
+            }
+        } else {
+            false
+        }
+    }
+
     fn write(&self, dest: PathBuf, store: Store, render: impl Render) -> Result<(), String> {
         let t_render = Instant::now();
         let content: String = render.render(store);
         match OpenOptions::new()
             .write(true)
             .create(true)
+            .truncate(true)
             .open(dest.clone())
         {
             Ok(mut file) => {
@@ -91,6 +122,7 @@ impl CtrlArg for ArgsOptionFiles {
         let mut src: Option<PathBuf> = None;
         let mut dest_rs: Option<PathBuf> = None;
         let mut dest_ts: Option<PathBuf> = None;
+        let mut dest_producer_rs: Option<PathBuf> = None;
         let mut err: Option<String> = None;
         if let Some(src_index) = args
             .iter()
@@ -116,18 +148,26 @@ impl CtrlArg for ArgsOptionFiles {
                 dest_ts = Some(Path::new(pwd).join(arg_str_dest));
             }
         }
+        if let Some(dest_index) = args
+            .iter()
+            .position(|arg| arg == keys::DESTINATION_PRODUCER_RS || arg == keys::DEST_PRODUCER_RS || arg == keys::PRS)
+        {
+            if let Some(arg_str_dest) = args.get(dest_index + 1) {
+                dest_producer_rs = Some(Path::new(pwd).join(arg_str_dest));
+            }
+        }
         if src.is_none() {
             err = Some("Source filename has to be defined. Use key --source (--src or -s) to set source file".to_string());
         }
         if let (Some(src_path_buf), Some(dest_rs_path_buf), Some(dest_ts_path_buf)) = (
-            src.clone().take(),
-            dest_rs.clone().take(),
-            dest_ts.clone().take(),
+            src.clone(),
+            dest_rs.clone(),
+            dest_ts.clone(),
         ) {
             if !src_path_buf.exists() {
                 err = Some(format!(
                     "Source file doesn't exist. Path: {}",
-                    src_path_buf.as_path().display().to_string()
+                    src_path_buf.as_path().display()
                 ));
             }
             let mut overwrite: bool = false;
@@ -138,8 +178,8 @@ impl CtrlArg for ArgsOptionFiles {
             }
             if !overwrite && (dest_rs_path_buf.exists() || dest_ts_path_buf.exists()) {
                 err = Some(format!("File(s) already exist. Use key --overwrite (--ow or -o) to overwrite destination file. Files: \n{}\n{}",
-                    dest_rs_path_buf.as_path().display().to_string(),
-                    dest_ts_path_buf.as_path().display().to_string(),
+                    dest_rs_path_buf.as_path().display(),
+                    dest_ts_path_buf.as_path().display(),
                 ));
             }
         }
@@ -147,6 +187,7 @@ impl CtrlArg for ArgsOptionFiles {
             _src: src,
             _dest_rs: dest_rs,
             _dest_ts: dest_ts,
+            _dest_producer_rs: dest_producer_rs,
             _err: err,
         }
     }
@@ -180,11 +221,19 @@ impl CtrlArg for ArgsOptionFiles {
         &self,
         ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>,
     ) -> Result<(), String> {
+        let help: bool = ctrls.get(&EArgumentsNames::OptionHelp).is_some_and(|arg| arg.is_action_available());
+        if help || self.get_check_compat_flag(ctrls) {
+            return Ok(());
+        }
         if let Some(src) = self._src.clone() {
             let t_parsing = Instant::now();
             let overwrite: bool = self.get_overwrite_flag(ctrls);
             let embedded: bool = self.get_embedded_flag(ctrls);
+            let lock_path: PathBuf = Lock::get_path(&src);
             let mut parser: Parser = Parser::new(src.clone());
+            if !self.get_no_lock_flag(ctrls) {
+                parser.set_lock(Lock::load(&lock_path)?);
+            }
             match parser.parse() {
                 Ok(store) => {
                     println!(
@@ -192,6 +241,10 @@ impl CtrlArg for ArgsOptionFiles {
                         t_parsing.elapsed().as_millis(),
                         src
                     );
+                    if let Some(lock) = parser.get_lock() {
+                        lock.save(&lock_path)?;
+                        println!("[OK] saved {:?}", lock_path);
+                    }
                     if let Some(dest) = self._dest_rs.clone() {
                         if dest.exists() && !overwrite {
                             return Err(format!("File {:?} exists. Use key \"overwrite\" to overwrite file. -h to get more info", dest));
@@ -209,9 +262,7 @@ impl CtrlArg for ArgsOptionFiles {
                                 );
                             }
                         }
-                        if let Err(e) = self.write(dest, store.clone(), RustRender::new(embedded, 0)) {
-                            return Err(e);
-                        }
+                        self.write(dest, store.clone(), RustRender::new(embedded, 0))?
                     }
                     if let Some(dest) = self._dest_ts.clone() {
                         if dest.exists() && !overwrite {
@@ -230,13 +281,30 @@ impl CtrlArg for ArgsOptionFiles {
                                 );
                             }
                         }
-                        if let Err(e) = self.write(dest, store, TypescriptRender::new(embedded, 0)) {
-                            return Err(e);
+                        self.write(dest, store.clone(), TypescriptRender::new(embedded, 0))?
+                    }
+                    if let Some(dest) = self._dest_producer_rs.clone() {
+                        if dest.exists() && !overwrite {
+                            return Err(format!("File {:?} exists. Use key \"overwrite\" to overwrite file. -h to get more info", dest));
+                        } else if dest.exists() {
+                            println!(
+                                "[INFO] {:?} will be overwritten",
+                                dest
+                            );
+                            if let Err(err) = remove_file(dest.clone()) {
+                                return Err(format!("Fail to remove file {:?} due error: {}", dest, err));
+                            } else {
+                                println!(
+                                    "[INFO] {:?} clean",
+                                    dest
+                                );
+                            }
                         }
+                        self.write(dest, store, ProducerRender::new(embedded, 0))?
                     }
                     Ok(())
                 }
-                Err(errs) => Err(errs.join("\n")),
+                Err(errs) => Err(errs.iter().map(|e| e.to_string()).collect::<Vec<String>>().join("\n")),
             }
         } else {
             Err(String::from("protocol file isn't defined"))
@@ -244,19 +312,15 @@ impl CtrlArg for ArgsOptionFiles {
     }
 
     fn get_help(&self) -> String {
-        format!("{}\n{}\n{}",
-            format!("{}{}",
-                helpers::output::keys(&format!("{} ({}, {})", keys::SOURCE, keys::SRC, keys::S)),
-                helpers::output::desk("[required] path to source file. Protocol file with description messages."),
-            ),
-            format!("{}{}",
-                helpers::output::keys(&format!("{} ({}, {})", keys::DESTINATION_RS, keys::DEST_RS, keys::RS)),
-                helpers::output::desk("path to destination rs (rust) file. If value isn't defined, would be used path and name of source file"),
-            ),
-            format!("{}{}",
-                helpers::output::keys(&format!("{} ({}, {})", keys::DESTINATION_TS, keys::DEST_TS, keys::TS)),
-                helpers::output::desk("path to destination ts (typescript) file. If value isn't defined, would be used path and name of source file"),
-            )
+        format!("{}{}\n{}{}\n{}{}\n{}{}",
+            helpers::output::keys(&format!("{} ({}, {})", keys::SOURCE, keys::SRC, keys::S)),
+            helpers::output::desk("[required] path to source file. Protocol file with description messages."),
+            helpers::output::keys(&format!("{} ({}, {})", keys::DESTINATION_RS, keys::DEST_RS, keys::RS)),
+            helpers::output::desk("path to destination rs (rust) file. If value isn't defined, would be used path and name of source file"),
+            helpers::output::keys(&format!("{} ({}, {})", keys::DESTINATION_TS, keys::DEST_TS, keys::TS)),
+            helpers::output::desk("path to destination ts (typescript) file. If value isn't defined, would be used path and name of source file"),
+            helpers::output::keys(&format!("{} ({}, {})", keys::DESTINATION_PRODUCER_RS, keys::DEST_PRODUCER_RS, keys::PRS)),
+            helpers::output::desk("path to destination rs (rust) file with producer's observers of relations. Isn't generated if value isn't defined"),
         )
     }
 
@@ -306,6 +370,20 @@ pub fn get_cleaner() -> impl Fn(Vec<String>) -> Vec<String> {
                 }
             }
         }
+        if let Some(index) = args
+            .iter()
+            .position(|arg| arg == keys::DESTINATION_PRODUCER_RS || arg == keys::DEST_PRODUCER_RS || arg == keys::PRS)
+        {
+            match args.get(index + 1) {
+                Some(_) => {
+                    args.remove(index + 1);
+                    args.remove(index);
+                }
+                None => {
+                    args.remove(index);
+                }
+            }
+        }
         args
     }
 }
@@ -0,0 +1,59 @@
+use std::path::{ Path };
+use std::collections::{ HashMap };
+use super::{ CtrlArg, EArgumentsNames, EArgumentsValues };
+use super:: { helpers };
+
+mod keys {
+    pub const NO_LOCK: &str = "--no-lock";
+    pub const NL: &str = "--nl";
+}
+
+pub struct ArgsOptionNoLock {
+    _no_lock: bool,
+}
+
+impl CtrlArg for ArgsOptionNoLock {
+
+    fn new(_pwd: &Path, args: Vec<String>, mut _ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> Self {
+        ArgsOptionNoLock {
+            _no_lock: args.iter().any(|arg| arg == keys::NO_LOCK || arg == keys::NL)
+        }
+    }
+
+    fn name(&self) -> EArgumentsNames {
+        EArgumentsNames::OptionNoLock
+    }
+
+    fn value(&self) -> EArgumentsValues {
+        EArgumentsValues::OptionNoLock(self._no_lock)
+    }
+
+    fn get_err(&self) -> Option<String> {
+        None
+    }
+
+    fn is_action_available(&self) -> bool {
+        false
+    }
+
+    fn action(&self, mut _ctrls: &HashMap<EArgumentsNames, Box<dyn CtrlArg + 'static>>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn get_help(&self) -> String {
+        format!("{}{}",
+            helpers::output::keys(&format!("{} ({})", keys::NO_LOCK, keys::NL)),
+            helpers::output::desk("if key exist, lock file (<source>.lock) with IDs of entities wouldn't be read and updated. Default: false"),
+        )
+    }
+
+}
+
+pub fn get_cleaner() -> impl Fn(Vec<String>) -> Vec<String> {
+    move |mut args: Vec<String>| {
+        if let Some(index) = args.iter().position(|arg| arg == keys::NO_LOCK || arg == keys::NL) {
+            args.remove(index);
+        }
+        args
+    }
+}
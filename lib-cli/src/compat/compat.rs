@@ -0,0 +1,208 @@
+use super::parser::enums::{ Enum, EnumItem };
+use super::parser::fields::{ Field };
+use super::parser::store::{ Store };
+use super::parser::structs::{ Struct };
+use super::parser::types::{ PrimitiveTypes };
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EChange {
+    // Change, which makes impossible to decode messages between old and new versions
+    Breaking(String),
+    // Change, which old version can live with
+    Additive(String),
+}
+
+impl EChange {
+
+    pub fn is_breaking(&self) -> bool {
+        matches!(self, EChange::Breaking(_))
+    }
+
+}
+
+impl std::fmt::Display for EChange {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EChange::Breaking(msg) => write!(f, "[BREAKING] {}", msg),
+            EChange::Additive(msg) => write!(f, "[WARN] {}", msg),
+        }
+    }
+
+}
+
+pub fn check(old: &Store, new: &Store) -> Vec<EChange> {
+    let mut changes: Vec<EChange> = vec![];
+    check_groups(old, new, &mut changes);
+    for old_strct in old.structs.iter() {
+        let path = old.get_struct_path(old_strct.id).join(".");
+        if let Some(new_strct) = new.structs.iter().find(|s| new.get_struct_path(s.id).join(".") == path) {
+            check_struct(&path, (old, old_strct), (new, new_strct), &mut changes);
+        } else {
+            changes.push(EChange::Breaking(format!("struct {} was removed", path)));
+        }
+    }
+    for new_strct in new.structs.iter() {
+        let path = new.get_struct_path(new_strct.id).join(".");
+        if !old.structs.iter().any(|s| old.get_struct_path(s.id).join(".") == path) {
+            changes.push(EChange::Additive(format!("struct {} was added", path)));
+        }
+    }
+    for old_enum in old.enums.iter() {
+        let path = old.get_enum_path(old_enum.id).join(".");
+        if let Some(new_enum) = new.enums.iter().find(|e| new.get_enum_path(e.id).join(".") == path) {
+            check_enum(&path, (old, old_enum), (new, new_enum), &mut changes);
+        } else {
+            changes.push(EChange::Breaking(format!("enum {} was removed", path)));
+        }
+    }
+    for new_enum in new.enums.iter() {
+        let path = new.get_enum_path(new_enum.id).join(".");
+        if !old.enums.iter().any(|e| old.get_enum_path(e.id).join(".") == path) {
+            changes.push(EChange::Additive(format!("enum {} was added", path)));
+        }
+    }
+    changes
+}
+
+// Scopes of structs aren't compared: changes of nested structs and enums are reported by themselves
+fn check_groups(old: &Store, new: &Store, changes: &mut Vec<EChange>) {
+    let old_paths: Vec<String> = old.groups.iter().filter(|g| g.owner.is_none()).map(|g| old.get_group_path(g.id).join(".")).collect();
+    let new_paths: Vec<String> = new.groups.iter().filter(|g| g.owner.is_none()).map(|g| new.get_group_path(g.id).join(".")).collect();
+    for path in old_paths.iter() {
+        if !new_paths.contains(path) {
+            changes.push(EChange::Breaking(format!("group {} was removed or renamed", path)));
+        }
+    }
+    for path in new_paths.iter() {
+        if !old_paths.contains(path) {
+            changes.push(EChange::Additive(format!("group {} was added", path)));
+        }
+    }
+}
+
+fn check_struct(path: &str, old: (&Store, &Struct), new: (&Store, &Struct), changes: &mut Vec<EChange>) {
+    let ((old_store, old_strct), (new_store, new_strct)) = (old, new);
+    if old_strct.wire_id != new_strct.wire_id {
+        changes.push(EChange::Breaking(format!("struct {}: ID changed from {} to {}", path, old_strct.wire_id, new_strct.wire_id)));
+    }
+    for old_field in old_strct.fields.iter() {
+        let field_path = format!("{}.{}", path, old_field.name);
+        let new_field = if let Some(field) = new_strct.fields.iter().find(|f| f.name == old_field.name) {
+            field
+        } else {
+            changes.push(EChange::Breaking(format!("field {} was removed", field_path)));
+            continue;
+        };
+        if old_field.wire_id != new_field.wire_id {
+            changes.push(EChange::Breaking(format!("field {}: ID changed from {} to {}", field_path, old_field.wire_id, new_field.wire_id)));
+        }
+        let (old_type, new_type) = (get_field_type(old_store, old_field), get_field_type(new_store, new_field));
+        if old_type != new_type {
+            changes.push(EChange::Breaking(format!("field {}: type changed from {} to {}", field_path, old_type, new_type)));
+        }
+        if old_field.optional != new_field.optional {
+            changes.push(EChange::Breaking(format!("field {}: became {}", field_path, if new_field.optional { "optional" } else { "required" })));
+        }
+        if old_field.repeated != new_field.repeated {
+            changes.push(EChange::Breaking(format!("field {}: {}", field_path, if new_field.repeated { "became repeated" } else { "isn't repeated anymore" })));
+        }
+        if old_field.optional_items != new_field.optional_items {
+            changes.push(EChange::Breaking(format!("field {}: items became {}", field_path, if new_field.optional_items { "optional" } else { "required" })));
+        }
+        // Size of fixed-size array defines size of value on wire
+        match (old_field.fixed, new_field.fixed) {
+            (Some(old_size), Some(new_size)) if old_size != new_size => {
+                changes.push(EChange::Breaking(format!("field {}: size of fixed-size array changed from {} to {}", field_path, old_size, new_size)));
+            }
+            (None, Some(size)) => changes.push(EChange::Breaking(format!("field {}: became fixed-size array of {} items", field_path, size))),
+            (Some(_), None) => changes.push(EChange::Breaking(format!("field {}: isn't fixed-size array anymore", field_path))),
+            _ => {}
+        }
+    }
+    for new_field in new_strct.fields.iter() {
+        if old_strct.fields.iter().any(|f| f.name == new_field.name) {
+            continue;
+        }
+        let field_path = format!("{}.{}", path, new_field.name);
+        if new_field.optional {
+            changes.push(EChange::Additive(format!("field {} was added", field_path)));
+        } else {
+            // Messages from old version don't have this field at all
+            changes.push(EChange::Breaking(format!("field {} was added as required", field_path)));
+        }
+    }
+}
+
+fn check_enum(path: &str, old: (&Store, &Enum), new: (&Store, &Enum), changes: &mut Vec<EChange>) {
+    let ((old_store, old_enum), (new_store, new_enum)) = (old, new);
+    if old_enum.wire_id != new_enum.wire_id {
+        changes.push(EChange::Breaking(format!("enum {}: ID changed from {} to {}", path, old_enum.wire_id, new_enum.wire_id)));
+    }
+    if old_enum.numeric != new_enum.numeric {
+        // Items of numeric enum are encoded as u16 instead of variant with payload
+        changes.push(EChange::Breaking(format!("enum {}: {}", path, if new_enum.numeric { "became numeric" } else { "isn't numeric anymore" })));
+    }
+    for old_item in old_enum.variants.iter() {
+        let item_path = format!("{}.{}", path, old_item.name);
+        let new_item = if let Some(item) = new_enum.variants.iter().find(|i| i.name == old_item.name) {
+            item
+        } else {
+            changes.push(EChange::Breaking(format!("enum item {} was removed", item_path)));
+            continue;
+        };
+        if old_item.wire_id != new_item.wire_id {
+            changes.push(EChange::Breaking(format!("enum item {}: ID changed from {} to {}", item_path, old_item.wire_id, new_item.wire_id)));
+        }
+        let (old_type, new_type) = (get_item_type(old_store, old_item), get_item_type(new_store, new_item));
+        if old_type != new_type {
+            changes.push(EChange::Breaking(format!("enum item {}: type changed from {} to {}", item_path, old_type, new_type)));
+        }
+        if old_item.repeated != new_item.repeated {
+            changes.push(EChange::Breaking(format!("enum item {}: {}", item_path, if new_item.repeated { "became repeated" } else { "isn't repeated anymore" })));
+        }
+        if old_item.optional != new_item.optional {
+            changes.push(EChange::Breaking(format!("enum item {}: became {}", item_path, if new_item.optional { "optional" } else { "required" })));
+        }
+        if old_item.optional_items != new_item.optional_items {
+            changes.push(EChange::Breaking(format!("enum item {}: items became {}", item_path, if new_item.optional_items { "optional" } else { "required" })));
+        }
+    }
+    for new_item in new_enum.variants.iter() {
+        if !old_enum.variants.iter().any(|i| i.name == new_item.name) {
+            changes.push(EChange::Additive(format!("enum item {}.{} was added", path, new_item.name)));
+        }
+    }
+}
+
+fn get_ref_type(store: &Store, ref_type_id: usize) -> String {
+    if store.get_struct(ref_type_id).is_some() {
+        store.get_struct_path(ref_type_id).join(".")
+    } else {
+        store.get_enum_path(ref_type_id).join(".")
+    }
+}
+
+fn get_field_type(store: &Store, field: &Field) -> String {
+    let kind = if let Some(ref_type_id) = field.ref_type_id.or(field.numeric) {
+        get_ref_type(store, ref_type_id)
+    } else {
+        field.kind.clone()
+    };
+    if let Some(key) = field.key.as_ref() {
+        let value = field.value_keys.iter().rev().fold(kind, |value, key| format!("map<{}, {}>", key, value));
+        format!("map<{}, {}>", key, value)
+    } else {
+        kind
+    }
+}
+
+fn get_item_type(store: &Store, item: &EnumItem) -> String {
+    if let Some(ref_type_id) = item.ref_type_id {
+        get_ref_type(store, ref_type_id)
+    } else if let Some(types) = item.types.clone() {
+        PrimitiveTypes::get_entity_as_string(types).unwrap_or_default()
+    } else {
+        String::new()
+    }
+}
@@ -1,18 +1,17 @@
 use std::env;
 use std::path::{ PathBuf, Path };
 use std::collections::{ HashMap };
-use super:: { helpers, stop };
-
-#[path = "./parser/parser.rs"]
-pub mod parser;
-#[path = "./render/render.rs"]
-pub mod render;
+use super:: { compat, helpers, parser, render };
 #[path = "./arguments/ctrl.args.option.files.rs"]
 pub mod arg_option_files;
 #[path = "./arguments/ctrl.args.option.overwrite.rs"]
 pub mod arg_option_overwrite;
 #[path = "./arguments/ctrl.args.option.embedded.rs"]
 pub mod arg_option_embedded;
+#[path = "./arguments/ctrl.args.option.nolock.rs"]
+pub mod arg_option_no_lock;
+#[path = "./arguments/ctrl.args.option.compat.rs"]
+pub mod arg_check_compat;
 #[path = "./arguments/ctrl.args.option.help.rs"]
 pub mod arg_option_help;
 
@@ -21,13 +20,17 @@ pub enum EArgumentsNames {
     OptionFiles,
     OptionOverwrite,
     OptionEmbedded,
+    OptionNoLock,
     OptionHelp,
+    CheckCompat,
 }
 
 pub enum EArgumentsValues {
     Files((PathBuf, PathBuf, PathBuf)),
     OptionOverwrite(bool),
     OptionEmbedded(bool),
+    OptionNoLock(bool),
+    CheckCompat(bool),
     Empty(()),
 }
 pub trait CtrlArg {
@@ -49,6 +52,7 @@ pub struct CtrlArgs {
 pub type TCleaner = Box<dyn Fn(Vec<String>) -> Vec<String>>;
 
 #[allow(clippy::new_without_default)]
+#[allow(clippy::result_unit_err)]
 impl CtrlArgs {
 
     pub fn new() -> Self {
@@ -74,6 +78,14 @@ impl CtrlArgs {
             EArgumentsNames::OptionEmbedded, 
             Box::new(arg_option_embedded::ArgsOptionEmbedded::new(&pwd, args.clone(), &ctrls))
         );
+        ctrls.insert(
+            EArgumentsNames::OptionNoLock, 
+            Box::new(arg_option_no_lock::ArgsOptionNoLock::new(&pwd, args.clone(), &ctrls))
+        );
+        ctrls.insert(
+            EArgumentsNames::CheckCompat, 
+            Box::new(arg_check_compat::ArgsCheckCompat::new(&pwd, args.clone(), &ctrls))
+        );
         ctrls.insert(
             EArgumentsNames::OptionFiles, 
             Box::new(arg_option_files::ArgsOptionFiles::new(&pwd, args, &ctrls))
@@ -95,6 +107,16 @@ impl CtrlArgs {
                     return Ok(());
                 }
             }
+            if let Some(ctrl) = self._ctrls.get(&EArgumentsNames::CheckCompat) {
+                if let EArgumentsValues::CheckCompat(true) = ctrl.as_ref().value() {
+                    // Checking of compatibility doesn't need any other keys
+                    if let Some(err) = ctrl.as_ref().get_err() {
+                        println!("{}", err);
+                        return Err(());
+                    }
+                    return Ok(());
+                }
+            }
             for ctrl in self._ctrls.values() {
                 if let Some(err) = ctrl.as_ref().get_err() {
                     errors = true;
@@ -131,10 +153,12 @@ impl CtrlArgs {
             match ctrl.as_ref().value() {
                 EArgumentsValues::OptionOverwrite(ow) => println!("{:?} = {}", EArgumentsNames::OptionOverwrite, ow),
                 EArgumentsValues::OptionEmbedded(em) => println!("{:?} = {}", EArgumentsNames::OptionEmbedded, em),
+                EArgumentsValues::OptionNoLock(nl) => println!("{:?} = {}", EArgumentsNames::OptionNoLock, nl),
+                EArgumentsValues::CheckCompat(cc) => println!("{:?} = {}", EArgumentsNames::CheckCompat, cc),
                 EArgumentsValues::Files((src, dest_rs, dest_ts)) => {
-                    println!("{:?}: src = {}", EArgumentsNames::OptionFiles, src.as_path().display().to_string());
-                    println!("{:?}: dest_rs = {}", EArgumentsNames::OptionFiles, dest_rs.as_path().display().to_string());
-                    println!("{:?}: dest_ts = {}", EArgumentsNames::OptionFiles, dest_ts.as_path().display().to_string());
+                    println!("{:?}: src = {}", EArgumentsNames::OptionFiles, src.as_path().display());
+                    println!("{:?}: dest_rs = {}", EArgumentsNames::OptionFiles, dest_rs.as_path().display());
+                    println!("{:?}: dest_ts = {}", EArgumentsNames::OptionFiles, dest_ts.as_path().display());
                 },
                 _ => println!("Empty value has been found"),
             }
@@ -151,6 +175,8 @@ impl CtrlArgs {
             Box::new(arg_option_help::get_cleaner()),
             Box::new(arg_option_embedded::get_cleaner()),
             Box::new(arg_option_overwrite::get_cleaner()),
+            Box::new(arg_option_no_lock::get_cleaner()),
+            Box::new(arg_check_compat::get_cleaner()),
             Box::new(arg_option_files::get_cleaner()),
         ];
         for cleaner in cleaners {
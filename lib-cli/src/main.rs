@@ -1,3 +1,6 @@
+#[path = "./compat/compat.rs"]
+pub mod compat;
+
 #[path = "./ctrl.args.rs"]
 pub mod ctrlargs;
 
@@ -26,15 +29,26 @@ fn main() {
         Err(_) => std::process::exit(1),
     }
     if let Err(errors) = ctrl.actions() {
-        println!("{}", errors.join("\n"))
+        println!("{}", errors.join("\n"));
+        std::process::exit(1);
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::compat::{ self, EChange };
     use super::parser::{ Parser };
+    use super::parser::constraints::{ Constraint };
+    use super::parser::error::{ ParseError };
+    use super::parser::lock::{ Lock };
+    use super::parser::span::{ Location, Span };
+    use super::parser::values::{ EValue };
+    use super::render::producer::{ ProducerRender };
     use super::render::rust::{ RustRender };
+    use super::render::typescript::{ TypescriptRender };
     use super::render::{ Render };
+    use std::fs;
+    use std::path::PathBuf;
 
     #[test]
     fn parsing() {
@@ -45,7 +59,7 @@ mod tests {
                 match parser.parse() {
                     Ok(store) => {
                         // println!("{:?}", store.groups);
-                        let rust_render: RustRender = RustRender::new(true);
+                        let rust_render: RustRender = RustRender::new(true, 0);
                         println!("{}", rust_render.render(store));
                         assert_eq!(true, true);
                     },
@@ -58,4 +72,1042 @@ mod tests {
         }
     }
 
+    #[test]
+    fn explicit_ids() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_ids.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let user_type = store.enums.iter().find(|e| e.name == "UserType").unwrap();
+                assert_eq!(user_type.wire_id, 200);
+                let items: Vec<usize> = user_type.variants.iter().map(|v| v.wire_id).collect();
+                assert_eq!(items, vec![10, 1, 2]);
+                let user = store.structs.iter().find(|s| s.name == "User").unwrap();
+                assert_eq!(user.wire_id, 120);
+                let fields: Vec<usize> = user.fields.iter().map(|f| f.wire_id).collect();
+                assert_eq!(fields[0], 4);
+                assert_eq!(fields[2], 1);
+                assert!(!fields[1..2].contains(&4) && !fields[1..2].contains(&1));
+                let login = store.structs.iter().find(|s| s.name == "Login").unwrap();
+                assert!(login.wire_id != 120 && login.wire_id != 200);
+                assert_eq!(login.fields[1].wire_id, 0);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store);
+                assert!(output.contains("fn get_id(&self) -> u32 { 120 }"));
+                assert!(output.contains("Some(4)"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_ids_duplicate.prot"));
+                assert!(parser.parse().is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn lock() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_lock.prot"));
+                parser.set_lock(Lock::new());
+                let before = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let lock = Lock::parse_content(&parser.get_lock().unwrap().to_string()).unwrap();
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_lock_changed.prot"));
+                parser.set_lock(lock);
+                let after = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let user_before = before.structs.iter().find(|s| s.name == "User").unwrap();
+                let user_after = after.structs.iter().find(|s| s.name == "User").unwrap();
+                let session = after.structs.iter().find(|s| s.name == "Session").unwrap();
+                assert_eq!(user_before.wire_id, user_after.wire_id);
+                assert!(session.wire_id > before.structs.iter().map(|s| s.wire_id).chain(before.enums.iter().map(|e| e.wire_id)).max().unwrap());
+                let id_of = |strct: &super::parser::structs::Struct, name: &str| strct.fields.iter().find(|f| f.name == name).unwrap().wire_id;
+                assert_eq!(id_of(user_before, "name"), id_of(user_after, "name"));
+                assert_eq!(id_of(user_before, "email"), id_of(user_after, "email"));
+                assert!(id_of(user_after, "login") > id_of(user_before, "age"));
+                let kind_before = before.enums.iter().find(|e| e.name == "Kind").unwrap();
+                let kind_after = after.enums.iter().find(|e| e.name == "Kind").unwrap();
+                assert_eq!(kind_before.wire_id, kind_after.wire_id);
+                let items: Vec<usize> = kind_after.variants.iter().map(|v| v.wire_id).collect();
+                assert_eq!(items, vec![2, 0, 1]);
+                let output = parser.get_lock().unwrap().to_string();
+                assert!(output.lines().any(|l| l.starts_with("field User.age = ") && l.ends_with(" removed")));
+            }
+        }
+    }
+
+    #[test]
+    fn check_compat() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_compat_old.prot"));
+                let old = parser.parse().unwrap();
+                assert!(compat::check(&old, &old).is_empty());
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_compat_new.prot"));
+                let new = parser.parse().unwrap();
+                let changes: Vec<String> = compat::check(&old, &new).iter().map(|c| c.to_string()).collect();
+                for expected in [
+                    "[BREAKING] group Accounts was removed or renamed",
+                    "[BREAKING] struct Accounts.User was removed",
+                    "[BREAKING] enum Accounts.Kind was removed",
+                    "[WARN] struct Users.User was added",
+                ].iter() {
+                    assert!(changes.contains(&expected.to_string()), "{:?}", changes);
+                }
+                // Same schema inside of renamed group
+                let mut new = new;
+                new.groups[0].name = String::from("Accounts");
+                let changes = compat::check(&old, &new);
+                for expected in [
+                    EChange::Breaking(String::from("field Accounts.User.age: type changed from u8 to u16")),
+                    EChange::Breaking(String::from("field Accounts.User.email: became required")),
+                    EChange::Breaking(String::from("field Accounts.User.roles: isn't repeated anymore")),
+                    EChange::Breaking(String::from("field Accounts.User.phone was removed")),
+                    EChange::Additive(String::from("field Accounts.User.nick was added")),
+                    EChange::Breaking(String::from("enum item Accounts.Kind.Guest was removed")),
+                    EChange::Additive(String::from("enum item Accounts.Kind.Root was added")),
+                    EChange::Breaking(String::from("enum Accounts.Level: became numeric")),
+                    EChange::Breaking(String::from("field Accounts.User.code: size of fixed-size array changed from 4 to 8")),
+                    EChange::Breaking(String::from("field Accounts.User.hash: isn't fixed-size array anymore")),
+                ].iter() {
+                    assert!(changes.contains(expected), "{:?}", changes);
+                }
+                assert_eq!(changes.len(), 10);
+            }
+        }
+    }
+
+    #[test]
+    fn error_location() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_error.prot"));
+                match parser.parse() {
+                    Ok(_) => panic!("protocol_error.prot should not be parsed"),
+                    Err(errs) => {
+                        let output = errs[0].to_string();
+                        let lines: Vec<&str> = output.lines().collect();
+                        assert!(lines[0].starts_with("error: "));
+                        assert!(lines[1].ends_with("protocol_error.prot:4:5"));
+                        assert_eq!(lines[3], "4 |     str 1mail;");
+                        assert_eq!(lines[4], "  |     ^^^");
+                    }
+                }
+                // Column is counted in chars, not in bytes
+                let content = "struct Имя {\n    str имя 1x;\n}";
+                let from = content.find("1x").unwrap();
+                let location = Location::new(PathBuf::from("a.prot"), content, &Span::new(from, from + 2));
+                assert_eq!((location.line, location.column, location.len), (2, 13, 2));
+                // Errors, which are found after parsing, point to declaration of entity
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_ids_duplicate.prot"));
+                let errs = parser.parse().err().unwrap();
+                let location = errs[0].location().unwrap();
+                assert_eq!((location.line, location.column), (1, 8));
+            }
+        }
+    }
+
+    #[test]
+    fn literal_errors() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_literal_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                // Rest of literal with invalid escape isn't parsed as code
+                let lines: Vec<(usize, usize)> = errs.iter().map(|e| {
+                    let location = e.location().unwrap();
+                    (location.line, location.column)
+                }).collect();
+                assert_eq!(lines, vec![(2, 31), (7, 25), (8, 11)]);
+                let output: Vec<String> = errs.iter().map(|e| e.to_string()).collect();
+                assert!(output[0].starts_with("error: Not supported char(s): unknown escape sequence: \\d\n"));
+                assert!(output[2].starts_with("error: Not supported char(s): found not supportable char: $\n"));
+                assert!(output.iter().all(|e| e.lines().next().unwrap().matches("error").count() == 1), "{:?}", output);
+            }
+        }
+    }
+
+    #[test]
+    fn comments() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_with_comments.prot"));
+                if let Err(e) = parser.parse() {
+                    panic!("{}", e[0]);
+                }
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_docs.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let user_type = store.enums.iter().find(|e| e.name == "UserType").unwrap();
+                assert_eq!(user_type.doc, vec!["Kind of user account"]);
+                let docs: Vec<Vec<String>> = user_type.variants.iter().map(|v| v.doc.clone()).collect();
+                assert_eq!(docs, vec![vec!["Has full access".to_string()], vec![], vec!["Account with".to_string(), "custom role".to_string()]]);
+                let user = store.structs.iter().find(|s| s.name == "User").unwrap();
+                assert_eq!(user.doc, vec!["Registered user"]);
+                let docs: Vec<Vec<String>> = user.fields.iter().map(|f| f.doc.clone()).collect();
+                assert_eq!(docs, vec![vec!["Name to display".to_string(), "in UI".to_string()], vec!["Age in years".to_string()], vec![], vec![]]);
+                let login = store.structs.iter().find(|s| s.name == "Login").unwrap();
+                assert_eq!(login.doc, vec!["Login request"]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("/// Registered user\n#[derive(Debug, Clone, PartialEq)]\npub struct User {"));
+                assert!(output.contains("    /// Name to display\n    /// in UI\n    pub name: String,"));
+                assert!(output.contains("    /// Has full access\n    Admin("));
+                let ts_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = ts_render.render(store);
+                assert!(output.contains("/**\n * Registered user\n */\nexport interface IUser {"));
+                assert!(output.contains("    /**\n     * Age in years\n     */\n    age: number;"));
+            }
+        }
+    }
+
+    #[test]
+    fn imports() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_import.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let mut paths: Vec<String> = store.structs.iter().map(|s| store.get_struct_path(s.id).join(".")).collect();
+                paths.sort();
+                assert_eq!(paths, vec!["Common.Pagination", "Request", "Users.List"]);
+                let ids: Vec<usize> = store.structs.iter().map(|s| s.wire_id).collect();
+                assert!(ids.iter().all(|id| ids.iter().filter(|i| *i == id).count() == 1));
+                let request = store.structs.iter().find(|s| s.name == "Request").unwrap();
+                let pagination = store.structs.iter().find(|s| s.name == "Pagination").unwrap();
+                assert_eq!(request.fields[0].ref_type_id, Some(pagination.id));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_import_cycle.prot"));
+                let errs = parser.parse().err().unwrap();
+                assert!(matches!(errs[0], ParseError::Located(ref err, _) if matches!(**err, ParseError::ImportCycle(_))));
+                assert!(errs[0].location().unwrap().file.ends_with("cycle_b.prot"));
+            }
+        }
+    }
+
+    #[test]
+    fn relations() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_binds.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                assert_eq!(store.relations.len(), 3);
+                let struct_path = |id: Option<usize>| id.map(|id| store.get_struct_path(id).join("."));
+                let version = store.relations.iter().find(|r| r.name == "Version").unwrap();
+                assert_eq!(struct_path(version.request), Some("VersionRequest".to_string()));
+                assert_eq!(struct_path(version.response), Some("VersionResponse".to_string()));
+                assert!(version.error.is_none() && version.conclusions.is_empty());
+                let login = store.relations.iter().find(|r| r.name == "Login").unwrap();
+                assert_eq!(store.get_relation_path(login.id), vec!["UserLogin", "Login"]);
+                assert_eq!(login.doc, vec!["Login of user"]);
+                assert_eq!(struct_path(login.request), Some("UserLogin.Request".to_string()));
+                assert_eq!(struct_path(login.error), Some("UserLogin.Err".to_string()));
+                assert!(login.response.is_none());
+                let conclusions: Vec<(String, String)> = login.conclusions.iter().map(|c| (c.name.clone(), store.get_struct_path(c.response).join("."))).collect();
+                assert_eq!(conclusions, vec![
+                    ("Accept".to_string(), "UserLogin.Accepted".to_string()),
+                    ("Deny".to_string(), "UserLogin.Denied".to_string()),
+                ]);
+                assert_eq!(login.conclusions[0].doc, vec!["User is logged in"]);
+                let check = store.relations.iter().find(|r| r.name == "Check").unwrap();
+                assert_eq!(struct_path(check.request), Some("CheckRequest".to_string()));
+                let broadcasts: Vec<(String, bool)> = login.conclusions[0].broadcasts.iter().map(|b| (store.get_struct_path(b.id).join("."), b.optional)).collect();
+                assert_eq!(broadcasts, vec![
+                    ("Events.UserConnected".to_string(), false),
+                    ("Events.Message".to_string(), true),
+                ]);
+                assert!(login.conclusions[1].broadcasts.is_empty());
+                assert_eq!(check.broadcasts.len(), 1);
+                let mut broadcasts: Vec<String> = store.broadcasts.iter().map(|id| store.get_struct_path(*id).join(".")).collect();
+                broadcasts.sort();
+                assert_eq!(broadcasts, vec!["Events.Message", "Events.UserConnected", "Events.UserDisconnected"]);
+                assert!(!store.is_broadcast(version.response.unwrap()));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_relations_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![13, 19, 22, 24, 28, 36, 41]);
+                assert!(matches!(errs[2], ParseError::Located(ref err, _) if matches!(**err, ParseError::UnknownType(_))));
+                assert!(matches!(errs[4], ParseError::Located(ref err, _) if matches!(**err, ParseError::Duplicate(_))));
+            }
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_defaults.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let settings = store.structs.iter().find(|s| s.name == "Settings").unwrap();
+                assert_eq!(settings.fields[0].default, Some(EValue::Number(String::from("3"))));
+                assert_eq!(settings.fields[1].default, Some(EValue::Str(String::from("en\t\"US\""))));
+                assert_eq!(settings.fields[3].default, Some(EValue::Number(String::from("-12"))));
+                assert_eq!(settings.fields[6].wire_id, 7);
+                assert!(settings.fields[7].default.is_none());
+                let consts: Vec<(String, usize)> = store.consts.iter().map(|c| (c.name.clone(), c.parent)).collect();
+                let limits = store.groups.iter().find(|g| g.name == "Limits").unwrap();
+                assert_eq!(consts, vec![
+                    (String::from("MAX_USERS"), 0),
+                    (String::from("LOCALE"), 0),
+                    (String::from("MIN_BALANCE"), limits.id),
+                    (String::from("RATE"), limits.id),
+                    (String::from("STRICT"), limits.id),
+                ]);
+                assert_eq!(store.consts[0].doc, vec!["Limit of users in one room"]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("/// Limit of users in one room\npub const MAX_USERS: u32 = 100;\n"));
+                assert!(output.contains("pub const LOCALE: &str = \"en\";\n"));
+                assert!(output.contains("    pub const MIN_BALANCE: i64 = -100;\n"));
+                assert!(output.contains("            retries: 3,\n            locale: String::from(\"en\\t\\\"US\\\"\"),\n            enabled: false,\n            offset: -12,\n            ratio: 0.5,\n            scale: 2.0,\n"));
+                assert!(output.contains("            users: 0,\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export const MAX_USERS: number = 100;\n"));
+                assert!(output.contains("    export const MIN_BALANCE: bigint = BigInt(\"-100\");\n"));
+                assert!(output.contains("            retries: 3,\n"));
+                assert!(output.contains("            limit: BigInt(\"18446744073709551615\"),\n"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_defaults_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![6, 7, 8, 9, 10, 11, 12, 13, 14, 17, 18]);
+            }
+        }
+    }
+
+    #[test]
+    fn maps() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_maps.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let directory = store.structs.iter().find(|s| s.name == "Directory").unwrap();
+                let keys: Vec<Option<String>> = directory.fields.iter().map(|f| f.key.clone()).collect();
+                assert_eq!(keys, vec![
+                    Some(String::from("str")),
+                    Some(String::from("u32")),
+                    Some(String::from("i64")),
+                    Some(String::from("str")),
+                    Some(String::from("bool")),
+                    Some(String::from("u8")),
+                ]);
+                assert_eq!(directory.fields[0].kind, "User");
+                assert_eq!(directory.fields[1].kind, "str");
+                assert!(directory.fields[1].ref_type_id.is_none());
+                assert_eq!(directory.fields[3].get_full_name(), vec!["Chat", "Room"]);
+                assert!(directory.fields[4].optional);
+                let index = store.structs.iter().find(|s| s.name == "Index").unwrap();
+                let values: Vec<(bool, Vec<String>)> = index.fields.iter().map(|f| (f.repeated, f.value_keys.clone())).collect();
+                assert_eq!(values, vec![
+                    (true, vec![]),
+                    (true, vec![]),
+                    (false, vec![String::from("u16")]),
+                    (true, vec![String::from("u8")]),
+                ]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("    pub users: HashMap<String, User>,\n"));
+                assert!(output.contains("    pub codes: HashMap<String, Vec<u8>>,\n"));
+                assert!(output.contains("    pub rooms: HashMap<String, HashMap<u8, Vec<Chat::Room>>>,\n"));
+                assert!(output.contains("self.labels = match HashMap::<String, HashMap::<u16, String>>::get_from_storage(Source::Storage(&mut storage), Some("));
+                assert!(output.contains("    pub rooms: HashMap<String, Chat::Room>,\n"));
+                assert!(output.contains("    pub flags: Option<HashMap<bool, f64>>,\n"));
+                assert!(output.contains("            users: HashMap::new(),\n"));
+                assert!(output.contains("self.statuses = match HashMap::<i64, Status>::get_from_storage(Source::Storage(&mut storage), Some("));
+                assert!(output.contains("self.optional_statuses = match HashMap::<u8, Status>::get_from_storage(Source::Storage(&mut storage), Some("));
+                // Generated code is kept and checked by tests of protocol/implementations/rust; it should be actual
+                let rust_render: RustRender = RustRender::new(false, 0);
+                let generated = fs::read_to_string(path.join("../../../../protocol/implementations/rust/src/test/protocol_maps.rs")).unwrap();
+                assert_eq!(rust_render.render(store.clone()), generated, "protocol/implementations/rust/src/test/protocol_maps.rs should be regenerated");
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("    public users: Map<string, User>;\n"));
+                assert!(output.contains("    public flags: Map<boolean, number> | undefined;\n"));
+                assert!(output.contains("            statuses: new Map(),\n"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.decode<string, User>(buf, Protocol.Primitives.StrUTF8.decode, User.from)"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.decode<bigint, IStatus>(buf, Protocol.Primitives.i64.decode, Status.from)"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.encode<boolean, number>(map, Protocol.Primitives.bool.encode, Protocol.Primitives.f64.encode)"));
+                assert!(!output.contains("private _statuses"));
+                assert!(output.contains("    public codes: Map<string, Array<number>>;\n"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.encode<string, Array<number>>(map, Protocol.Primitives.StrUTF8.encode, Protocol.Primitives.ArrayU8.encode)"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.decode<number, Array<User>>(buf, Protocol.Primitives.u32.decode, (buf: ArrayBufferLike) => User.defaults().decodeSelfArray(buf) as Array<User> | Error)"));
+                assert!(output.contains("Protocol.Primitives.Dictionary.getValidator(Protocol.Primitives.StrUTF8, Protocol.Primitives.Dictionary.getValidator(Protocol.Primitives.u16, Protocol.Primitives.StrUTF8))"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_maps_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![10, 11, 12, 13, 14, 15, 16]);
+            }
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_bytes.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let chunk = store.structs.iter().find(|s| s.name == "Chunk").unwrap();
+                let kinds: Vec<String> = chunk.fields.iter().map(|f| f.kind.clone()).collect();
+                assert_eq!(kinds, vec!["u32", "bytes", "bytes", "bytes", "Payload"]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("    Raw(Vec<u8>),\n"));
+                assert!(output.contains("0 => match Vec::<u8>::decode(&body_buf) {\n"));
+                assert!(output.contains("    pub data: Vec<u8>,\n"));
+                assert!(output.contains("    pub checksum: Option<Vec<u8>>,\n"));
+                assert!(output.contains("    pub meta: HashMap<String, Vec<u8>>,\n"));
+                assert!(output.contains("            data: vec![],\n"));
+                assert!(output.contains("self.data = match Vec::<u8>::get_from_storage(Source::Storage(&mut storage), Some("));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export class Bytes extends Primitive<Uint8Array> {\n"));
+                assert!(output.contains("    Raw?: Uint8Array;\n"));
+                assert!(output.contains("    public data: Uint8Array;\n"));
+                assert!(output.contains("            data: new Uint8Array(),\n"));
+                assert!(output.contains("{ prop: 'data', types: Protocol.Primitives.Bytes, optional: false, },"));
+                assert!(output.contains("this.getBufferFromBuf<Uint8Array>(4, Protocol.ESize.u64, Protocol.Primitives.Bytes.encode, this.data)"));
+                assert!(output.contains("this.getValue<Uint8Array>(storage, 4, Protocol.Primitives.Bytes.decode)"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_bytes_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![2, 6, 7, 8]);
+            }
+        }
+    }
+
+    #[test]
+    fn platform_primitives() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_primitives.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub const SEPARATOR: char = ':';\n"));
+                assert!(output.contains("pub const MAX_LEN: usize = 4096;\n"));
+                assert!(output.contains("    Symbol(char),\n"));
+                assert!(output.contains("    Positions(Vec<usize>),\n"));
+                assert!(output.contains("    pub offsets: Vec<isize>,\n"));
+                assert!(output.contains("    pub counts: HashMap<char, usize>,\n"));
+                assert!(output.contains("            symbol: '\\0',\n"));
+                assert!(output.contains("            fill: '*',\n"));
+                assert!(output.contains("            offset: -5,\n"));
+                assert!(output.contains("    pub const CHAR_LEN: usize = mem::size_of::<u32>();\n"));
+                assert!(output.contains("impl Decode<usize> for usize {\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export const MAX_LEN: bigint = BigInt(\"4096\");\n"));
+                assert!(output.contains("    public symbol: string;\n"));
+                assert!(output.contains("    public position: bigint;\n"));
+                assert!(output.contains("            offset: BigInt(\"-5\"),\n"));
+                assert!(output.contains("{ prop: 'chars', types: Protocol.Primitives.ArrayChar, optional: false, },"));
+                assert!(output.contains("new Protocol.Primitives.Option<bigint>(1, new Protocol.Primitives.isize(src.Shift))"));
+                assert!(output.contains("Protocol.Primitives.usize.encode(this.position)"));
+                assert!(output.contains("export class char extends Primitive<string> {\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn well_known_types() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_well_known.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("    At(std::time::SystemTime),\n"));
+                assert!(output.contains("2 => match Vec::<uuid::Uuid>::decode(&body_buf) {\n"));
+                assert!(output.contains("    pub id: uuid::Uuid,\n"));
+                assert!(output.contains("    pub closed: Option<std::time::SystemTime>,\n"));
+                assert!(output.contains("    pub pauses: Vec<std::time::Duration>,\n"));
+                assert!(output.contains("    pub latency: HashMap<uuid::Uuid, std::time::Duration>,\n"));
+                assert!(output.contains("            created: std::time::UNIX_EPOCH,\n"));
+                assert!(output.contains("            timeout: std::time::Duration::from_millis(0),\n"));
+                assert!(output.contains("            id: uuid::Uuid::nil(),\n"));
+                assert!(output.contains("    pub const UUID_LEN: usize = 16;\n"));
+                assert!(output.contains("impl Decode<SystemTime> for SystemTime {\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export class uuid extends Primitive<string> {\n"));
+                assert!(output.contains("    At?: Date;\n"));
+                assert!(output.contains("    public created: Date;\n"));
+                assert!(output.contains("    public latency: Map<string, number>;\n"));
+                assert!(output.contains("            id: '00000000-0000-0000-0000-000000000000',\n"));
+                assert!(output.contains("            created: new Date(0),\n"));
+                assert!(output.contains("{ prop: 'touches', types: Protocol.Primitives.ArrayTimestamp, optional: false, },"));
+                assert!(output.contains("Protocol.Primitives.uuid.encode(this.id)"));
+                assert!(output.contains("this.getValue<Date>(storage, 4, Protocol.Primitives.timestamp.decode)"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_well_known_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![2, 3, 4]);
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_arrays() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_fixed.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let digest = store.structs.iter().find(|s| s.name == "Digest").unwrap();
+                let sizes: Vec<Option<usize>> = digest.fields.iter().map(|f| f.fixed).collect();
+                assert_eq!(sizes, vec![Some(16), Some(3), Some(4), Some(2), Some(100), Some(32), None]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("    pub hash: [u8; 16],\n"));
+                assert!(output.contains("    pub keys: [uuid::Uuid; 2],\n"));
+                assert!(output.contains("    pub signature: Option<[u8; 32]>,\n"));
+                assert!(output.contains("            hash: [0; 16],\n"));
+                assert!(output.contains("            position: [0.0; 3],\n"));
+                assert!(output.contains("self.hash = match <[u8; 16]>::get_from_storage(Source::Storage(&mut storage), Some("));
+                assert!(output.contains("self.signature = match Option::<[u8; 32]>::get_from_storage(Source::Storage(&mut storage), Some("));
+                assert!(output.contains("impl<T, const N: usize> Encode for [T; N] where T: Clone, Vec<T>: Encode {\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export class Fixed {\n"));
+                assert!(output.contains("    public hash: Array<number>;\n"));
+                assert!(output.contains("            hash: Array.from({ length: 16 }, () => 0),\n"));
+                assert!(output.contains("{ prop: 'hash', types: Protocol.Primitives.Fixed.getValidator(Protocol.Primitives.ArrayU8, 16), optional: false },"));
+                assert!(output.contains("this.getBufferFromBuf<Array<number>>(2, Protocol.ESize.u8, (arr: Array<number>) => Protocol.Primitives.Fixed.encode<number>(arr, 16, Protocol.Primitives.ArrayU8.encode), this.hash)"));
+                assert!(output.contains("this.getBufferFromBuf<Array<number>>(6, Protocol.ESize.u16, (arr: Array<number>) => Protocol.Primitives.Fixed.encode<number>(arr, 100, Protocol.Primitives.ArrayI32.encode), this.samples)"));
+                assert!(output.contains("this.getValue<Array<number>>(storage, 2, (buf: ArrayBufferLike) => Protocol.Primitives.Fixed.decode<number>(buf, 16, Protocol.Primitives.ArrayU8.decode))"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_fixed_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![7, 8, 9, 10, 11, 16]);
+            }
+        }
+    }
+
+    #[test]
+    fn constraints() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_constraints.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let profile = store.structs.iter().find(|s| s.name == "Profile").unwrap();
+                let username = profile.fields.iter().find(|f| f.name == "username").unwrap();
+                assert_eq!(username.constraints, vec![
+                    Constraint::MinLen(3),
+                    Constraint::MaxLen(32),
+                    Constraint::Pattern(String::from("^[a-z0-9_]+$")),
+                ]);
+                let rating = profile.fields.iter().find(|f| f.name == "rating").unwrap();
+                assert_eq!(rating.constraints, vec![Constraint::Range(String::from("-1.5"), String::from("5"))]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("        if self.username.chars().count() < 3 {\n"));
+                // Pattern is compiled once and kept in static
+                assert!(output.contains("        static PATTERN_USERNAME: std::sync::OnceLock<Result<regex::Regex, String>> = std::sync::OnceLock::new();\n"));
+                assert!(output.contains("        let pattern = PATTERN_USERNAME.get_or_init(|| regex::Regex::new(\"^[a-z0-9_]+$\").map_err(|e| e.to_string())).as_ref().map_err(|e| e.clone())?;\n        if !pattern.is_match(&self.username) {\n"));
+                // Range includes both bounds
+                assert!(output.contains("        if !(0..=130).contains(&self.age) {\n"));
+                assert!(output.contains("            return Err(format!(\"Field \\\"age\\\" of Profile should be in range 0..130, but value is {}\", self.age));\n"));
+                assert!(output.contains("        if let Some(value) = self.email.as_ref() {\n"));
+                assert!(output.contains("        if !(-1.5..=5.0).contains(&self.rating) {\n"));
+                assert!(output.contains("        if let Some(item) = self.scores.iter().find(|item| !(-100..=100).contains(*item)) {\n"));
+                assert!(output.contains("        if let Some(item) = self.tags.iter().find(|item| !pattern.is_match(item)) {\n"));
+                assert!(output.contains("        if self.tags.is_empty() {\n"));
+                assert!(output.contains("        if self.counters.len() > 8 {\n"));
+                // Generated code is kept and checked by tests of protocol/implementations/rust; it should be actual
+                let rust_render: RustRender = RustRender::new(false, 0);
+                let generated = fs::read_to_string(path.join("../../../../protocol/implementations/rust/src/test/protocol_constraints.rs")).unwrap();
+                assert_eq!(rust_render.render(store.clone()), generated, "protocol/implementations/rust/src/test/protocol_constraints.rs should be regenerated");
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("{ prop: 'username', types: Protocol.Primitives.StrUTF8, optional: false, constraints: { minLen: 3, maxLen: 32, pattern: new RegExp(\"^[a-z0-9_]+$\") }, },"));
+                assert!(output.contains("{ prop: 'age', types: Protocol.Primitives.u8, optional: false, constraints: { range: [0, 130] }, },"));
+                assert!(output.contains("constraints: { maxLen: 10, range: [BigInt(\"-100\"), BigInt(\"100\")] }"));
+                assert!(output.contains("{ prop: 'friends', types: Friend.getValidator(true), optional: false, constraints: { maxLen: 3 } },"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_constraints_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![2, 3, 4, 5, 6, 7, 8, 9, 10]);
+            }
+        }
+    }
+
+    #[test]
+    fn aliases() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_aliases.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let aliases: Vec<(String, String, bool)> = store.aliases.iter().map(|a| (a.name.clone(), a.kind.clone(), a.newtype)).collect();
+                assert_eq!(aliases, vec![
+                    (String::from("UserId"), String::from("str"), false),
+                    (String::from("Ids"), String::from("str"), false),
+                    (String::from("Login"), String::from("str"), true),
+                    (String::from("Score"), String::from("u32"), true),
+                    (String::from("RoomId"), String::from("u64"), true),
+                    (String::from("Title"), String::from("str"), false),
+                ]);
+                let user = store.structs.iter().find(|s| s.name == "User").unwrap();
+                let kinds: Vec<(&str, bool)> = user.fields.iter().map(|f| (f.kind.as_str(), f.newtype.is_some())).collect();
+                assert_eq!(kinds, vec![
+                    ("str", false),
+                    ("str", true),
+                    ("str", true),
+                    ("str", true),
+                    ("u32", true),
+                    ("str", false),
+                    ("u32", false),
+                    ("str", false),
+                    ("str", true),
+                ]);
+                assert_eq!(user.fields[6].key, Some(String::from("str")));
+                let target = store.enums.iter().find(|e| e.name == "Target").unwrap();
+                assert!(target.variants.iter().all(|v| v.types.is_some() && v.ref_type_id.is_none()));
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub type UserId = String;\n"));
+                assert!(output.contains("pub struct Login(pub String);\n"));
+                assert!(output.contains("impl Decode<Vec<Login>> for Vec<Login> {\n"));
+                assert!(output.contains("    pub struct RoomId(pub u64);\n"));
+                assert!(output.contains("    pub id: String,\n    pub login: Login,\n    pub alias: Option<Login>,\n    pub previous: Vec<Login>,\n    pub score: Score,\n"));
+                assert!(output.contains("            score: Score(10),\n"));
+                assert!(output.contains("            last: Chat::RoomId(0),\n"));
+                assert!(output.contains("self.login = match Login::get_from_storage(Source::Storage(&mut storage), Some("));
+                assert!(output.contains("        if !pattern.is_match(&self.handle.0) {\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export type UserId = string;\n"));
+                assert!(output.contains("export type Login = string & { readonly __brand: 'Login' };\n"));
+                assert!(output.contains("    export type RoomId = bigint & { readonly __brand: 'RoomId' };\n"));
+                assert!(output.contains("    public previous: Array<Login>;\n"));
+                assert!(output.contains("            score: 10 as Score,\n"));
+                assert!(output.contains("            this.previous = previous as Array<Login>;\n"));
+                assert!(output.contains("    last: Chat.RoomId;\n"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_aliases_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![3, 4, 5, 8, 9, 10, 11, 12, 17, 21]);
+            }
+        }
+    }
+
+    #[test]
+    fn numeric_enums() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_enums_numeric.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let role = store.enums.iter().find(|e| e.name == "Role").unwrap();
+                assert!(role.numeric);
+                let ids: Vec<(&str, usize)> = role.variants.iter().map(|v| (v.name.as_str(), v.wire_id)).collect();
+                assert_eq!(ids, vec![("Admin", 1), ("User", 2), ("Guest", 10)]);
+                // Explicit IDs of items don't make enum numeric
+                assert!(!store.enums.iter().find(|e| e.name == "Kind").unwrap().numeric);
+                let user = store.structs.iter().find(|s| s.name == "User").unwrap();
+                let kinds: Vec<(&str, bool)> = user.fields.iter().map(|f| (f.kind.as_str(), f.numeric.is_some())).collect();
+                assert_eq!(kinds, vec![
+                    ("str", false),
+                    ("u16", true),
+                    ("u16", true),
+                    ("u16", true),
+                    ("u16", true),
+                    ("u16", true),
+                    ("u16", true),
+                    ("Kind", false),
+                ]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("/// Role of user\n#[derive(Debug, Clone, Copy, PartialEq)]\n#[repr(u16)]\npub enum Role {\n    Admin = 1,\n    /// Regular user\n    User = 2,\n    Guest = 10,\n}\n"));
+                assert!(output.contains("            _ => Err(format!(\"Invalid value {} of enum Role\", value)),\n"));
+                assert!(output.contains("    pub role: Role,\n    pub former: Option<Role>,\n    pub history: Vec<Role>,\n    pub slots: Vec<Option<Role>>,\n    pub level: Chat::Level,\n    pub status: user::Status,\n"));
+                assert!(output.contains("            role: Role::Admin,\n"));
+                assert!(output.contains("            status: user::Status::Active,\n"));
+                // Numeric enums aren't messages
+                assert!(!output.contains("Role(Role)"));
+                // Generated code is kept and checked by tests of protocol/implementations/rust; it should be actual
+                let rust_render: RustRender = RustRender::new(false, 0);
+                let generated = fs::read_to_string(path.join("../../../../protocol/implementations/rust/src/test/protocol_enums_numeric.rs")).unwrap();
+                assert_eq!(rust_render.render(store.clone()), generated, "protocol/implementations/rust/src/test/protocol_enums_numeric.rs should be regenerated");
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export enum Role {\n    Admin = 1,\n"));
+                assert!(output.contains("    export enum Level {\n        Low = 0,\n        High = 1,\n    }\n"));
+                assert!(output.contains("    public history: Array<Role>;\n"));
+                assert!(output.contains("            role: Role.Admin,\n"));
+                assert!(output.contains("            this.status = status as User.Status;\n"));
+                assert!(!output.contains("Role?: IRole"));
+                assert!(output.contains("        } else if (([] as Array<number | undefined>).concat(history).some((value: number | undefined) => value !== undefined && Role[value] === undefined)) {\n            return new Error(`Invalid value of enum Role in property \"history\"`);\n"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_enums_numeric_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![4, 5, 10, 13, 14, 15, 16, 20, 24, 24]);
+                // Item without explicit ID gets ID of previous item + 1, which can be already used
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_enums_numeric_collision.prot"));
+                let errs = parser.parse().err().unwrap();
+                assert_eq!(errs.len(), 1);
+                assert_eq!(errs[0].location().unwrap().line, 5);
+                assert!(errs[0].to_string().starts_with("error: ID 1 of item Role.User is already used by item Role.Admin\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn extends() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_extends.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let fields = |name: &str| -> Vec<(String, usize)> {
+                    let strct = store.structs.iter().find(|s| s.name == name).unwrap();
+                    strct.fields.iter().map(|f| (f.name.clone(), f.wire_id)).collect()
+                };
+                let user = fields("User");
+                let admin = fields("AdminUser");
+                assert_eq!(admin.len(), 5);
+                assert_eq!(admin[0..4], user[..]);
+                assert_eq!(admin[4].0, "permissions");
+                let member = fields("Member");
+                assert_eq!(member[0..4], user[..]);
+                assert_eq!(fields("Messages")[0..2], fields("Pagination")[..]);
+                assert_eq!(fields("Sorted")[0..2], fields("Pagination")[..]);
+                let member = store.structs.iter().find(|s| s.name == "Member").unwrap();
+                assert_eq!(member.explicit_id, Some(300));
+                assert_eq!(member.fields[3].get_full_name(), vec![String::from("Role")]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub struct AdminUser {\n    pub id: u64,\n    pub login: Login,\n    pub name: String,\n    pub role: Role,\n    pub permissions: Vec<String>,\n}\n"));
+                assert!(output.contains("    pub struct Messages {\n        pub page: u32,\n        pub size: u32,\n        pub items: Vec<String>,\n    }\n"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export interface IAdminUser {\n    id: bigint;\n    login: Login;\n    name: string;\n    role: IRole;\n    permissions: Array<string>;\n}\n"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_extends_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![9, 13, 18]);
+            }
+        }
+    }
+
+    #[test]
+    fn generics() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_generics.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let templates: Vec<(&str, Vec<String>)> = store.templates.iter().map(|t| (t.name.as_str(), t.params.clone())).collect();
+                assert_eq!(templates, vec![
+                    ("Page", vec![String::from("T")]),
+                    ("Pair", vec![String::from("K"), String::from("V")]),
+                    ("Wrapper", vec![String::from("T")]),
+                ]);
+                let instances: Vec<String> = store.structs.iter().filter(|s| s.id > store.structs.iter().find(|s| s.name == "Root").unwrap().id).map(|s| {
+                    store.get_struct_path(s.id).join(".")
+                }).collect();
+                assert_eq!(instances, vec![
+                    String::from("Messages.PageMessage"),
+                    String::from("PageUser"),
+                    String::from("PairStrLogin"),
+                    String::from("PageU8"),
+                    String::from("Messages.WrapperMessage"),
+                ]);
+                let page = store.structs.iter().find(|s| s.name == "PageUser").unwrap();
+                let template = store.templates.iter().find(|s| s.name == "Page").unwrap();
+                assert_eq!(page.fields.iter().map(|f| f.id).collect::<Vec<usize>>(), template.fields.iter().map(|f| f.id).collect::<Vec<usize>>());
+                assert_eq!(page.fields[0].ref_type_id, Some(store.structs.iter().find(|s| s.name == "User").unwrap().id));
+                let users = store.structs.iter().find(|s| s.name == "Response" && s.fields.len() == 3).unwrap();
+                assert!(users.fields.iter().all(|f| f.generic.is_empty() && f.ref_type_id.is_some()));
+                assert!(users.fields[1].repeated);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub struct PageUser {\n    pub items: Vec<User>,\n    pub total: u32,\n    pub cursor: Option<String>,\n}\n"));
+                assert!(output.contains("        pub page: Messages::PageMessage,\n"));
+                assert!(output.contains("        pub history: Vec<PageUser>,\n"));
+                assert!(!output.contains("struct Page {"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export interface IPairStrLogin {\n    key: string;\n    value: Login | undefined;\n}\n"));
+                assert!(!output.contains("class Page "));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_generics_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![9, 14, 15, 16, 17, 18, 22]);
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_generics_duplicate.prot"));
+                let errs = parser.parse().err().unwrap();
+                assert_eq!(errs[0].location().unwrap().line, 14);
+            }
+        }
+    }
+
+    #[test]
+    fn deprecated() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_deprecated.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let user = store.structs.iter().find(|s| s.name == "User").unwrap();
+                assert_eq!(user.deprecated, Some(String::from("use Account")));
+                assert_eq!(user.doc, vec![String::from("Account of user")]);
+                let account = store.structs.iter().find(|s| s.name == "Account").unwrap();
+                assert_eq!(account.reserved_ids, vec![2, 5]);
+                assert_eq!(account.reserved_names, vec![String::from("uuid")]);
+                let fields: Vec<(&str, Option<&str>)> = account.fields.iter().map(|f| (f.name.as_str(), f.deprecated.as_deref())).collect();
+                assert_eq!(fields, vec![
+                    ("id", None),
+                    ("uuid1", Some("use uuid2")),
+                    ("uuid2", None),
+                    ("tags", Some("")),
+                    ("level", None),
+                ]);
+                assert_eq!(account.fields[1].doc, vec![String::from("Unique identifier")]);
+                assert!(account.fields.iter().all(|f| !account.reserved_ids.contains(&f.wire_id)));
+                let role = store.enums.iter().find(|e| e.name == "Role").unwrap();
+                assert_eq!(role.variants[1].deprecated, Some(String::from("use Admin")));
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(!output.contains("#![allow(deprecated)]"));
+                assert!(output.contains("/// Account of user\n#[deprecated(note = \"use Account\")]\n#[allow(deprecated)]\n#[derive(Debug, Clone, PartialEq)]\npub struct User {\n"));
+                assert!(output.contains("#[allow(deprecated)]\n#[allow(unused_variables)]\n#[allow(unused_mut)]\nimpl StructDecode for Account {\n"));
+                assert!(output.contains("#[allow(deprecated)]\nimpl EnumDecode for Role {\n"));
+                assert!(output.contains("#[allow(deprecated)]\nimpl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {\n"));
+                assert!(output.contains("    /// Unique identifier\n    #[deprecated(note = \"use uuid2\")]\n    pub uuid1: String,\n"));
+                assert!(output.contains("    #[deprecated]\n    pub tags: HashMap<String, u8>,\n"));
+                assert!(output.contains("    #[deprecated(note = \"use Admin\")]\n    Root(String),\n"));
+                assert!(output.contains("    #[deprecated]\n    #[allow(deprecated)]\n    #[derive(Debug, Clone, PartialEq)]\n    pub struct Room {\n"));
+                // Generated code is kept and checked by tests of protocol/implementations/rust; it should be actual
+                let rust_render: RustRender = RustRender::new(false, 0);
+                let generated = fs::read_to_string(path.join("../../../../protocol/implementations/rust/src/test/protocol_deprecated.rs")).unwrap();
+                assert_eq!(rust_render.render(store.clone()), generated, "protocol/implementations/rust/src/test/protocol_deprecated.rs should be regenerated");
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("/**\n * Account of user\n * @deprecated use Account\n */\nexport interface IUser {\n"));
+                assert!(output.contains("    /**\n     * Unique identifier\n     * @deprecated use uuid2\n     */\n    public uuid1: string;\n"));
+                assert!(output.contains("    /**\n     * @deprecated use Admin\n     */\n    Root?: string;\n"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_deprecated_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![3, 4, 6, 7, 9, 13, 15, 18, 23]);
+            }
+        }
+    }
+
+    #[test]
+    fn optional() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_optional.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let shape = store.structs.iter().find(|s| s.name == "Shape").unwrap();
+                let flags: Vec<(&str, bool, bool, bool)> = shape.fields.iter().map(|f| (f.name.as_str(), f.repeated, f.optional_items, f.optional)).collect();
+                assert_eq!(flags, vec![
+                    ("labels", true, true, false),
+                    ("points", true, true, false),
+                    ("kinds", true, true, false),
+                    ("levels", true, true, true),
+                    ("tags", true, false, true),
+                    ("sizes", true, true, false),
+                ]);
+                let kind = store.enums.iter().find(|e| e.name == "Kind").unwrap();
+                let flags: Vec<(&str, bool, bool, bool)> = kind.variants.iter().map(|v| (v.name.as_str(), v.repeated, v.optional_items, v.optional)).collect();
+                assert_eq!(flags, vec![
+                    ("Name", false, false, true),
+                    ("Ids", true, true, false),
+                    ("Tags", true, false, true),
+                    ("Points", true, true, false),
+                ]);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub enum Kind {\n    Name(Option<String>),\n    Ids(Vec<Option<u32>>),\n    Tags(Option<Vec<String>>),\n    Points(Vec<Option<Point>>),\n"));
+                assert!(output.contains("    pub kinds: Vec<Option<Kind>>,\n    pub levels: Option<Vec<Option<u8>>>,\n"));
+                assert!(output.contains("1 => match Vec::<Option<u32>>::decode(&body_buf) {"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export interface IKind {\n    Name?: string | null;\n    Ids?: Array<number | undefined>;\n    Tags?: Array<string> | null;\n"));
+                assert!(output.contains("    public levels: Array<number | undefined> | undefined;\n    public tags: Array<string> | undefined;\n"));
+                assert!(output.contains("Protocol.Primitives.Nullable.getSignature(Protocol.Primitives.u32.getSignature()),"));
+                assert!(output.contains("{ prop: 'points', types: Protocol.Primitives.Nullable.getValidator(Point.getValidator(false)), optional: false },"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_optional_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![2, 3, 4, 5, 6, 11, 12]);
+            }
+        }
+    }
+
+    #[test]
+    fn nested() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_nested.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let point = store.structs.iter().find(|s| s.name == "Point").unwrap();
+                assert_eq!(store.get_struct_path(point.id), vec!["Shape", "Point"]);
+                assert!(store.is_scoped(point.parent));
+                let style = store.enums.iter().find(|e| e.name == "Style").unwrap();
+                assert_eq!(store.get_enum_path(style.id), vec!["Shape", "Style"]);
+                let tag = store.structs.iter().find(|s| s.name == "Tag").unwrap();
+                assert_eq!(store.get_struct_path(tag.id), vec!["Scene", "Node", "Meta", "Tag"]);
+                let meta = store.structs.iter().find(|s| s.name == "Meta").unwrap();
+                assert!(meta.deprecated.is_some());
+                let shape = store.structs.iter().find(|s| s.name == "Shape").unwrap();
+                assert!(!store.is_scoped(shape.parent));
+                assert_eq!(shape.fields.iter().map(|f| f.name.as_str()).collect::<Vec<&str>>(), vec!["origin", "points", "style", "styles", "title", "page"]);
+                // Page<Point> inside of Shape and Page<Shape:Point> refer to same instance
+                let gallery = store.structs.iter().find(|s| s.name == "Gallery").unwrap();
+                assert_eq!(shape.fields[5].ref_type_id, gallery.fields[0].ref_type_id);
+                let rust_render: RustRender = RustRender::new(true, 0);
+                let output = rust_render.render(store.clone());
+                assert!(output.contains("pub struct Shape {\n    pub origin: shape::Point,\n    pub points: Vec<shape::Point>,\n    pub style: shape::Style,\n    pub styles: Vec<Option<shape::Style>>,\n"));
+                assert!(output.contains("pub mod shape {\n"));
+                assert!(output.contains("pub struct Gallery {\n    pub points: shape::PagePoint,\n    pub tags: Scene::node::meta::PageTag,\n}\n"));
+                assert!(output.contains("    pub mod node {\n"));
+                assert!(output.contains("        pub first: node::meta::Tag,\n"));
+                assert!(output.contains("Ok(m) => Ok(AvailableMessages::Scene(Scene::AvailableMessages::Root(m))),"));
+                assert!(!output.contains("AvailableMessages::Point(m)"));
+                assert!(!output.contains("Shape(shape::AvailableMessages)"));
+                let typescript_render: TypescriptRender = TypescriptRender::new(true, 0);
+                let output = typescript_render.render(store);
+                assert!(output.contains("export interface IShape {\n    origin: Shape.Point;\n    points: Array<Shape.Point>;\n    style: Shape.IStyle;\n"));
+                assert!(output.contains("this._style = new Shape.Style()"));
+                assert!(output.contains("export namespace Shape {\n"));
+                assert!(output.contains("    export namespace Node {\n"));
+                assert!(output.contains("        first: Scene.Node.Meta.Tag;\n"));
+                assert!(!output.contains("Shape?: Shape.IAvailableMessages"));
+                assert!(!output.contains("Point?: Point"));
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_nested_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![3, 4, 8, 13, 14, 24, 31, 33]);
+            }
+        }
+    }
+
+    #[test]
+    fn identification() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_binds.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let identification = store.identification.clone().unwrap();
+                assert_eq!(store.get_group(identification.group).unwrap().name, "Identification");
+                let consumer = store.get_struct(identification.consumer.unwrap()).unwrap();
+                assert_eq!(store.get_struct_path(consumer.id), vec!["Identification", "SelfKey"]);
+                assert_eq!(consumer.fields.iter().map(|f| f.name.as_str()).collect::<Vec<&str>>(), vec!["uuid", "id", "location"]);
+                assert!(consumer.fields.iter().all(|f| f.optional));
+                let producer = store.get_struct(identification.producer.unwrap()).unwrap();
+                assert_eq!(store.get_struct_path(producer.id), vec!["Identification", "AssignedKey"]);
+                assert!(producer.fields.iter().all(|f| f.optional));
+                let response = store.structs.iter().find(|s| s.name == "SelfKeyResponse").unwrap();
+                assert_eq!(response.parent, identification.group);
+                assert!(!response.fields[0].optional);
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_identification_errors.prot"));
+                let errs = parser.parse().err().unwrap();
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![6, 8, 13, 15]);
+                assert!(matches!(errs[2], ParseError::Located(ref err, _) if matches!(**err, ParseError::UnknownType(_))));
+            }
+        }
+    }
+
+    #[test]
+    fn producer() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_binds.prot"));
+                let store = match parser.parse() {
+                    Ok(store) => store,
+                    Err(e) => panic!("{}", e[0]),
+                };
+                let producer_render: ProducerRender = ProducerRender::new(true, 0);
+                let output = producer_render.render(store);
+                assert!(output.contains("pub mod UserLoginLoginObserver {"));
+                assert!(output.contains("        Accept(Protocol::UserLogin::Accepted),\n        Deny(Protocol::UserLogin::Denied),\n"));
+                assert!(output.contains("        pub UserConnected: (Filter, Protocol::Events::UserConnected),\n        pub Message: Option<(Filter, Protocol::Events::Message)>,\n"));
+                assert!(output.contains(") -> Result<Conclusion, Protocol::UserLogin::Err> {"));
+                assert!(output.contains(") -> Result<ResponseBroadcasting, String> {"));
+                assert!(output.contains("    /// Login of user\n"));
+                assert!(output.contains("Protocol::AvailableMessages::UserLogin(Protocol::UserLogin::AvailableMessages::Request(request)) => Some("));
+                assert!(output.contains("Protocol::AvailableMessages::VersionRequest(request) => Some("));
+                assert!(output.contains("use super::consumer_identification::{ Filter, FilterCallback };"));
+                assert!(output.contains("    pub fn merge_key(existing: &mut Protocol::Identification::SelfKey, key: Protocol::Identification::SelfKey) {\n        if let Some(uuid) = key.uuid {\n            existing.uuid = Some(uuid);\n        }\n"));
+                assert!(output.contains("    pub fn consumer_id(value: u64) -> Box<FilterCallback> {\n"));
+                assert!(output.contains("    pub fn producer_auth(value: bool) -> Box<FilterCallback> {\n"));
+            }
+        }
+    }
+
+    #[test]
+    fn recovery() {
+        if let Ok(exe) = std::env::current_exe() {
+            if let Some(path) = exe.as_path().parent() {
+                let mut parser: Parser = Parser::new(path.join("../../../test/protocol_errors.prot"));
+                let errs = match parser.parse() {
+                    Ok(_) => panic!("protocol_errors.prot should not be parsed"),
+                    Err(errs) => errs,
+                };
+                let lines: Vec<usize> = errs.iter().map(|e| e.location().unwrap().line).collect();
+                assert_eq!(lines, vec![3, 4, 5, 6, 11]);
+                assert!(matches!(errs[0], ParseError::Located(ref err, _) if matches!(**err, ParseError::Unexpected(_))));
+                assert!(matches!(errs[1], ParseError::Located(ref err, _) if matches!(**err, ParseError::Syntax(_))));
+                assert!(matches!(errs[2], ParseError::Located(ref err, _) if matches!(**err, ParseError::UnknownType(_))));
+                assert!(matches!(errs[3], ParseError::Located(ref err, _) if matches!(**err, ParseError::Duplicate(_))));
+                let mut parser: Parser = Parser::new(path.join("../../../test/not_existing.prot"));
+                assert!(matches!(parser.parse().err().unwrap()[0], ParseError::Io(_)));
+            }
+        }
+    }
+
 }
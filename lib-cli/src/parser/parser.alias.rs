@@ -0,0 +1,54 @@
+use super::{ ParseError, PrimitiveTypes };
+
+// Alias of primitive type:
+// type UserId = str;
+// Alias is transparent: fields of alias have type, which is referred by alias.
+// Newtype is written on wire in the same way, but it's rendered as distinct type:
+// newtype Login = str;
+#[derive(Debug, Clone)]
+pub struct Alias {
+    pub id: usize,
+    pub parent: usize,
+    pub name: String,
+    pub kind: String,
+    pub newtype: bool,
+    pub doc: Vec<String>,
+}
+
+impl Alias {
+
+    pub fn new(id: usize, parent: usize, newtype: bool) -> Self {
+        Alias {
+            id,
+            parent,
+            name: String::new(),
+            kind: String::new(),
+            newtype,
+            doc: vec![],
+        }
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    pub fn set_type(&mut self, kind: &str) -> Result<(), ParseError> {
+        if !PrimitiveTypes::is_valid(kind) {
+            return Err(ParseError::UnknownType(format!("Alias {} can refer only to primitive type or another alias; type {} isn't", self.name, kind)));
+        }
+        self.kind = kind.to_string();
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), ParseError> {
+        if self.kind.is_empty() {
+            return Err(ParseError::Structure(format!("Alias {} doesn't have type", self.name)));
+        }
+        Ok(())
+    }
+
+}
@@ -0,0 +1,52 @@
+use super::{ EValue, ParseError, PrimitiveTypes };
+
+// Named constant of primitive type:
+// const u32 MAX_USERS = 100;
+#[derive(Debug, Clone)]
+pub struct Const {
+    pub id: usize,
+    pub parent: usize,
+    pub name: String,
+    pub kind: String,
+    pub value: Option<EValue>,
+    pub doc: Vec<String>,
+}
+
+impl Const {
+
+    pub fn new(id: usize, parent: usize, kind: String) -> Result<Self, ParseError> {
+        if !PrimitiveTypes::is_valid(&kind) {
+            return Err(ParseError::UnknownType(format!("Constant can have only primitive type; type {} isn't primitive", kind)));
+        }
+        Ok(Const {
+            id,
+            parent,
+            name: String::new(),
+            kind,
+            value: None,
+            doc: vec![],
+        })
+    }
+
+    pub fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    pub fn set_value(&mut self, value: EValue) -> Result<(), ParseError> {
+        value.validate(&self.kind)?;
+        self.value = Some(value);
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), ParseError> {
+        if self.value.is_none() {
+            return Err(ParseError::Structure(format!("Constant {} doesn't have value", self.name)));
+        }
+        Ok(())
+    }
+
+}
@@ -0,0 +1,117 @@
+use super::{ EValue, ParseError, PrimitiveTypes };
+use PrimitiveTypes::ETypes;
+
+// Names of constraints, which are declared after name of field: str name [min_len=3, max_len=32];
+// Default value of field is declared in same way: u8 retries [default=3];
+pub mod names {
+    pub const MIN_LEN: &str = "min_len";
+    pub const MAX_LEN: &str = "max_len";
+    pub const PATTERN: &str = "pattern";
+    pub const RANGE: &str = "range";
+    pub const DEFAULT: &str = "default";
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    // Length of string (count of chars), bytes, array or map
+    MinLen(usize),
+    MaxLen(usize),
+    // Regular expression, which string (or each string of array) should match
+    Pattern(String),
+    // Range of numeric value (or of each item of array); both bounds are included
+    Range(String, String),
+}
+
+impl Constraint {
+
+    pub fn new(name: &str, value: EValue) -> Result<Self, ParseError> {
+        match (name, value) {
+            (names::MIN_LEN, EValue::Number(value)) => Ok(Constraint::MinLen(Self::get_len(name, &value)?)),
+            (names::MAX_LEN, EValue::Number(value)) => Ok(Constraint::MaxLen(Self::get_len(name, &value)?)),
+            (names::PATTERN, EValue::Str(value)) => Ok(Constraint::Pattern(value)),
+            (names::RANGE, EValue::Number(value)) => {
+                match value.split_once("..") {
+                    Some((min, max)) if !min.is_empty() && !max.is_empty() => Ok(Constraint::Range(min.to_string(), max.to_string())),
+                    _ => Err(ParseError::Unexpected(format!("Constraint range should be defined as MIN..MAX, but value is {}", value))),
+                }
+            }
+            (names::MIN_LEN, value) | (names::MAX_LEN, value) | (names::PATTERN, value) | (names::RANGE, value) => {
+                Err(ParseError::Unexpected(format!("Invalid value {} of constraint {}", value, name)))
+            }
+            _ => Err(ParseError::Unexpected(format!(
+                "Unknown constraint {}. Expecting one of: {}, {}, {}, {}, {}",
+                name, names::MIN_LEN, names::MAX_LEN, names::PATTERN, names::RANGE, names::DEFAULT
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Constraint::MinLen(_) => names::MIN_LEN,
+            Constraint::MaxLen(_) => names::MAX_LEN,
+            Constraint::Pattern(_) => names::PATTERN,
+            Constraint::Range(_, _) => names::RANGE,
+        }
+    }
+
+    // Checks, that constraint can be applied to field of given type.
+    // Length is applied to the value itself; pattern and range to each item of array
+    pub fn validate(&self, kind: &str, repeated: bool, map: bool) -> Result<(), ParseError> {
+        let etype = PrimitiveTypes::get_entity(kind);
+        match self {
+            Constraint::MinLen(_) | Constraint::MaxLen(_) => {
+                if repeated || map || matches!(etype, Some(ETypes::Estr) | Some(ETypes::Ebytes)) {
+                    Ok(())
+                } else {
+                    Err(ParseError::UnknownType(format!("Constraint {} can be used only with str, bytes, arrays and maps; type {} isn't", self.name(), kind)))
+                }
+            }
+            Constraint::Pattern(_) => {
+                if !map && matches!(etype, Some(ETypes::Estr)) {
+                    Ok(())
+                } else {
+                    Err(ParseError::UnknownType(format!("Constraint {} can be used only with str; type {} isn't", self.name(), kind)))
+                }
+            }
+            Constraint::Range(min, max) => {
+                let numeric = matches!(etype, Some(ETypes::Ei8) | Some(ETypes::Ei16) | Some(ETypes::Ei32) | Some(ETypes::Ei64) | Some(ETypes::Eisize)
+                    | Some(ETypes::Eu8) | Some(ETypes::Eu16) | Some(ETypes::Eu32) | Some(ETypes::Eu64) | Some(ETypes::Eusize)
+                    | Some(ETypes::Ef32) | Some(ETypes::Ef64));
+                if map || !numeric {
+                    return Err(ParseError::UnknownType(format!("Constraint {} can be used only with numeric types; type {} isn't", self.name(), kind)));
+                }
+                EValue::Number(min.to_string()).validate(kind)?;
+                EValue::Number(max.to_string()).validate(kind)?;
+                match (min.parse::<f64>(), max.parse::<f64>()) {
+                    (Ok(from), Ok(to)) if from <= to => Ok(()),
+                    _ => Err(ParseError::Unexpected(format!("Invalid range {}..{}: minimum should not be greater than maximum", min, max))),
+                }
+            }
+        }
+    }
+
+    // Checks default value of field. Pattern isn't checked, because it's applied by regexp engine of target language
+    pub fn check(&self, value: &EValue) -> Result<(), ParseError> {
+        let valid = match (self, value) {
+            (Constraint::MinLen(len), EValue::Str(value)) => value.chars().count() >= *len,
+            (Constraint::MaxLen(len), EValue::Str(value)) => value.chars().count() <= *len,
+            (Constraint::Range(min, max), EValue::Number(value)) => {
+                match (min.parse::<f64>(), max.parse::<f64>(), value.parse::<f64>()) {
+                    (Ok(min), Ok(max), Ok(value)) => value >= min && value <= max,
+                    _ => false,
+                }
+            }
+            _ => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ParseError::Unexpected(format!("Value {} doesn't match constraint {}", value, self.name())))
+        }
+    }
+
+    fn get_len(name: &str, value: &str) -> Result<usize, ParseError> {
+        value.parse::<usize>().map_err(|_| ParseError::Unexpected(format!("Value of constraint {} should be non-negative integer, but value is {}", name, value)))
+    }
+
+}
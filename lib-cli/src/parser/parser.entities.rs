@@ -6,6 +6,10 @@ pub mod Entities {
         EGroup,
         EStruct,
         EEnum,
+        ERelation,
+        EConst,
+        EAlias,
+        ENewtype,
     }
 
     #[allow(non_upper_case_globals)]
@@ -13,6 +17,10 @@ pub mod Entities {
         pub const TGroup: &str = "group";
         pub const TStruct: &str = "struct";
         pub const TEnum: &str = "enum";
+        pub const TRelation: &str = "relation";
+        pub const TConst: &str = "const";
+        pub const TAlias: &str = "type";
+        pub const TNewtype: &str = "newtype";
     }
 
     pub fn is_valid(str: &str) -> bool {
@@ -24,6 +32,10 @@ pub mod Entities {
             centities::TGroup => Some(EEntities::EGroup),
             centities::TStruct => Some(EEntities::EStruct),
             centities::TEnum => Some(EEntities::EEnum),
+            centities::TRelation => Some(EEntities::ERelation),
+            centities::TConst => Some(EEntities::EConst),
+            centities::TAlias => Some(EEntities::EAlias),
+            centities::TNewtype => Some(EEntities::ENewtype),
             _ => None
         }
     }
@@ -1,5 +1,5 @@
 
-use super::{ PrimitiveTypes, stop, Store };
+use super::{ Location, ParseError, PrimitiveTypes, Store };
 
 #[derive(Debug, Clone)]
 pub struct EnumItem {
@@ -8,21 +8,35 @@ pub struct EnumItem {
     pub ref_type_id: Option<usize>,
     pub ref_type_path: Vec<usize>,
     pub repeated: bool,
+    // Value of item can be None: str Name?;
+    pub optional: bool,
+    // Items of array can be None: str?[] Names;
+    pub optional_items: bool,
     pub type_path: Vec<String>,
+    pub explicit_id: Option<usize>,
+    pub wire_id: usize,
+    pub doc: Vec<String>,
+    // Reason of deprecation; it's empty, if reason isn't defined
+    pub deprecated: Option<String>,
+    // Place of declaration; it's used to report errors, which are found after parsing
+    pub location: Option<Location>,
 }
 
 impl EnumItem {
 
-    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) {
-        if self.type_path.len() == 0 {
-            stop!("Fail to accept field type because no any type references were provided");
+    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) -> Result<(), ParseError> {
+        if self.type_path.is_empty() {
+            return Err(ParseError::Structure(String::from("Fail to accept field type because no any type references were provided")));
         }
         let first = self.type_path[0].clone();
         if self.type_path.len() == 1 && PrimitiveTypes::get_entity(&first).is_some() {
+            if self.repeated && first == PrimitiveTypes::ctypes::Tbytes {
+                return Err(ParseError::UnknownType(String::from("Type bytes cannot be repeated as type of enum item")));
+            }
             if let Some(type_ref) = PrimitiveTypes::get_entity(&first) {
                 self.types = Some(type_ref);
             } else {
-                stop!("Fail to get primitive type {}", first);
+                return Err(ParseError::UnknownType(format!("Fail to get primitive type {}", first)));
             }
         } else {
             let path = if let Some(path) = store.find_by_path(own_group_id, &self.type_path) {
@@ -32,12 +46,27 @@ impl EnumItem {
                 // Has been found in root group
                 path
             } else {
-                stop!("Fail to find type: {}", self.type_path.join("."));
+                return Err(ParseError::UnknownType(format!("Fail to find type: {}", self.type_path.join("."))));
             };
             let (_, type_id) = path[path.len() - 1].clone();
+            if let Some(alias) = store.get_alias(type_id) {
+                // Item of alias gets type, which is referred by alias
+                if alias.newtype {
+                    return Err(ParseError::UnknownType(format!("Newtype {} cannot be used as type of enum item", alias.name)));
+                }
+                if self.repeated && alias.kind == PrimitiveTypes::ctypes::Tbytes {
+                    return Err(ParseError::UnknownType(String::from("Type bytes cannot be repeated as type of enum item")));
+                }
+                self.types = PrimitiveTypes::get_entity(&alias.kind);
+                return Ok(());
+            }
+            if let Some(enums) = store.get_enum(type_id).filter(|e| e.numeric) {
+                return Err(ParseError::UnknownType(format!("Numeric enum {} cannot be used as type of enum item", enums.name)));
+            }
             self.ref_type_id = Some(type_id);
-            self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| id.clone() ).collect();
+            self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| *id ).collect();
         }
+        Ok(())
     }
 
     pub fn add_type_path(&mut self, type_str: &str) {
@@ -60,6 +89,13 @@ pub struct Enum {
     pub parent: usize,
     pub name: String,
     pub variants: Vec<EnumItem>,
+    pub explicit_id: Option<usize>,
+    pub wire_id: usize,
+    pub doc: Vec<String>,
+    // C-like enum (@numeric): items have no payload and are encoded as u16 discriminant, which is ID of item
+    pub numeric: bool,
+    // Place of declaration; it's used to report errors, which are found after parsing
+    pub location: Option<Location>,
     current: Option<EnumItem>,
 }
 
@@ -71,26 +107,97 @@ impl Enum {
             parent,
             name,
             variants: vec![],
+            explicit_id: None,
+            wire_id: id,
+            doc: vec![],
+            numeric: false,
+            location: None,
             current: None,
         }
     }
 
-    pub fn set_name(&mut self, name: String) {
+    // Returns location of enum or of its last item, if it isn't defined yet
+    pub fn get_unlocated(&mut self) -> Option<&mut Option<Location>> {
+        if self.location.is_none() {
+            Some(&mut self.location)
+        } else if self.current.as_ref().is_some_and(|item| item.location.is_none()) {
+            self.current.as_mut().map(|item| &mut item.location)
+        } else if self.current.is_none() && self.variants.last().is_some_and(|item| item.location.is_none()) {
+            self.variants.last_mut().map(|item| &mut item.location)
+        } else {
+            None
+        }
+    }
+
+    pub fn set_explicit_id(&mut self, id: usize) {
+        self.explicit_id = Some(id);
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    pub fn set_numeric(&mut self, numeric: bool) {
+        self.numeric = numeric;
+    }
+
+    // Items of C-like enum are names only: Admin = 1;
+    fn check_payload(&self) -> Result<(), ParseError> {
+        if self.numeric {
+            Err(ParseError::Unexpected(format!("Items of numeric enum {} cannot have types or be optional", self.name)))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Sets doc of enum item, which is defining now or was just added
+    pub fn set_item_doc(&mut self, doc: Vec<String>) {
+        if let Some(current) = self.current.as_mut() {
+            current.doc = doc;
+        } else if let Some(last) = self.variants.last_mut() {
+            last.doc = doc;
+        }
+    }
+
+    pub fn set_item_deprecated(&mut self, deprecated: Option<String>) {
+        if let Some(current) = self.current.as_mut() {
+            current.deprecated = deprecated;
+        } else if let Some(last) = self.variants.last_mut() {
+            last.deprecated = deprecated;
+        }
+    }
+
+    pub fn set_item_id(&mut self, id: usize) -> Result<(), ParseError> {
+        if let Some(last) = self.variants.last_mut() {
+            last.explicit_id = Some(id);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Cannot set ID of enum item, because no enum items were defined")))
+        }
+    }
+
+    pub fn set_name(&mut self, name: String) -> Result<(), ParseError> {
         if let Some(mut current) = self.current.take() {
+            if self.variants.iter().any(|v| v.name == name) {
+                return Err(ParseError::Duplicate(format!("Fail to add item \"{}\" into \"{}\" because item with same name already exist", name, self.name)));
+            }
             current.name = name;
+            current.wire_id = self.variants.len();
             self.variants.push(current);
             self.current = None;
+            Ok(())
         } else {
-            stop!("Cannot set name of enum item, because enum item wasn't opened");
+            Err(ParseError::Structure(String::from("Cannot set name of enum item, because enum item wasn't opened")))
         }
     }
 
-    pub fn set_type_ref(&mut self, ref_type_id: usize) {
+    pub fn set_type_ref(&mut self, ref_type_id: usize) -> Result<(), ParseError> {
+        self.check_payload()?;
         if let Some(mut current) = self.current.take() {
             if current.ref_type_path.is_empty() {
-                stop!("Attempt to add new enum item, while previous isn't closed");
+                return Err(ParseError::Structure(String::from("Attempt to add new enum item, while previous isn't closed")));
             } else if current.ref_type_id.is_some() {
-                stop!("Type of enum's option is already defined");
+                return Err(ParseError::Structure(String::from("Type of enum's option is already defined")));
             } else {
                 current.ref_type_id = Some(ref_type_id);
                 self.current = Some(current);
@@ -102,23 +209,52 @@ impl Enum {
                 name: String::new(),
                 ref_type_path: vec![],
                 repeated: false,
+                optional: false,
+                optional_items: false,
                 type_path: vec![],
+                explicit_id: None,
+                wire_id: 0,
+                doc: vec![],
+                deprecated: None,
+                location: None,
             });
         }
+        Ok(())
     }
 
-    pub fn set_as_repeated(&mut self) {
+    pub fn set_as_repeated(&mut self) -> Result<(), ParseError> {
         if let Some(mut current) = self.current.take() {
             current.repeated = true;
             self.current = Some(current);
+            Ok(())
         } else {
-            stop!("Cannot set repeated flag of enum item, because enum item wasn't opened");
+            Err(ParseError::Structure(String::from("Cannot set repeated flag of enum item, because enum item wasn't opened")))
         }
     }
 
-    pub fn set_simple(&mut self, value: &str) {
+    pub fn set_items_as_optional(&mut self) -> Result<(), ParseError> {
+        if let Some(current) = self.current.as_mut() {
+            current.optional_items = true;
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Cannot set optional items of enum item, because enum item wasn't opened")))
+        }
+    }
+
+    // Item is already added, because optional mark follows name of item
+    pub fn set_item_as_optional(&mut self) -> Result<(), ParseError> {
+        self.check_payload()?;
+        if let Some(last) = self.variants.last_mut() {
+            last.optional = true;
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Cannot set enum item as optional, because no enum items were defined")))
+        }
+    }
+
+    pub fn set_simple(&mut self, value: &str) -> Result<(), ParseError> {
         if self.current.is_some() {
-            stop!("Attempt to add new enum item, while previous isn't closed");
+            return Err(ParseError::Structure(String::from("Attempt to add new enum item, while previous isn't closed")));
         }
         self.current = Some(EnumItem {
             types: Some(PrimitiveTypes::ETypes::Estr),
@@ -126,12 +262,20 @@ impl Enum {
             name: String::new(),
             ref_type_path: vec![],
             repeated: false,
+            optional: false,
+            optional_items: false,
             type_path: vec![],
+            explicit_id: None,
+            wire_id: 0,
+            doc: vec![],
+            deprecated: None,
+            location: None,
         });
-        self.set_name(value.to_string());
+        self.set_name(value.to_string())
     }
 
-    pub fn add_type_path(&mut self, type_str: &str) {
+    pub fn add_type_path(&mut self, type_str: &str) -> Result<(), ParseError> {
+        self.check_payload()?;
         if self.current.is_none() {
             self.current = Some(EnumItem{
                 types: None,
@@ -139,25 +283,37 @@ impl Enum {
                 name: String::new(),
                 ref_type_path: vec![],
                 repeated: false,
+                optional: false,
+                optional_items: false,
                 type_path: vec![],
+                explicit_id: None,
+                wire_id: 0,
+                doc: vec![],
+                deprecated: None,
+                location: None,
             });
         } 
         if let Some(current) = self.current.as_mut() {
             current.add_type_path(type_str);
+            Ok(())
         } else {
-            stop!("Cannot set path of enum item, because enum item wasn't opened");
+            Err(ParseError::Structure(String::from("Cannot set path of enum item, because enum item wasn't opened")))
         }
     }
 
+    pub fn discard_current(&mut self) {
+        self.current = None;
+    }
+
     pub fn get_current_option(&self) -> Option<&EnumItem> {
         self.current.as_ref()
     }
 
-    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) {
+    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) -> Result<(), ParseError> {
         if let Some(current) = self.current.as_mut() {
-            current.accept_type(store, own_group_id);
+            current.accept_type(store, own_group_id)
         } else {
-            stop!("Attempt to accept type of enum item as soon as it isn't created");
+            Err(ParseError::Structure(String::from("Attempt to accept type of enum item as soon as it isn't created")))
         }
     }
 
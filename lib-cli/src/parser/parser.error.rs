@@ -0,0 +1,75 @@
+use super::span::Location;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    // Source file cannot be read
+    Io(String),
+    // Tokenizer found something, what isn't a part of language
+    Syntax(String),
+    // Token is valid, but it isn't expected in current position
+    Unexpected(String),
+    // Entity is defined in wrong place or isn't opened/closed
+    Structure(String),
+    // Referenced type doesn't exist
+    UnknownType(String),
+    // Entity with same name already exists
+    Duplicate(String),
+    // Explicit or locked ID conflicts with others or is out of range
+    InvalidId(String),
+    // Imported files import each other
+    ImportCycle(String),
+    // Any error with position in source file
+    Located(Box<ParseError>, Location),
+}
+
+impl ParseError {
+
+    pub fn at(self, location: Location) -> Self {
+        match self {
+            ParseError::Located(_, _) => self,
+            err => ParseError::Located(Box::new(err), location),
+        }
+    }
+
+    // Error of entity is located, if entity was declared in source file
+    pub fn at_entity(self, location: &Option<Location>) -> Self {
+        match location {
+            Some(location) => self.at(location.clone()),
+            None => self,
+        }
+    }
+
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ParseError::Located(_, location) => Some(location),
+            _ => None,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            ParseError::Io(msg)
+            | ParseError::Syntax(msg)
+            | ParseError::Unexpected(msg)
+            | ParseError::Structure(msg)
+            | ParseError::UnknownType(msg)
+            | ParseError::Duplicate(msg)
+            | ParseError::InvalidId(msg)
+            | ParseError::ImportCycle(msg) => msg.clone(),
+            ParseError::Located(err, _) => err.message(),
+        }
+    }
+
+}
+
+impl fmt::Display for ParseError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Located(err, location) => write!(f, "error: {}\n{}", err.message(), location),
+            err => write!(f, "error: {}", err.message()),
+        }
+    }
+
+}
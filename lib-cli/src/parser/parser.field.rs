@@ -1,4 +1,4 @@
-use super::{ PrimitiveTypes, stop, Store };
+use super::{ Alias, Constraint, EValue, Location, ParseError, PrimitiveTypes, Store };
 
 #[derive(Debug, Clone)]
 pub enum EReferenceToType {
@@ -17,7 +17,36 @@ pub struct Field {
     pub ref_type_path: Vec<usize>,
     pub repeated: bool,
     pub optional: bool,
+    // Items of array can be undefined: T?[] items;
+    pub optional_items: bool,
+    pub explicit_id: Option<usize>,
+    pub wire_id: usize,
+    pub doc: Vec<String>,
+    pub default: Option<EValue>,
+    // Type of key, if field is a map; kind and ref_type_id describe value of map
+    pub key: Option<String>,
+    // Keys of nested maps, if value of map is map as well: map<K, map<K1, V>>; from outer to inner.
+    // Field is repeated, if the most inner value is an array: map<K, V[]>
+    pub value_keys: Vec<String>,
+    // Count of items, if field is a fixed-size array; such field is repeated as well
+    pub fixed: Option<usize>,
+    pub constraints: Vec<Constraint>,
+    // ID of newtype, if field has type of newtype; kind is a type wrapped by newtype
+    pub newtype: Option<usize>,
+    // ID of numeric enum, if field has type of it; kind is u16, which is discriminant of item
+    pub numeric: Option<usize>,
+    // Index of parameter of template, if field has type of parameter: T[] items;
+    pub param: Option<usize>,
+    // Arguments of template, if field has generic type: Page<User> users;
+    // type path refers to template until templates are expanded
+    pub generic: Vec<Vec<String>>,
+    // Reason of deprecation; it's empty, if reason isn't defined
+    pub deprecated: Option<String>,
+    // Place of declaration; it's used to report errors, which are found after parsing
+    pub location: Option<Location>,
     type_path: Vec<String>,
+    // Count of maps, which are opened, but not closed yet, while type of field is parsed
+    maps: usize,
 }
 
 impl Field {
@@ -33,7 +62,23 @@ impl Field {
             ref_type_path: vec![],
             repeated: false,
             optional: false,
+            optional_items: false,
+            explicit_id: None,
+            wire_id: id,
+            doc: vec![],
+            default: None,
+            key: None,
+            value_keys: vec![],
+            fixed: None,
+            constraints: vec![],
+            newtype: None,
+            numeric: None,
+            param: None,
+            generic: vec![],
+            deprecated: None,
+            location: None,
             type_path: vec![],
+            maps: 0,
         }
     }
 
@@ -41,11 +86,12 @@ impl Field {
         self.name = name;
     }
 
-    pub fn set_type(&mut self, kind: PrimitiveTypes::ETypes) {
+    pub fn set_type(&mut self, kind: PrimitiveTypes::ETypes) -> Result<(), ParseError> {
         if let Some(primitive) = PrimitiveTypes::get_entity_as_string(kind) {
             self.kind = primitive;
+            Ok(())
         } else {
-            stop!("Unknown type");
+            Err(ParseError::UnknownType(String::from("Unknown type")))
         }
     }
 
@@ -57,14 +103,153 @@ impl Field {
         self.ref_type_path.push(ref_type_id);
     }
 
+    pub fn set_type_path(&mut self, path: Vec<String>) {
+        self.type_path = path;
+    }
+
+    pub fn set_param(&mut self, pos: usize) {
+        self.param = Some(pos);
+    }
+
+    pub fn open_generic_arg(&mut self) {
+        self.generic.push(vec![]);
+    }
+
+    pub fn add_generic_arg_path(&mut self, type_str: &str) -> Result<(), ParseError> {
+        if let Some(arg) = self.generic.last_mut() {
+            arg.push(type_str.to_owned());
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set argument of template, because list of arguments wasn't opened")))
+        }
+    }
+
+    // Path is (name, id) of each group and of type itself, starting from root group
+    pub fn set_absolute_type_path(&mut self, path: &[(String, usize)]) {
+        self.type_path = path.iter().map(|(name, _)| name.clone()).collect();
+        if self.ref_type_id.is_some() || self.numeric.is_some() {
+            self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| *id ).collect();
+        }
+    }
+
     pub fn set_as_repeated(&mut self) {
         self.repeated = true;
     }
 
+    pub fn set_as_fixed(&mut self, size: usize) -> Result<(), ParseError> {
+        if self.key.is_some() {
+            return Err(ParseError::UnknownType(String::from("Fixed-size array cannot be used as value of map")));
+        }
+        if self.optional_items {
+            return Err(ParseError::Unexpected(String::from("Items of fixed-size array cannot be optional")));
+        }
+        if size == 0 {
+            return Err(ParseError::Unexpected(String::from("Size of fixed-size array should be greater than 0")));
+        }
+        self.repeated = true;
+        self.fixed = Some(size);
+        Ok(())
+    }
+
     pub fn set_as_optional(&mut self) {
         self.optional = true;
     }
 
+    pub fn set_items_as_optional(&mut self) {
+        self.optional_items = true;
+    }
+
+    pub fn set_explicit_id(&mut self, id: usize) {
+        self.explicit_id = Some(id);
+    }
+
+    pub fn set_deprecated(&mut self, deprecated: Option<String>) {
+        self.deprecated = deprecated;
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    // Key of map can be any primitive type except floats, because floats cannot be compared exactly,
+    // except bytes, which are opaque binary, and except timestamp, because Date in TypeScript
+    // is compared by reference
+    pub fn set_key(&mut self, kind: &str) -> Result<(), ParseError> {
+        match PrimitiveTypes::get_entity(kind) {
+            None
+            | Some(PrimitiveTypes::ETypes::Ef32)
+            | Some(PrimitiveTypes::ETypes::Ef64)
+            | Some(PrimitiveTypes::ETypes::Ebytes)
+            | Some(PrimitiveTypes::ETypes::Etimestamp) => {
+                Err(ParseError::UnknownType(format!("Key of map can be only primitive type (except f32, f64, bytes and timestamp) or str; type {} isn't", kind)))
+            }
+            Some(_) => {
+                if self.key.is_none() {
+                    self.key = Some(kind.to_string());
+                } else {
+                    self.value_keys.push(kind.to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    pub fn open_map(&mut self) {
+        self.maps += 1;
+    }
+
+    // Returns true, if field is still inside of map, which contains closed one
+    pub fn close_map(&mut self) -> bool {
+        self.maps = self.maps.saturating_sub(1);
+        self.maps > 0
+    }
+
+    pub fn is_map_opened(&self) -> bool {
+        self.maps > 0
+    }
+
+    // Default is used instead of zero value of type, when instance is created
+    pub fn set_default(&mut self, value: EValue) -> Result<(), ParseError> {
+        if self.repeated || self.optional || self.ref_type_id.is_some() || self.numeric.is_some() || self.key.is_some() {
+            return Err(ParseError::Unexpected(format!("Default value can be defined only for required field of primitive type; field \"{}\" isn't", self.name)));
+        }
+        if self.default.is_some() {
+            return Err(ParseError::Unexpected(format!("Default value is already defined for field \"{}\"", self.name)));
+        }
+        value.validate(&self.kind)?;
+        for constraint in self.constraints.iter() {
+            constraint.check(&value)?;
+        }
+        self.default = Some(value);
+        Ok(())
+    }
+
+    pub fn add_constraint(&mut self, constraint: Constraint) -> Result<(), ParseError> {
+        if self.constraints.iter().any(|c| c.name() == constraint.name()) {
+            return Err(ParseError::Unexpected(format!("Constraint {} is already defined for field \"{}\"", constraint.name(), self.name)));
+        }
+        if self.numeric.is_some() {
+            return Err(ParseError::UnknownType(format!("Constraint {} cannot be used with numeric enum; field \"{}\"", constraint.name(), self.name)));
+        }
+        if self.optional_items && matches!(constraint, Constraint::Pattern(_) | Constraint::Range(_, _)) {
+            return Err(ParseError::UnknownType(format!("Constraint {} cannot be used with optional items of array; field \"{}\"", constraint.name(), self.name)));
+        }
+        constraint.validate(&self.kind, self.repeated, self.key.is_some())?;
+        if let Some(default) = self.default.as_ref() {
+            constraint.check(default)?;
+        }
+        self.constraints.push(constraint);
+        let min = self.constraints.iter().find_map(|c| if let Constraint::MinLen(len) = c { Some(*len) } else { None });
+        let max = self.constraints.iter().find_map(|c| if let Constraint::MaxLen(len) = c { Some(*len) } else { None });
+        if let (Some(min), Some(max)) = (min, max) {
+            if min > max {
+                self.constraints.pop();
+                return Err(ParseError::Unexpected(format!("Constraint min_len={} is greater than max_len={} of field \"{}\"", min, max, self.name)));
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_full_name(&self) -> Vec<String> {
         self.type_path.clone()
     }
@@ -73,12 +258,18 @@ impl Field {
         (self.type_path[0..self.type_path.len() - 1]).to_vec()
     }
 
-    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) {
-        if self.type_path.len() == 0 {
-            stop!("Fail to accept field type because no any type references were provided");
+    pub fn accept_type(&mut self, store: &Store, own_group_id: usize) -> Result<(), ParseError> {
+        if self.type_path.is_empty() {
+            return Err(ParseError::Structure(String::from("Fail to accept field type because no any type references were provided")));
         }
         let first = self.type_path[0].clone();
         if self.type_path.len() == 1 && PrimitiveTypes::get_entity(&first).is_some() {
+            if self.repeated && first == PrimitiveTypes::ctypes::Tbytes {
+                return Err(ParseError::UnknownType(format!("Type bytes cannot be repeated; field \"{}\"", self.name)));
+            }
+            if self.fixed.is_some() && PrimitiveTypes::get_size(&first).is_none() {
+                return Err(ParseError::UnknownType(format!("Fixed-size array can contain only primitive types of fixed size; type {} of field \"{}\" isn't", first, self.name)));
+            }
             self.kind = first;
         } else {
             let path = if let Some(path) = store.find_by_path(own_group_id, &self.type_path) {
@@ -88,13 +279,63 @@ impl Field {
                 // Has been found in root group
                 path
             } else {
-                stop!("Fail to find type: {}", self.type_path.join("."));
+                return Err(ParseError::UnknownType(format!("Fail to find type: {}", self.type_path.join("."))));
             };
             let (type_name, type_id) = path[path.len() - 1].clone();
+            if let Some(alias) = store.get_alias(type_id) {
+                return self.accept_alias(alias);
+            }
+            if let Some(enums) = store.get_enum(type_id).filter(|e| e.numeric) {
+                self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| *id ).collect();
+                return self.accept_numeric(enums.id, &enums.name);
+            }
+            if self.fixed.is_some() {
+                return Err(ParseError::UnknownType(format!("Fixed-size array can contain only primitive types of fixed size; type {} of field \"{}\" isn't", self.type_path.join("."), self.name)));
+            }
+            if store.get_enum(type_id).is_some() && self.key.is_some() && (self.repeated || !self.value_keys.is_empty()) {
+                return Err(ParseError::UnknownType(format!("Enum {} can be used only as value of map itself, but not as item of array or value of nested map; field \"{}\"", type_name, self.name)));
+            }
             self.ref_type_id = Some(type_id);
             self.kind = type_name;
-            self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| id.clone() ).collect();
+            self.ref_type_path = path[0..path.len() - 1].iter().map(|(_name, id)| *id ).collect();
+        }
+        Ok(())
+    }
+
+    // Field of numeric enum is encoded as discriminant, like u16. Numeric enum cannot be item of
+    // fixed-size array or value of map (including array or map in value of map); array (Role[]) can be used
+    fn accept_numeric(&mut self, enum_id: usize, name: &str) -> Result<(), ParseError> {
+        if self.fixed.is_some() {
+            return Err(ParseError::UnknownType(format!("Fixed-size array cannot contain numeric enum {}; field \"{}\"", name, self.name)));
+        }
+        if self.key.is_some() {
+            return Err(ParseError::UnknownType(format!("Numeric enum {} cannot be used as value of map; field \"{}\"", name, self.name)));
+        }
+        self.numeric = Some(enum_id);
+        self.kind = String::from(PrimitiveTypes::ctypes::Tu16);
+        Ok(())
+    }
+
+    // Field of alias gets type, which is referred by alias. Like numeric enum, newtype cannot be item of
+    // fixed-size array or value of map; plain alias can, because it's replaced by referred type
+    fn accept_alias(&mut self, alias: Alias) -> Result<(), ParseError> {
+        if alias.newtype {
+            if self.fixed.is_some() {
+                return Err(ParseError::UnknownType(format!("Fixed-size array cannot contain newtype {}; field \"{}\"", alias.name, self.name)));
+            }
+            if self.key.is_some() {
+                return Err(ParseError::UnknownType(format!("Newtype {} cannot be used as value of map; field \"{}\"", alias.name, self.name)));
+            }
+            self.newtype = Some(alias.id);
+        }
+        if self.repeated && alias.kind == PrimitiveTypes::ctypes::Tbytes {
+            return Err(ParseError::UnknownType(format!("Type bytes cannot be repeated; field \"{}\"", self.name)));
+        }
+        if self.fixed.is_some() && PrimitiveTypes::get_size(&alias.kind).is_none() {
+            return Err(ParseError::UnknownType(format!("Fixed-size array can contain only primitive types of fixed size; type {} of field \"{}\" isn't", alias.kind, self.name)));
         }
+        self.kind = alias.kind;
+        Ok(())
     }
 
 }
\ No newline at end of file
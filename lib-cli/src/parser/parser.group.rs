@@ -6,6 +6,12 @@ pub struct Group {
     pub structs: Vec<usize>,
     pub enums: Vec<usize>,
     pub groups: Vec<usize>,
+    pub relations: Vec<usize>,
+    pub consts: Vec<usize>,
+    pub aliases: Vec<usize>,
+    // Struct, which owns group. Structs and enums, which are declared inside of struct, are placed
+    // into the group named as struct
+    pub owner: Option<usize>,
 }
 
 impl Group {
@@ -18,9 +24,17 @@ impl Group {
             structs: vec![],
             enums: vec![],
             groups: vec![],
+            relations: vec![],
+            consts: vec![],
+            aliases: vec![],
+            owner: None,
         }
     }
 
+    pub fn set_owner(&mut self, id: usize) {
+        self.owner = Some(id);
+    }
+
     pub fn bind_struct(&mut self, id: usize) {
         self.structs.push(id);
     }
@@ -33,4 +47,16 @@ impl Group {
         self.groups.push(id);
     }
 
+    pub fn bind_relation(&mut self, id: usize) {
+        self.relations.push(id);
+    }
+
+    pub fn bind_const(&mut self, id: usize) {
+        self.consts.push(id);
+    }
+
+    pub fn bind_alias(&mut self, id: usize) {
+        self.aliases.push(id);
+    }
+
 }
@@ -0,0 +1,76 @@
+use super::ParseError;
+
+pub mod keys {
+    pub const CONSUMER: &str = "consumer";
+    pub const PRODUCER: &str = "producer";
+}
+
+// Names of entities, which are created by @Identification
+pub mod names {
+    pub const GROUP: &str = "Identification";
+    pub const SELF_KEY: &str = "SelfKey";
+    pub const ASSIGNED_KEY: &str = "AssignedKey";
+    pub const SELF_KEY_RESPONSE: &str = "SelfKeyResponse";
+}
+
+// Describes keys of consumer. Consumer sends own key (SelfKey); producer
+// assigns own key (AssignedKey) to consumer:
+// @Identification {
+//     consumer {
+//         str uuid;
+//         u64 id;
+//     }
+//     producer {
+//         bool auth;
+//     }
+// }
+// All fields of keys are optional, because keys are merged with existing
+#[derive(Debug, Clone)]
+pub struct Identification {
+    pub group: usize,
+    pub consumer: Option<usize>,
+    pub producer: Option<usize>,
+}
+
+impl Identification {
+
+    pub fn new(group: usize) -> Self {
+        Identification {
+            group,
+            consumer: None,
+            producer: None,
+        }
+    }
+
+    // Returns name of struct for key
+    pub fn get_struct_name(key: &str) -> Result<&str, ParseError> {
+        match key {
+            keys::CONSUMER => Ok(names::SELF_KEY),
+            keys::PRODUCER => Ok(names::ASSIGNED_KEY),
+            _ => Err(ParseError::Unexpected(format!("Only {} and {} can be defined in @{}. Value {}", keys::CONSUMER, keys::PRODUCER, names::GROUP, key))),
+        }
+    }
+
+    pub fn set_key(&mut self, key: &str, id: usize) -> Result<(), ParseError> {
+        let target = match key {
+            keys::CONSUMER => &mut self.consumer,
+            keys::PRODUCER => &mut self.producer,
+            _ => {
+                return Err(ParseError::Unexpected(format!("Unknown key \"{}\" in @{}", key, names::GROUP)));
+            }
+        };
+        if target.is_some() {
+            return Err(ParseError::Duplicate(format!("@{} already has {}", names::GROUP, key)));
+        }
+        *target = Some(id);
+        Ok(())
+    }
+
+    pub fn validate(&self) -> Result<(), ParseError> {
+        if self.consumer.is_none() || self.producer.is_none() {
+            return Err(ParseError::Structure(format!("@{} should have {} and {}", names::GROUP, keys::CONSUMER, keys::PRODUCER)));
+        }
+        Ok(())
+    }
+
+}
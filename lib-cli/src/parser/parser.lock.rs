@@ -0,0 +1,168 @@
+use std::fs::{ self, OpenOptions };
+use std::io::prelude::*;
+use std::path::{ Path, PathBuf };
+
+pub mod kinds {
+    pub const STRUCT: &str = "struct";
+    pub const ENUM: &str = "enum";
+    pub const FIELD: &str = "field";
+    pub const ITEM: &str = "item";
+}
+
+const REMOVED: &str = "removed";
+const HEADER: &str = "# Generated by fiber-cli. Keeps IDs of entities stable between builds. Do not edit manually.";
+
+#[derive(Debug, Clone)]
+pub struct LockEntry {
+    pub kind: String,
+    pub path: String,
+    pub id: usize,
+    pub removed: bool,
+}
+
+impl LockEntry {
+
+    pub fn key(&self) -> String {
+        format!("{} {}", self.kind, self.path)
+    }
+
+}
+
+#[derive(Debug, Clone)]
+pub struct Lock {
+    pub entries: Vec<LockEntry>,
+}
+
+impl Lock {
+
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Lock { entries: vec![] }
+    }
+
+    pub fn get_path(src: &Path) -> PathBuf {
+        let mut filename = src.as_os_str().to_owned();
+        filename.push(".lock");
+        PathBuf::from(filename)
+    }
+
+    pub fn load(target: &Path) -> Result<Self, String> {
+        if !target.exists() {
+            return Ok(Lock::new());
+        }
+        let content = match fs::read_to_string(target) {
+            Ok(content) => content,
+            Err(e) => return Err(format!("Fail to read lock file {:?}: {}", target, e)),
+        };
+        Lock::parse_content(&content).map_err(|e| format!("Fail to parse lock file {:?}: {}", target, e))
+    }
+
+    pub fn parse_content(content: &str) -> Result<Self, String> {
+        let mut lock = Lock::new();
+        for (num, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 4 || parts.len() > 5 || parts[2] != "=" {
+                return Err(format!("invalid record at line {}: {}", num + 1, line));
+            }
+            if ![kinds::STRUCT, kinds::ENUM, kinds::FIELD, kinds::ITEM].contains(&parts[0]) {
+                return Err(format!("unknown kind \"{}\" at line {}", parts[0], num + 1));
+            }
+            let id = match parts[3].parse::<usize>() {
+                Ok(id) => id,
+                Err(e) => return Err(format!("invalid ID at line {}: {}", num + 1, e)),
+            };
+            let removed = match parts.get(4) {
+                Some(mark) if *mark == REMOVED => true,
+                Some(mark) => return Err(format!("unknown mark \"{}\" at line {}", mark, num + 1)),
+                None => false,
+            };
+            lock.entries.push(LockEntry {
+                kind: parts[0].to_string(),
+                path: parts[1].to_string(),
+                id,
+                removed,
+            });
+        }
+        Ok(lock)
+    }
+
+    /// Returns locked IDs as (key, id), where key is "<kind> <path>".
+    /// Scope filters records by owner path; None returns records of given kinds on any level
+    pub fn get(&self, kinds: &[&str], scope: Option<&str>) -> Vec<(String, usize)> {
+        self.entries.iter().filter(|entry| {
+            if !kinds.contains(&entry.kind.as_str()) {
+                return false;
+            }
+            if let Some(scope) = scope {
+                match entry.path.rsplit_once('.') {
+                    Some((owner, _)) => owner == scope,
+                    None => false,
+                }
+            } else {
+                true
+            }
+        }).map(|entry| (entry.key(), entry.id)).collect()
+    }
+
+    /// Replaces active records with actual IDs. Records which aren't present anymore
+    /// are kept as removed, so their IDs never be given to other entities.
+    pub fn update(&mut self, actual: Vec<(&str, String, usize)>) {
+        for entry in self.entries.iter_mut() {
+            entry.removed = true;
+        }
+        for (kind, path, id) in actual {
+            if let Some(entry) = self.entries.iter_mut().find(|e| e.kind == kind && e.path == path) {
+                entry.id = id;
+                entry.removed = false;
+            } else {
+                self.entries.push(LockEntry {
+                    kind: kind.to_string(),
+                    path,
+                    id,
+                    removed: false,
+                });
+            }
+        }
+    }
+
+    pub fn save(&self, target: &Path) -> Result<(), String> {
+        match OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(target)
+        {
+            Ok(mut file) => {
+                if let Err(e) = file.write_all(self.to_string().as_bytes()) {
+                    return Err(format!("Fail to write lock file {:?}: {}", target, e));
+                }
+                Ok(())
+            }
+            Err(e) => Err(format!("Fail to open lock file {:?}: {}", target, e)),
+        }
+    }
+
+}
+
+impl std::fmt::Display for Lock {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let order = |kind: &str| [kinds::STRUCT, kinds::ENUM, kinds::FIELD, kinds::ITEM].iter().position(|k| *k == kind);
+        let mut entries: Vec<&LockEntry> = self.entries.iter().collect();
+        entries.sort_by(|a, b| order(&a.kind).cmp(&order(&b.kind)).then(a.path.cmp(&b.path)));
+        writeln!(f, "{}", HEADER)?;
+        for entry in entries {
+            if entry.removed {
+                writeln!(f, "{} {} = {} {}", entry.kind, entry.path, entry.id, REMOVED)?;
+            } else {
+                writeln!(f, "{} {} = {}", entry.kind, entry.path, entry.id)?;
+            }
+        }
+        Ok(())
+    }
+
+}
@@ -0,0 +1,230 @@
+use super::{ ParseError, Store };
+
+pub mod keys {
+    pub const REQUEST: &str = "request";
+    pub const RESPONSE: &str = "response";
+    pub const ERROR: &str = "error";
+    pub const CONCLUSION: &str = "conclusion";
+    pub const BROADCAST: &str = "broadcast";
+}
+
+// Struct, which is sent to consumers as soon as request is done.
+// Optional broadcast can be skipped by the producer
+#[derive(Debug, Clone)]
+pub struct Broadcast {
+    pub id: usize,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Conclusion {
+    pub name: String,
+    pub response: usize,
+    pub broadcasts: Vec<Broadcast>,
+    pub doc: Vec<String>,
+}
+
+// Binds request with possible responses:
+// relation UserLogin {
+//     request UserLogin:Request;
+//     error UserLogin:Err;
+//     conclusion Accept UserLogin:Accepted {
+//         broadcast Events:UserConnected;
+//         broadcast Events:Message?;
+//     }
+//     conclusion Deny UserLogin:Denied;
+// }
+#[derive(Debug, Clone)]
+pub struct Relation {
+    pub id: usize,
+    pub parent: usize,
+    pub name: String,
+    pub request: Option<usize>,
+    pub response: Option<usize>,
+    pub error: Option<usize>,
+    pub conclusions: Vec<Conclusion>,
+    pub broadcasts: Vec<Broadcast>,
+    pub doc: Vec<String>,
+    key: Option<String>,
+    conclusion: Option<(String, Vec<String>)>,
+    type_path: Vec<String>,
+    optional: bool,
+    block: bool,
+}
+
+impl Relation {
+
+    pub fn new(id: usize, parent: usize, name: String) -> Self {
+        Relation {
+            id,
+            parent,
+            name,
+            request: None,
+            response: None,
+            error: None,
+            conclusions: vec![],
+            broadcasts: vec![],
+            doc: vec![],
+            key: None,
+            conclusion: None,
+            type_path: vec![],
+            optional: false,
+            block: false,
+        }
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    pub fn is_key(word: &str) -> bool {
+        [keys::REQUEST, keys::RESPONSE, keys::ERROR, keys::CONCLUSION, keys::BROADCAST].contains(&word)
+    }
+
+    pub fn set_key(&mut self, key: &str) -> Result<(), ParseError> {
+        if self.block && key != keys::BROADCAST {
+            return Err(ParseError::Unexpected(format!("Only broadcasts can be defined in conclusion of relation \"{}\"", self.name)));
+        }
+        let defined = match key {
+            keys::REQUEST => self.request.is_some(),
+            keys::RESPONSE => self.response.is_some(),
+            keys::ERROR => self.error.is_some(),
+            keys::CONCLUSION | keys::BROADCAST => false,
+            _ => {
+                return Err(ParseError::Unexpected(format!("Unknown key \"{}\" in relation \"{}\"", key, self.name)));
+            }
+        };
+        if defined {
+            return Err(ParseError::Duplicate(format!("Relation \"{}\" already has {}", self.name, key)));
+        }
+        self.key = Some(key.to_string());
+        self.type_path = vec![];
+        self.optional = false;
+        Ok(())
+    }
+
+    pub fn set_optional(&mut self) -> Result<(), ParseError> {
+        if self.key.as_deref() != Some(keys::BROADCAST) {
+            return Err(ParseError::Unexpected(format!("Only broadcasts of relation \"{}\" can be optional", self.name)));
+        }
+        self.optional = true;
+        Ok(())
+    }
+
+    // Opens block of broadcasts of last conclusion
+    pub fn open_block(&mut self) -> Result<(), ParseError> {
+        if self.block || self.key.as_deref() != Some(keys::CONCLUSION) {
+            return Err(ParseError::Unexpected(format!("Only conclusion of relation \"{}\" can have a block", self.name)));
+        }
+        self.block = true;
+        Ok(())
+    }
+
+    // Returns true if block of conclusion was closed; false means relation itself should be closed
+    pub fn close_block(&mut self) -> bool {
+        let closed = self.block;
+        self.block = false;
+        closed
+    }
+
+    pub fn set_conclusion_name(&mut self, name: &str, doc: Vec<String>) -> Result<(), ParseError> {
+        if self.conclusions.iter().any(|c| c.name == name) {
+            return Err(ParseError::Duplicate(format!("Relation \"{}\" already has conclusion \"{}\"", self.name, name)));
+        }
+        self.conclusion = Some((name.to_string(), doc));
+        Ok(())
+    }
+
+    pub fn add_type_path(&mut self, type_str: &str) {
+        self.type_path.push(type_str.to_string());
+    }
+
+    // Resolves type of current record. Only structs can be sent as request or response
+    pub fn accept_type(&mut self, store: &Store) -> Result<(), ParseError> {
+        let path = if let Some(path) = store.find_by_path(self.parent, &self.type_path) {
+            path
+        } else if let Some(path) = store.find_by_path(0, &self.type_path) {
+            path
+        } else {
+            return Err(ParseError::UnknownType(format!("Fail to find type: {}", self.type_path.join("."))));
+        };
+        let (_, type_id) = path[path.len() - 1];
+        match store.get_struct(type_id) {
+            None => return Err(ParseError::UnknownType(format!("Type {} of relation \"{}\" should be a struct", self.type_path.join("."), self.name))),
+            Some(strct) if store.is_scoped(strct.parent) => {
+                return Err(ParseError::UnknownType(format!("Type {} of relation \"{}\" is declared inside of struct and cannot be sent as message", self.type_path.join("."), self.name)));
+            }
+            _ => {}
+        }
+        let key = if self.key.as_deref() == Some(keys::CONCLUSION) {
+            // Key is kept till the end of conclusion to let it have a block
+            self.key.clone()
+        } else {
+            self.key.take()
+        };
+        match key.as_deref() {
+            Some(keys::REQUEST) => self.request = Some(type_id),
+            Some(keys::RESPONSE) => self.response = Some(type_id),
+            Some(keys::ERROR) => self.error = Some(type_id),
+            Some(keys::CONCLUSION) => {
+                if let Some((name, doc)) = self.conclusion.take() {
+                    self.conclusions.push(Conclusion {
+                        name,
+                        response: type_id,
+                        broadcasts: vec![],
+                        doc,
+                    });
+                } else {
+                    return Err(ParseError::Structure(format!("Conclusion of relation \"{}\" doesn't have name", self.name)));
+                }
+            }
+            Some(keys::BROADCAST) => {
+                let broadcast = Broadcast {
+                    id: type_id,
+                    optional: self.optional,
+                };
+                if self.block {
+                    if let Some(conclusion) = self.conclusions.last_mut() {
+                        conclusion.broadcasts.push(broadcast);
+                    }
+                } else {
+                    self.broadcasts.push(broadcast);
+                }
+            }
+            _ => {
+                return Err(ParseError::Structure(format!("No open record in relation \"{}\"", self.name)));
+            }
+        };
+        self.type_path = vec![];
+        Ok(())
+    }
+
+    // Returns IDs of all structs, which are broadcasted by relation
+    pub fn get_broadcasts(&self) -> Vec<usize> {
+        self.broadcasts.iter().chain(self.conclusions.iter().flat_map(|c| c.broadcasts.iter())).map(|b| b.id).collect()
+    }
+
+    pub fn discard_current(&mut self) {
+        self.key = None;
+        self.conclusion = None;
+        self.type_path = vec![];
+    }
+
+    // Request always has a result: a response or one of conclusions
+    pub fn validate(&self) -> Result<(), ParseError> {
+        if self.request.is_none() {
+            return Err(ParseError::Structure(format!("Relation \"{}\" doesn't have request", self.name)));
+        }
+        if self.response.is_some() && !self.conclusions.is_empty() {
+            return Err(ParseError::Structure(format!("Relation \"{}\" can have response or conclusions, but not both", self.name)));
+        }
+        if self.response.is_none() && self.conclusions.is_empty() {
+            return Err(ParseError::Structure(format!("Relation \"{}\" should have response or at least one conclusion", self.name)));
+        }
+        if !self.broadcasts.is_empty() && self.response.is_none() {
+            return Err(ParseError::Structure(format!("Relation \"{}\" doesn't have response; broadcasts should be defined in conclusions", self.name)));
+        }
+        Ok(())
+    }
+
+}
@@ -1,14 +1,21 @@
-use super::{ stop };
-
+use aliases::Alias;
+use constraints::Constraint;
 use entities::Entities;
+use error::ParseError;
 use enums::Enum;
 use fields::{ Field };
+use consts::Const;
 use groups::Group;
+use identification::Identification;
+use lock::Lock;
+use span::{ Location, Span };
 use std::fs;
-use std::path::PathBuf;
+use std::path::{ Path, PathBuf };
 use store::Store;
+use relations::Relation;
 use structs::Struct;
 use types::PrimitiveTypes;
+use values::EValue;
 
 #[path = "./parser.types.rs"]
 pub mod types;
@@ -31,6 +38,49 @@ pub mod groups;
 #[path = "./parser.store.rs"]
 pub mod store;
 
+#[path = "./parser.lock.rs"]
+pub mod lock;
+
+#[path = "./parser.span.rs"]
+pub mod span;
+
+#[path = "./parser.relation.rs"]
+pub mod relations;
+
+#[path = "./parser.value.rs"]
+pub mod values;
+
+#[path = "./parser.constraint.rs"]
+pub mod constraints;
+
+#[path = "./parser.const.rs"]
+pub mod consts;
+
+#[path = "./parser.alias.rs"]
+pub mod aliases;
+
+#[path = "./parser.identification.rs"]
+pub mod identification;
+
+#[path = "./parser.error.rs"]
+pub mod error;
+
+mod keywords {
+    pub const IMPORT: &str = "import";
+    pub const MAP: &str = "map";
+    pub const EXTENDS: &str = "extends";
+    pub const RESERVED: &str = "reserved";
+}
+
+// Names of blocks, which are defined with @, like @broadcasts { ... }
+// and markers of next entity, like @deprecated("reason")
+mod directives {
+    pub const BROADCASTS: &str = "broadcasts";
+    pub const IDENTIFICATION: &str = "Identification";
+    pub const DEPRECATED: &str = "deprecated";
+    pub const NUMERIC: &str = "numeric";
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 enum ENext {
@@ -40,8 +90,24 @@ enum ENext {
     Semicolon(usize),
     Space(usize),
     Repeated(usize),
+    // Size of fixed-size array: [N]
+    FixedSize((usize, usize)),
+    // Beginning of constraints of field: [name=value, ...]; they are closed with ]
+    OpenConstraints(usize),
     Optional(usize),
     PathSpliter(usize),
+    IdMark(usize),
+    Assign(usize),
+    OpenGeneric(usize),
+    CloseGeneric(usize),
+    Comma(usize),
+    OpenArgs(usize),
+    CloseArgs(usize),
+    Number((usize, usize)),
+    // Negative or fractional number; it's kept as it was written
+    Numeric((String, usize)),
+    Comment((String, usize)),
+    Literal((String, usize)),
     End(),
 }
 
@@ -50,6 +116,8 @@ enum ENextErr {
     NotAscii(String),
     NumericFirst(),
     NotSupported(String),
+    // Invalid escape sequence and end of string literal, which contains it
+    InvalidEscape((String, usize)),
 }
 
 #[allow(dead_code)]
@@ -64,17 +132,66 @@ enum EExpectation {
     FieldName,
     FieldRepeatedMark,
     FieldOptionalMark,
+    FieldItemsOptionalMark,
     StructName,
+    StructExtends,
+    StructParent,
+    StructParamsOpen,
+    StructParam,
+    StructParamsSplitter,
+    StructParamsClose,
     EnumName,
     EntityOpen,
     EntityClose,
     Semicolon,
     PathSpliter,
+    EntityIdMark,
+    FieldIdAssign,
+    EntityId,
+    FieldId,
+    ImportPath,
+    ImportEnd,
+    RelationName,
+    RelationKey,
+    ConclusionName,
+    RelationType,
+    ConclusionOpen,
+    DirectiveName,
+    DirectiveArgsOpen,
+    DirectiveArg,
+    DirectiveArgsClose,
+    ReservedValue,
+    ReservedSplitter,
+    ConstType,
+    ConstName,
+    AliasName,
+    AliasAssign,
+    AliasType,
+    FieldDefaultMark,
+    FieldDefault,
+    MapOpen,
+    MapKey,
+    MapSplitter,
+    MapValue,
+    MapClose,
+    GenericOpen,
+    GenericArg,
+    GenericSplitter,
+    GenericClose,
+    FieldConstraintsOpen,
+    ConstraintName,
+    ConstraintAssign,
+    ConstraintValue,
+    ConstraintSplitter,
+    ConstraintsClose,
 }
 
 pub struct Parser {
     _src: PathBuf,
     _prev: Option<ENext>,
+    _lock: Option<Lock>,
+    _import: Option<String>,
+    _imported: Vec<PathBuf>,
 }
 
 #[allow(dead_code)]
@@ -83,340 +200,1183 @@ impl Parser {
         Parser {
             _src: src,
             _prev: None,
+            _lock: None,
+            _import: None,
+            _imported: vec![],
         }
     }
 
-    pub fn parse(&mut self) -> Result<Store, Vec<String>> {
-        fn is_in(src: &[EExpectation], target: &EExpectation) -> bool {
-            src.iter().any(|e| e == target)
+    pub fn set_lock(&mut self, lock: Lock) {
+        self._lock = Some(lock);
+    }
+
+    pub fn get_lock(&self) -> Option<Lock> {
+        self._lock.clone()
+    }
+
+    pub fn parse(&mut self) -> Result<Store, Vec<ParseError>> {
+        let mut store: Store = Store::new();
+        self._imported = vec![];
+        let errs: Vec<ParseError> = self.parse_file(self._src.clone(), &mut store, &[]);
+        if errs.is_empty() {
+            match store.order(self._lock.as_mut()) {
+                Ok(_) => Ok(store),
+                Err(e) => Err(vec![e]),
+            }
+        } else {
+            Err(errs)
         }
-        let mut content: String = match self.get_content(self._src.clone()) {
+    }
+
+    // Parses file into given store. Imported files are parsed into the same store in place of import.
+    // Chain is a list of files, which import current one; it's used to detect cycles
+    fn parse_file(&mut self, src: PathBuf, store: &mut Store, chain: &[PathBuf]) -> Vec<ParseError> {
+        let mut content: String = match self.get_content(src.clone()) {
             Ok(c) => c,
-            Err(e) => return Err(vec![e]),
+            Err(e) => return vec![e],
         };
-        let mut errs: Vec<String> = vec![];
+        let mut chain: Vec<PathBuf> = chain.to_vec();
+        chain.push(Self::get_canonical(&src));
+        self._imported.push(Self::get_canonical(&src));
+        let source: String = content.clone();
+        let mut cursor: usize = 0;
+        let mut errs: Vec<ParseError> = vec![];
+        let mut recovering: bool = false;
+        // Comments, which are placed right above entity, are kept as its doc
+        let mut docs: Vec<String> = vec![];
+        let mut doc_end: Option<usize> = None;
+        let mut last_end: Option<usize> = None;
+        let separated = |from: Option<usize>, to: usize| from.is_some_and(|from| source[from..to].matches('\n').count() > 1);
         let mut expectation: Vec<EExpectation> = vec![
             EExpectation::StructDef,
             EExpectation::GroupDef,
             EExpectation::EnumDef,
         ];
-        let mut store: Store = Store::new();
         loop {
-            match self.next(content.clone()) {
-                Ok(enext) => {
+            let offset: usize = match self.next(content.clone()) {
+                Ok((enext, span)) => {
+                    let span = span.shift(cursor);
                     self._prev = Some(enext.clone());
-                    let offset: usize = match enext {
-                        ENext::Word((word, offset, next_char)) => {
-                            let next_char: char = if let Some(c) = next_char { c } else { '.' };
-                            if Entities::get_entity(&word).is_some()
-                                && (is_in(&expectation, &EExpectation::GroupDef)
-                                    || is_in(&expectation, &EExpectation::StructDef)
-                                    || is_in(&expectation, &EExpectation::EnumDef))
-                            {
-                                match Entities::get_entity(&word) {
-                                    Some(Entities::EEntities::EGroup) => {
-                                        if is_in(&expectation, &EExpectation::GroupDef) {
-                                            expectation = vec![EExpectation::GroupName];
-                                        } else {
-                                            stop!(
-                                                "Has been gotten Group Def, but expections is {:?}",
-                                                expectation
-                                            );
-                                        }
-                                    }
-                                    Some(Entities::EEntities::EStruct) => {
-                                        if is_in(&expectation, &EExpectation::StructDef) {
-                                            expectation = vec![EExpectation::StructName];
-                                        } else {
-                                            stop!("Has been gotten Struct Def, but expections is {:?}", expectation);
-                                        }
-                                    }
-                                    Some(Entities::EEntities::EEnum) => {
-                                        if is_in(&expectation, &EExpectation::EnumDef) {
-                                            expectation = vec![EExpectation::EnumName];
-                                        } else {
-                                            stop!(
-                                                "Has been gotten Enum Def, but expections is {:?}",
-                                                expectation
-                                            );
-                                        }
-                                    }
-                                    None => {
-                                        stop!(
-                                            "Has been gotten unkonwn definition {:?}",
-                                            Entities::get_entity(&word)
-                                        );
-                                    }
-                                };
-                                if is_in(&expectation, &EExpectation::StructDef) {
-                                    expectation = vec![EExpectation::StructName];
-                                } else if is_in(&expectation, &EExpectation::EnumDef) {
-                                    expectation = vec![EExpectation::EnumName];
-                                } else if is_in(&expectation, &EExpectation::GroupDef) {
-                                    expectation = vec![EExpectation::GroupName];
-                                }
-                            } else if is_in(&expectation, &EExpectation::StructName) {
-                                store.open_struct(word.to_string());
-                                expectation = vec![EExpectation::EntityOpen];
-                            } else if is_in(&expectation, &EExpectation::EnumName) {
-                                store.open_enum(word.to_string());
-                                expectation = vec![EExpectation::EntityOpen];
-                            } else if is_in(&expectation, &EExpectation::GroupName) {
-                                store.open_group(word.to_string());
-                                expectation = vec![EExpectation::EntityOpen];
-                            } else if is_in(&expectation, &EExpectation::FieldName) {
-                                if store.is_enum_opened() {
-                                    store.set_enum_name(&word);
-                                    expectation = vec![EExpectation::Semicolon];
-                                } else {
-                                    store.set_field_name(&word);
-                                    expectation = vec![
-                                        EExpectation::Semicolon,
-                                        EExpectation::FieldOptionalMark,
-                                    ];
+                    if let ENext::End() = enext {
+                        if let Err(err) = store.finish() {
+                            errs.push(err.at(Self::location(&src, &source, &span)));
+                        }
+                        break;
+                    }
+                    let offset = Self::get_offset(&enext);
+                    if let ENext::Comment((text, _)) = &enext {
+                        // Comment in the end of line with code isn't a doc
+                        if last_end.is_none_or(|end| source[end..span.from].contains('\n')) {
+                            if separated(doc_end, span.from) {
+                                docs.clear();
+                            }
+                            docs.append(&mut Self::get_doc(text));
+                            doc_end = Some(span.to);
+                        }
+                        content = String::from(&content[offset..]);
+                        cursor += offset;
+                        continue;
+                    }
+                    if !docs.is_empty() {
+                        if matches!(enext, ENext::Word(_) | ENext::IdMark(_)) && !separated(doc_end, span.from) {
+                            store.set_doc(std::mem::take(&mut docs));
+                        } else {
+                            docs.clear();
+                        }
+                    }
+                    doc_end = None;
+                    last_end = Some(span.to);
+                    if recovering {
+                        // Skip everything till end of field (;) or entity (})
+                        match enext {
+                            ENext::Semicolon(_) => {
+                                store.discard_pending();
+                                expectation = Self::after_recovery(store);
+                                recovering = false;
+                            }
+                            ENext::CloseStruct(_) => {
+                                store.discard_pending();
+                                expectation = vec![EExpectation::EntityClose];
+                                recovering = false;
+                                if let Err(err) = self.accept(enext, &mut expectation, store) {
+                                    errs.push(err.at(Self::location(&src, &source, &span)));
                                 }
-                            } else if is_in(&expectation, &EExpectation::FieldType) {
-                                if store.is_enum_opened() {
-                                    if next_char == ';' {
-                                        store.set_simple_enum_item(&word);
-                                        expectation = vec![EExpectation::Semicolon];
-                                    } else {
-                                        store.set_enum_type(&word);
-                                        expectation = vec![
-                                            EExpectation::FieldName,
-                                            EExpectation::FieldRepeatedMark,
-                                            EExpectation::PathSpliter,
-                                        ];
-                                    }
-                                } else {
-                                    store.set_field_type(&word);
-                                    expectation = vec![
-                                        EExpectation::FieldName,
-                                        EExpectation::FieldRepeatedMark,
-                                        EExpectation::PathSpliter,
-                                    ];
+                            }
+                            _ => {}
+                        };
+                    } else if let Err(err) = self.accept(enext.clone(), &mut expectation, store) {
+                        let unexpected: bool = matches!(err, ParseError::Unexpected(_));
+                        errs.push(err.at(Self::location(&src, &source, &span)));
+                        match enext {
+                            ENext::Semicolon(_) => {
+                                store.discard_pending();
+                                expectation = Self::after_recovery(store);
+                            }
+                            ENext::OpenStruct(_) if !unexpected && store.is_entity_opened() => {
+                                // Struct is opened, but fields of parent weren't inherited
+                            }
+                            ENext::CloseStruct(_) if !unexpected => {
+                                // Closing itself failed; entity is already dropped by store
+                                store.discard_pending();
+                                expectation = Self::after_close();
+                            }
+                            ENext::CloseStruct(_) => {
+                                // Entity still has to be closed
+                                store.discard_pending();
+                                expectation = vec![EExpectation::EntityClose];
+                                if self.accept(enext, &mut expectation, store).is_err() {
+                                    expectation = Self::after_semicolon();
                                 }
-                            } else {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value {}",
-                                    expectation, word
-                                ));
-                                break;
                             }
-                            offset
+                            _ => {
+                                recovering = true;
+                            }
+                        };
+                    } else {
+                        store.locate(|| Self::location(&src, &source, &span));
+                        if let Some(target) = self._import.take() {
+                            errs.append(&mut self.import(&src, &target, store, &chain).into_iter().map(|err| {
+                                err.at(Self::location(&src, &source, &span))
+                            }).collect());
                         }
-                        ENext::OpenStruct(offset) => {
-                            if !is_in(&expectation, &EExpectation::EntityOpen) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: OpenStruct",
+                    }
+                    offset
+                }
+                Err((e, span)) => {
+                    // Rest of string literal with invalid escape is skipped, it isn't a code
+                    let offset = if let ENextErr::InvalidEscape((_, end)) = e { end } else { span.to };
+                    let span = span.shift(cursor);
+                    let msg = match e {
+                        ENextErr::NotAscii(msg) => format!("Not ASCII char(s): {}", msg),
+                        ENextErr::NotSupported(msg) | ENextErr::InvalidEscape((msg, _)) => format!("Not supported char(s): {}", msg),
+                        ENextErr::NumericFirst() => "Numeric symbols cannot be used as first in names.".to_string(),
+                    };
+                    errs.push(ParseError::Syntax(msg).at(Self::location(&src, &source, &span)));
+                    recovering = true;
+                    offset
+                }
+            };
+            content = String::from(&content[offset..]);
+            cursor += offset;
+        }
+        errs
+    }
+
+    // Parses imported file. Path of imported file is relative to the file, which imports it
+    fn import(&mut self, from: &Path, target: &str, store: &mut Store, chain: &[PathBuf]) -> Vec<ParseError> {
+        let src: PathBuf = match from.parent() {
+            Some(dir) => dir.join(target),
+            None => PathBuf::from(target),
+        };
+        let canonical: PathBuf = Self::get_canonical(&src);
+        if let Some(pos) = chain.iter().position(|path| path == &canonical) {
+            let cycle: Vec<String> = chain[pos..].iter().chain(std::iter::once(&canonical)).map(|path| path.display().to_string()).collect();
+            return vec![ParseError::ImportCycle(format!("Import cycle: {}", cycle.join(" -> ")))];
+        }
+        if self._imported.contains(&canonical) {
+            // File is already imported by another file
+            return vec![];
+        }
+        self.parse_file(src, store, chain)
+    }
+
+    fn get_canonical(src: &Path) -> PathBuf {
+        fs::canonicalize(src).unwrap_or_else(|_| src.to_path_buf())
+    }
+
+    fn accept(&mut self, enext: ENext, expectation: &mut Vec<EExpectation>, store: &mut Store) -> Result<(), ParseError> {
+        fn is_in(src: &[EExpectation], target: &EExpectation) -> bool {
+            src.iter().any(|e| e == target)
+        }
+        match enext {
+            ENext::Word((word, _offset, _)) if word == keywords::IMPORT && is_in(expectation, &EExpectation::GroupDef) => {
+                if !store.is_root() {
+                    return Err(ParseError::Structure(String::from("Import can be used only on top level of file")));
+                }
+                // Comment above import isn't a doc of imported entities
+                store.set_doc(vec![]);
+                *expectation = vec![EExpectation::ImportPath];
+            }
+            ENext::Literal((value, _)) if is_in(expectation, &EExpectation::ConstraintValue) => {
+                store.set_constraint_value(EValue::Str(value))?;
+                *expectation = vec![
+                    EExpectation::ConstraintSplitter,
+                    EExpectation::ConstraintsClose,
+                ];
+            }
+            ENext::Literal((value, _)) | ENext::Numeric((value, _)) if is_in(expectation, &EExpectation::FieldId) => {
+                return Err(ParseError::Unexpected(format!(
+                    "Invalid ID {} of field; default value of field is declared as constraint: [default=VALUE]",
+                    value
+                )));
+            }
+            ENext::Literal((value, _)) if is_in(expectation, &EExpectation::FieldDefault) => {
+                store.set_value(EValue::Str(value))?;
+                *expectation = vec![EExpectation::Semicolon];
+            }
+            ENext::Literal((value, _)) if is_in(expectation, &EExpectation::DirectiveArg) => {
+                store.set_deprecated(value)?;
+                *expectation = vec![EExpectation::DirectiveArgsClose];
+            }
+            ENext::Literal((value, _)) if is_in(expectation, &EExpectation::ReservedValue) => {
+                store.add_reserved_name(&value)?;
+                *expectation = vec![
+                    EExpectation::ReservedSplitter,
+                    EExpectation::Semicolon,
+                ];
+            }
+            ENext::Literal((value, _)) => {
+                if !is_in(expectation, &EExpectation::ImportPath) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: \"{}\"",
+                        expectation, value
+                    )));
+                }
+                self._import = Some(value);
+                *expectation = vec![EExpectation::ImportEnd];
+            }
+            ENext::Word((word, _offset, next_char)) => {
+                let next_char: char = next_char.unwrap_or('.');
+                if Entities::get_entity(&word).is_some()
+                    && (is_in(expectation, &EExpectation::GroupDef)
+                        || is_in(expectation, &EExpectation::StructDef)
+                        || is_in(expectation, &EExpectation::EnumDef))
+                {
+                    match Entities::get_entity(&word) {
+                        Some(Entities::EEntities::EGroup) => {
+                            if is_in(expectation, &EExpectation::GroupDef) {
+                                *expectation = vec![EExpectation::GroupName];
+                            } else {
+                                return Err(ParseError::Unexpected(format!(
+                                    "Has been gotten Group Def, but expections is {:?}",
                                     expectation
-                                ));
-                                break;
+                                )));
                             }
-                            expectation = vec![
-                                EExpectation::FieldType,
-                                EExpectation::GroupDef,
-                                EExpectation::StructDef,
-                                EExpectation::EnumDef,
-                                EExpectation::EnumValue,
-                                EExpectation::EntityClose,
-                            ];
-                            store.open();
-                            offset
                         }
-                        ENext::CloseStruct(offset) => {
-                            if !is_in(&expectation, &EExpectation::EntityClose) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: CloseStruct",
-                                    expectation
-                                ));
-                                break;
+                        Some(Entities::EEntities::EStruct) => {
+                            if is_in(expectation, &EExpectation::StructDef) {
+                                *expectation = vec![EExpectation::StructName];
+                            } else {
+                                return Err(ParseError::Unexpected(format!("Has been gotten Struct Def, but expections is {:?}", expectation)));
                             }
-                            expectation = vec![
-                                EExpectation::FieldType, // Only if it's nested struct
-                                EExpectation::GroupDef,
-                                EExpectation::StructDef,
-                                EExpectation::EnumDef,
-                                EExpectation::EntityClose,
-                            ];
-                            store.close();
-                            offset
                         }
-                        ENext::Semicolon(offset) => {
-                            if !is_in(&expectation, &EExpectation::Semicolon) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: Semicolon",
+                        Some(Entities::EEntities::ERelation) => {
+                            if is_in(expectation, &EExpectation::GroupDef) {
+                                *expectation = vec![EExpectation::RelationName];
+                            } else {
+                                return Err(ParseError::Unexpected(format!(
+                                    "Has been gotten Relation Def, but expections is {:?}",
                                     expectation
-                                ));
-                                break;
-                            }
-                            if !store.is_enum_opened() {
-                                store.close_field();
+                                )));
                             }
-                            expectation = vec![
-                                EExpectation::FieldType,
-                                EExpectation::StructDef,
-                                EExpectation::EnumDef,
-                                EExpectation::EnumValue,
-                                EExpectation::EntityClose,
-                            ];
-                            offset
                         }
-                        ENext::Space(offset) => offset,
-                        ENext::Repeated(offset) => {
-                            if !is_in(&expectation, &EExpectation::FieldRepeatedMark) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: FieldRepeatedMark",
+                        Some(Entities::EEntities::EConst) => {
+                            if is_in(expectation, &EExpectation::GroupDef) {
+                                *expectation = vec![EExpectation::ConstType];
+                            } else {
+                                return Err(ParseError::Unexpected(format!(
+                                    "Has been gotten Const Def, but expections is {:?}",
                                     expectation
-                                ));
-                                break;
+                                )));
                             }
-                            expectation = vec![EExpectation::FieldName];
-                            store.set_field_type_as_repeated();
-                            offset
                         }
-                        ENext::Optional(offset) => {
-                            if !is_in(&expectation, &EExpectation::FieldOptionalMark) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: FieldOptionalMark",
+                        Some(Entities::EEntities::EAlias) | Some(Entities::EEntities::ENewtype) => {
+                            if is_in(expectation, &EExpectation::GroupDef) {
+                                store.open_alias(matches!(Entities::get_entity(&word), Some(Entities::EEntities::ENewtype)))?;
+                                *expectation = vec![EExpectation::AliasName];
+                            } else {
+                                return Err(ParseError::Unexpected(format!(
+                                    "Has been gotten Alias Def, but expections is {:?}",
                                     expectation
-                                ));
-                                break;
+                                )));
                             }
-                            expectation = vec![EExpectation::Semicolon];
-                            store.set_field_type_as_optional();
-                            offset
                         }
-                        ENext::PathSpliter(offset) => {
-                            if !is_in(&expectation, &EExpectation::PathSpliter) {
-                                errs.push(format!(
-                                    "Unexpecting next step: {:?}. Value: PathSpliter",
+                        Some(Entities::EEntities::EEnum) => {
+                            if is_in(expectation, &EExpectation::EnumDef) {
+                                *expectation = vec![EExpectation::EnumName];
+                            } else {
+                                return Err(ParseError::Unexpected(format!(
+                                    "Has been gotten Enum Def, but expections is {:?}",
                                     expectation
-                                ));
-                                break;
-                            }
-                            if !store.is_field_opened() && !store.is_enum_opened() {
-                                errs.push("Unexpecting : as soon as no open field or enum".to_owned());
+                                )));
                             }
-                            expectation = vec![EExpectation::FieldType];
-                            offset
-                        },
-                        ENext::End() => {
-                            break;
+                        }
+                        None => {
+                            return Err(ParseError::Unexpected(format!(
+                                "Has been gotten unkonwn definition {:?}",
+                                Entities::get_entity(&word)
+                            )));
                         }
                     };
-                    content = String::from(&content[offset..]);
-                }
-                Err(e) => {
-                    match e {
-                        ENextErr::NotAscii(msg) => errs.push(format!("ASCII error: {}", msg)),
-                        ENextErr::NotSupported(msg) => {
-                            errs.push(format!("Not supported char(s) error: {}", msg))
+                } else if is_in(expectation, &EExpectation::ConstraintName) {
+                    store.set_constraint_name(&word)?;
+                    *expectation = vec![EExpectation::ConstraintAssign];
+                } else if is_in(expectation, &EExpectation::ConstraintValue) {
+                    store.set_constraint_value(EValue::from_word(&word)?)?;
+                    *expectation = vec![
+                        EExpectation::ConstraintSplitter,
+                        EExpectation::ConstraintsClose,
+                    ];
+                } else if is_in(expectation, &EExpectation::FieldDefault) {
+                    store.set_value(EValue::from_word(&word)?)?;
+                    *expectation = vec![EExpectation::Semicolon];
+                } else if is_in(expectation, &EExpectation::MapKey) {
+                    store.set_map_key(&word)?;
+                    *expectation = vec![EExpectation::MapSplitter];
+                } else if is_in(expectation, &EExpectation::MapValue) && word == keywords::MAP && next_char == '<' {
+                    store.open_nested_map()?;
+                    *expectation = vec![EExpectation::MapOpen];
+                } else if is_in(expectation, &EExpectation::MapValue) {
+                    store.set_field_type(&word)?;
+                    *expectation = vec![
+                        EExpectation::PathSpliter,
+                        EExpectation::FieldRepeatedMark,
+                        EExpectation::MapClose,
+                    ];
+                } else if is_in(expectation, &EExpectation::ConstType) {
+                    store.open_const(&word)?;
+                    *expectation = vec![EExpectation::ConstName];
+                } else if is_in(expectation, &EExpectation::ConstName) {
+                    store.set_const_name(&word)?;
+                    *expectation = vec![EExpectation::FieldDefaultMark];
+                } else if is_in(expectation, &EExpectation::AliasName) {
+                    store.set_alias_name(&word)?;
+                    *expectation = vec![EExpectation::AliasAssign];
+                } else if is_in(expectation, &EExpectation::AliasType) {
+                    store.set_alias_type(&word)?;
+                    *expectation = vec![EExpectation::Semicolon];
+                } else if is_in(expectation, &EExpectation::GroupDef) && store.is_identification_opened() {
+                    store.open_identification_key(&word)?;
+                    *expectation = vec![EExpectation::EntityOpen];
+                } else if is_in(expectation, &EExpectation::StructName) && next_char == '<' {
+                    store.open_struct(word.to_string())?;
+                    *expectation = vec![EExpectation::StructParamsOpen];
+                } else if is_in(expectation, &EExpectation::StructParam) {
+                    store.add_struct_param(&word)?;
+                    *expectation = vec![
+                        EExpectation::StructParamsSplitter,
+                        EExpectation::StructParamsClose,
+                    ];
+                } else if is_in(expectation, &EExpectation::GenericArg) {
+                    store.add_generic_arg_path(&word)?;
+                    *expectation = vec![
+                        EExpectation::PathSpliter,
+                        EExpectation::GenericSplitter,
+                        EExpectation::GenericClose,
+                    ];
+                } else if is_in(expectation, &EExpectation::StructName) {
+                    store.open_struct(word.to_string())?;
+                    *expectation = vec![
+                        EExpectation::EntityOpen,
+                        EExpectation::EntityIdMark,
+                        EExpectation::StructExtends,
+                    ];
+                } else if is_in(expectation, &EExpectation::StructExtends) && word == keywords::EXTENDS {
+                    *expectation = vec![EExpectation::StructParent];
+                } else if is_in(expectation, &EExpectation::StructParent) {
+                    store.add_struct_parent_path(&word)?;
+                    *expectation = vec![
+                        EExpectation::EntityOpen,
+                        EExpectation::EntityIdMark,
+                        EExpectation::PathSpliter,
+                    ];
+                } else if is_in(expectation, &EExpectation::EnumName) {
+                    store.open_enum(word.to_string())?;
+                    *expectation = vec![
+                        EExpectation::EntityOpen,
+                        EExpectation::EntityIdMark,
+                    ];
+                } else if is_in(expectation, &EExpectation::DirectiveName) {
+                    match word.as_str() {
+                        directives::BROADCASTS => store.open_broadcasts()?,
+                        directives::IDENTIFICATION => store.open_identification()?,
+                        directives::DEPRECATED => {
+                            store.set_deprecated(String::new())?;
+                            *expectation = Self::after_deprecated(store);
+                            expectation.push(EExpectation::DirectiveArgsOpen);
+                            return Ok(());
+                        }
+                        directives::NUMERIC => {
+                            store.set_numeric()?;
+                            *expectation = vec![EExpectation::EnumDef];
+                            return Ok(());
+                        }
+                        _ => {
+                            return Err(ParseError::Unexpected(format!("Unknown directive @{}", word)));
                         }
-                        ENextErr::NumericFirst() => errs
-                            .push("Numeric symbols cannot be used as first in names.".to_string()),
                     };
-                    return Err(errs);
+                    *expectation = vec![EExpectation::EntityOpen];
+                } else if is_in(expectation, &EExpectation::RelationName) {
+                    store.open_relation(word.to_string())?;
+                    *expectation = vec![EExpectation::EntityOpen];
+                } else if is_in(expectation, &EExpectation::RelationKey) && store.is_relation_opened() {
+                    if !Relation::is_key(&word) {
+                        return Err(ParseError::Unexpected(format!(
+                            "Expecting one of relation keys: request, response, error, conclusion. Value {}",
+                            word
+                        )));
+                    }
+                    store.set_relation_key(&word)?;
+                    *expectation = if word == relations::keys::CONCLUSION {
+                        vec![EExpectation::ConclusionName]
+                    } else {
+                        vec![EExpectation::RelationType]
+                    };
+                } else if is_in(expectation, &EExpectation::ConclusionName) {
+                    store.set_relation_conclusion(&word)?;
+                    *expectation = vec![EExpectation::RelationType];
+                } else if is_in(expectation, &EExpectation::RelationType) {
+                    store.set_relation_type(&word)?;
+                    *expectation = vec![
+                        EExpectation::PathSpliter,
+                        EExpectation::Semicolon,
+                        EExpectation::ConclusionOpen,
+                        EExpectation::FieldOptionalMark,
+                    ];
+                } else if is_in(expectation, &EExpectation::GroupName) {
+                    store.open_group(word.to_string())?;
+                    *expectation = vec![EExpectation::EntityOpen];
+                } else if is_in(expectation, &EExpectation::FieldName) {
+                    if store.is_enum_opened() {
+                        store.set_enum_name(&word)?;
+                        *expectation = vec![
+                            EExpectation::Semicolon,
+                            EExpectation::FieldOptionalMark,
+                            EExpectation::FieldIdAssign,
+                        ];
+                    } else {
+                        store.set_field_name(&word)?;
+                        *expectation = vec![
+                            EExpectation::Semicolon,
+                            EExpectation::FieldOptionalMark,
+                            EExpectation::FieldIdAssign,
+                            EExpectation::FieldConstraintsOpen,
+                        ];
+                    }
+                } else if is_in(expectation, &EExpectation::FieldType) {
+                    if store.is_enum_opened() {
+                        if next_char == ';' || next_char == '=' {
+                            store.set_simple_enum_item(&word)?;
+                            *expectation = vec![
+                                EExpectation::Semicolon,
+                                EExpectation::FieldIdAssign,
+                            ];
+                        } else {
+                            store.set_enum_type(&word)?;
+                            *expectation = vec![
+                                EExpectation::FieldName,
+                                EExpectation::FieldRepeatedMark,
+                                EExpectation::FieldItemsOptionalMark,
+                                EExpectation::PathSpliter,
+                            ];
+                        }
+                    } else if word == keywords::RESERVED {
+                        store.open_reserved()?;
+                        *expectation = vec![EExpectation::ReservedValue];
+                    } else if word == keywords::MAP && next_char == '<' {
+                        store.open_map_field()?;
+                        *expectation = vec![EExpectation::MapOpen];
+                    } else if next_char == '<' {
+                        store.set_field_type(&word)?;
+                        *expectation = vec![EExpectation::GenericOpen];
+                    } else {
+                        store.set_field_type(&word)?;
+                        *expectation = vec![
+                            EExpectation::FieldName,
+                            EExpectation::FieldRepeatedMark,
+                            EExpectation::FieldItemsOptionalMark,
+                            EExpectation::PathSpliter,
+                        ];
+                    }
+                } else {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value {}",
+                        expectation, word
+                    )));
+                }
+            }
+            ENext::OpenStruct(_) if is_in(expectation, &EExpectation::ConclusionOpen) => {
+                store.open_conclusion_block()?;
+                *expectation = vec![
+                    EExpectation::RelationKey,
+                    EExpectation::EntityClose,
+                ];
+            }
+            ENext::OpenStruct(_) => {
+                if !is_in(expectation, &EExpectation::EntityOpen) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: OpenStruct",
+                        expectation
+                    )));
+                }
+                store.open()?;
+                *expectation = if store.is_relation_opened() {
+                    vec![
+                        EExpectation::RelationKey,
+                        EExpectation::EntityClose,
+                    ]
+                } else {
+                    vec![
+                        EExpectation::FieldType,
+                        EExpectation::GroupDef,
+                        EExpectation::StructDef,
+                        EExpectation::EnumDef,
+                        EExpectation::EnumValue,
+                        EExpectation::EntityClose,
+                    ]
+                };
+                store.extend_struct()?;
+            }
+            ENext::CloseStruct(_) => {
+                if !is_in(expectation, &EExpectation::EntityClose) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: CloseStruct",
+                        expectation
+                    )));
+                }
+                *expectation = Self::after_close();
+                store.close()?;
+                if store.is_relation_opened() {
+                    // Block of conclusion is closed
+                    *expectation = vec![
+                        EExpectation::RelationKey,
+                        EExpectation::EntityClose,
+                    ];
+                }
+            }
+            ENext::Semicolon(_) if is_in(expectation, &EExpectation::ImportEnd) => {
+                *expectation = vec![
+                    EExpectation::StructDef,
+                    EExpectation::GroupDef,
+                    EExpectation::EnumDef,
+                ];
+            }
+            ENext::Semicolon(_) if is_in(expectation, &EExpectation::ReservedSplitter) => {
+                *expectation = Self::after_semicolon();
+            }
+            ENext::Semicolon(_) => {
+                if !is_in(expectation, &EExpectation::Semicolon) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: Semicolon",
+                        expectation
+                    )));
+                }
+                if store.is_const_opened() {
+                    store.close_const()?;
+                    *expectation = Self::after_close();
+                    return Ok(());
+                }
+                if store.is_alias_opened() {
+                    store.close_alias()?;
+                    *expectation = Self::after_close();
+                    return Ok(());
+                }
+                if store.is_relation_opened() {
+                    store.close_relation_record()?;
+                    *expectation = vec![
+                        EExpectation::RelationKey,
+                        EExpectation::EntityClose,
+                    ];
+                    return Ok(());
+                }
+                if !store.is_enum_opened() {
+                    store.close_field()?;
+                }
+                *expectation = Self::after_semicolon();
+            }
+            ENext::Space(_) => {}
+            ENext::Repeated(_) if is_in(expectation, &EExpectation::ConstraintsClose) => {
+                *expectation = vec![
+                    EExpectation::Semicolon,
+                    EExpectation::FieldIdAssign,
+                ];
+            }
+            ENext::OpenConstraints(_) => {
+                if !is_in(expectation, &EExpectation::FieldConstraintsOpen) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: [",
+                        expectation
+                    )));
+                }
+                *expectation = vec![EExpectation::ConstraintName];
+            }
+            ENext::Repeated(_) => {
+                if !is_in(expectation, &EExpectation::FieldRepeatedMark) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: FieldRepeatedMark",
+                        expectation
+                    )));
+                }
+                // Array can be value of map: map<K, V[]>
+                *expectation = if store.is_map_opened() {
+                    vec![EExpectation::MapClose]
+                } else {
+                    vec![EExpectation::FieldName]
+                };
+                store.set_field_type_as_repeated()?;
+            }
+            ENext::FixedSize((size, _)) => {
+                if !is_in(expectation, &EExpectation::FieldRepeatedMark) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: [{}]",
+                        expectation, size
+                    )));
+                }
+                *expectation = vec![EExpectation::FieldName];
+                store.set_field_type_as_fixed(size)?;
+            }
+            ENext::Optional(_) if is_in(expectation, &EExpectation::FieldItemsOptionalMark) => {
+                // Optional mark after type is allowed only for items of array: T?[] items;
+                *expectation = vec![EExpectation::FieldRepeatedMark];
+                store.set_field_items_as_optional()?;
+            }
+            ENext::Optional(_) => {
+                if !is_in(expectation, &EExpectation::FieldOptionalMark) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: FieldOptionalMark",
+                        expectation
+                    )));
+                }
+                if store.is_relation_opened() {
+                    store.set_relation_optional()?;
+                    *expectation = vec![EExpectation::Semicolon];
+                    return Ok(());
+                }
+                if store.is_enum_opened() {
+                    store.set_field_type_as_optional()?;
+                    *expectation = vec![
+                        EExpectation::Semicolon,
+                        EExpectation::FieldIdAssign,
+                    ];
+                    return Ok(());
+                }
+                *expectation = vec![
+                    EExpectation::Semicolon,
+                    EExpectation::FieldIdAssign,
+                    EExpectation::FieldConstraintsOpen,
+                ];
+                store.set_field_type_as_optional()?;
+            }
+            ENext::IdMark(_) => {
+                if is_in(expectation, &EExpectation::EntityIdMark) {
+                    *expectation = vec![EExpectation::EntityId];
+                } else if is_in(expectation, &EExpectation::GroupDef) || is_in(expectation, &EExpectation::FieldType) {
+                    *expectation = vec![EExpectation::DirectiveName];
+                } else {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: IdMark",
+                        expectation
+                    )));
+                }
+            }
+            ENext::Assign(_) if is_in(expectation, &EExpectation::AliasAssign) => {
+                *expectation = vec![EExpectation::AliasType];
+            }
+            ENext::Assign(_) if is_in(expectation, &EExpectation::ConstraintAssign) => {
+                *expectation = vec![EExpectation::ConstraintValue];
+            }
+            ENext::Assign(_) if is_in(expectation, &EExpectation::FieldDefaultMark) => {
+                *expectation = vec![EExpectation::FieldDefault];
+            }
+            ENext::Assign(_) => {
+                // Fields and enum items get ID in same way: name = N;
+                if !is_in(expectation, &EExpectation::FieldIdAssign) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: Assign",
+                        expectation
+                    )));
+                }
+                *expectation = vec![EExpectation::FieldId];
+            }
+            ENext::OpenGeneric(_) if is_in(expectation, &EExpectation::StructParamsOpen) => {
+                *expectation = vec![EExpectation::StructParam];
+            }
+            ENext::OpenGeneric(_) if is_in(expectation, &EExpectation::GenericOpen) => {
+                store.open_generic_arg()?;
+                *expectation = vec![EExpectation::GenericArg];
+            }
+            ENext::OpenGeneric(_) => {
+                if !is_in(expectation, &EExpectation::MapOpen) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: <",
+                        expectation
+                    )));
+                }
+                *expectation = vec![EExpectation::MapKey];
+            }
+            ENext::Comma(_) if is_in(expectation, &EExpectation::ConstraintSplitter) => {
+                *expectation = vec![EExpectation::ConstraintName];
+            }
+            ENext::Comma(_) if is_in(expectation, &EExpectation::ReservedSplitter) => {
+                *expectation = vec![EExpectation::ReservedValue];
+            }
+            ENext::OpenArgs(_) => {
+                if !is_in(expectation, &EExpectation::DirectiveArgsOpen) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: (",
+                        expectation
+                    )));
+                }
+                *expectation = vec![EExpectation::DirectiveArg];
+            }
+            ENext::CloseArgs(_) => {
+                if !is_in(expectation, &EExpectation::DirectiveArgsClose) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: )",
+                        expectation
+                    )));
+                }
+                *expectation = Self::after_deprecated(store);
+            }
+            ENext::Comma(_) if is_in(expectation, &EExpectation::StructParamsSplitter) => {
+                *expectation = vec![EExpectation::StructParam];
+            }
+            ENext::Comma(_) if is_in(expectation, &EExpectation::GenericSplitter) => {
+                store.open_generic_arg()?;
+                *expectation = vec![EExpectation::GenericArg];
+            }
+            ENext::Comma(_) => {
+                if !is_in(expectation, &EExpectation::MapSplitter) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: ,",
+                        expectation
+                    )));
+                }
+                *expectation = vec![EExpectation::MapValue];
+            }
+            ENext::CloseGeneric(_) if is_in(expectation, &EExpectation::StructParamsClose) => {
+                *expectation = vec![
+                    EExpectation::EntityOpen,
+                    EExpectation::StructExtends,
+                ];
+            }
+            ENext::CloseGeneric(_) if is_in(expectation, &EExpectation::GenericClose) => {
+                *expectation = vec![
+                    EExpectation::FieldName,
+                    EExpectation::FieldRepeatedMark,
+                    EExpectation::FieldItemsOptionalMark,
+                ];
+            }
+            ENext::CloseGeneric(_) => {
+                if !is_in(expectation, &EExpectation::MapClose) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: >",
+                        expectation
+                    )));
+                }
+                *expectation = if store.close_map()? {
+                    vec![EExpectation::MapClose]
+                } else {
+                    vec![EExpectation::FieldName]
+                };
+            }
+            ENext::Number((value, _)) if is_in(expectation, &EExpectation::ConstraintValue) => {
+                store.set_constraint_value(EValue::Number(value.to_string()))?;
+                *expectation = vec![
+                    EExpectation::ConstraintSplitter,
+                    EExpectation::ConstraintsClose,
+                ];
+            }
+            ENext::Numeric((value, _)) if is_in(expectation, &EExpectation::ConstraintValue) => {
+                store.set_constraint_value(EValue::Number(value))?;
+                *expectation = vec![
+                    EExpectation::ConstraintSplitter,
+                    EExpectation::ConstraintsClose,
+                ];
+            }
+            ENext::Number((value, _)) if is_in(expectation, &EExpectation::FieldDefault) => {
+                store.set_value(EValue::Number(value.to_string()))?;
+                *expectation = vec![EExpectation::Semicolon];
+            }
+            ENext::Numeric((value, _)) => {
+                if !is_in(expectation, &EExpectation::FieldDefault) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: {}",
+                        expectation, value
+                    )));
+                }
+                store.set_value(EValue::Number(value))?;
+                *expectation = vec![EExpectation::Semicolon];
+            }
+            ENext::Number((id, _)) if is_in(expectation, &EExpectation::ReservedValue) => {
+                store.add_reserved_id(id)?;
+                *expectation = vec![
+                    EExpectation::ReservedSplitter,
+                    EExpectation::Semicolon,
+                ];
+            }
+            ENext::Number((id, _)) => {
+                if is_in(expectation, &EExpectation::EntityId) {
+                    store.set_entity_id(id)?;
+                    *expectation = vec![EExpectation::EntityOpen];
+                } else if is_in(expectation, &EExpectation::FieldId) {
+                    if store.is_field_opened() {
+                        store.set_field_id(id)?;
+                        *expectation = vec![
+                            EExpectation::Semicolon,
+                            EExpectation::FieldConstraintsOpen,
+                        ];
+                    } else {
+                        store.set_enum_item_id(id)?;
+                        *expectation = vec![EExpectation::Semicolon];
+                    }
+                } else {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: {}",
+                        expectation, id
+                    )));
+                }
+            }
+            ENext::PathSpliter(_) => {
+                if !is_in(expectation, &EExpectation::PathSpliter) {
+                    return Err(ParseError::Unexpected(format!(
+                        "Unexpecting next step: {:?}. Value: PathSpliter",
+                        expectation
+                    )));
+                }
+                if store.is_relation_opened() {
+                    *expectation = vec![EExpectation::RelationType];
+                    return Ok(());
                 }
+                if store.is_struct_extending() {
+                    *expectation = vec![EExpectation::StructParent];
+                    return Ok(());
+                }
+                if store.is_generic_opened() {
+                    *expectation = vec![EExpectation::GenericArg];
+                    return Ok(());
+                }
+                if !store.is_field_opened() && !store.is_enum_opened() {
+                    return Err(ParseError::Unexpected("Unexpecting : as soon as no open field or enum".to_owned()));
+                }
+                *expectation = if store.is_map_opened() {
+                    vec![EExpectation::MapValue]
+                } else {
+                    vec![EExpectation::FieldType]
+                };
             }
+            // Comments never reach this point, because they are handled by parse_file()
+            ENext::Comment(_) | ENext::End() => {}
+        };
+        Ok(())
+    }
+
+    fn after_semicolon() -> Vec<EExpectation> {
+        vec![
+            EExpectation::FieldType,
+            EExpectation::StructDef,
+            EExpectation::EnumDef,
+            EExpectation::EnumValue,
+            EExpectation::RelationKey,
+            EExpectation::EntityClose,
+        ]
+    }
+
+    // @deprecated marks struct, if it's placed in root, in group or in struct; field or enum item otherwise
+    fn after_deprecated(store: &Store) -> Vec<EExpectation> {
+        if store.is_entity_opened() {
+            vec![EExpectation::FieldType, EExpectation::StructDef]
+        } else {
+            vec![EExpectation::StructDef]
         }
-        if errs.is_empty() {
-            match store.order() {
-                Ok(_) => Ok(store),
-                Err(e) => Err(vec![e]),
+    }
+
+    // Field, item or record is dropped; next one is expected, or next entity if it was a constant
+    fn after_recovery(store: &Store) -> Vec<EExpectation> {
+        if store.is_entity_opened() {
+            Self::after_semicolon()
+        } else {
+            Self::after_close()
+        }
+    }
+
+    fn after_close() -> Vec<EExpectation> {
+        vec![
+            EExpectation::FieldType, // Only if it's nested struct
+            EExpectation::GroupDef,
+            EExpectation::StructDef,
+            EExpectation::EnumDef,
+            EExpectation::EntityClose,
+        ]
+    }
+
+    fn get_offset(enext: &ENext) -> usize {
+        match enext {
+            ENext::Word((_, offset, _)) => *offset,
+            ENext::Number((_, offset)) => *offset,
+            ENext::Comment((_, offset)) => *offset,
+            ENext::Literal((_, offset)) => *offset,
+            ENext::Numeric((_, offset)) => *offset,
+            ENext::FixedSize((_, offset)) => *offset,
+            ENext::OpenStruct(offset)
+            | ENext::CloseStruct(offset)
+            | ENext::Semicolon(offset)
+            | ENext::Space(offset)
+            | ENext::Repeated(offset)
+            | ENext::OpenConstraints(offset)
+            | ENext::Optional(offset)
+            | ENext::PathSpliter(offset)
+            | ENext::IdMark(offset)
+            | ENext::Assign(offset)
+            | ENext::OpenGeneric(offset)
+            | ENext::CloseGeneric(offset)
+            | ENext::Comma(offset)
+            | ENext::OpenArgs(offset)
+            | ENext::CloseArgs(offset) => *offset,
+            ENext::End() => 0,
+        }
+    }
+
+    // Removes comment's marks and returns lines of comment
+    fn get_doc(text: &str) -> Vec<String> {
+        fn clean(line: &str) -> String {
+            line.strip_prefix(' ').unwrap_or(line).trim_end().to_string()
+        }
+        if let Some(body) = text.strip_prefix("/*") {
+            let body = body.strip_suffix("*/").unwrap_or(body);
+            let mut lines: Vec<String> = body.lines().map(|line| {
+                let line = line.trim_start();
+                clean(line.strip_prefix('*').unwrap_or(line))
+            }).collect();
+            while lines.first().is_some_and(|line| line.is_empty()) {
+                lines.remove(0);
             }
+            while lines.last().is_some_and(|line| line.is_empty()) {
+                lines.pop();
+            }
+            lines
         } else {
-            Err(errs)
+            vec![clean(text.trim_start_matches('#').trim_start_matches('/'))]
         }
     }
 
-    fn next(&mut self, content: String) -> Result<ENext, ENextErr> {
+    fn location(src: &Path, content: &str, span: &Span) -> Location {
+        Location::new(src.to_path_buf(), content, span)
+    }
+
+    fn next(&mut self, content: String) -> Result<(ENext, Span), (ENextErr, Span)> {
         let mut str: String = String::new();
         let mut pass: usize = 0;
-        let break_chars: Vec<char> = vec![';', '{', '}', '?', ':'];
+        let mut start: usize = 0;
+        let mut numeric: bool = false;
+        let break_chars: Vec<char> = vec![';', '{', '}', '?', ':', '=', '@', '<', '>', ',', '(', ')'];
         let special_chars: Vec<char> = vec!['[', ']'];
         let allowed_chars: Vec<char> = vec!['_'];
         for char in content.chars() {
             pass += 1;
+            let char_span = Span::new(pass - 1, pass);
             if !char.is_ascii() {
-                return Err(ENextErr::NotAscii(format!(
+                return Err((ENextErr::NotAscii(format!(
                     "found not ascii char: {}",
                     char
-                )));
+                )), Span::new(pass - 1, pass - 1 + char.len_utf8())));
             }
-            if char.is_ascii_digit() && str.is_empty() {
-                return Err(ENextErr::NumericFirst());
+            if char == '#' || char == '"' || (char == '/' && (content[pass..].starts_with('/') || content[pass..].starts_with('*'))) {
+                if str.is_empty() {
+                    return if char == '"' {
+                        Self::get_literal(&content, pass - 1)
+                    } else {
+                        Self::get_comment(&content, pass - 1)
+                    };
+                }
+                let word_span = Span::new(start, pass - 1);
+                if numeric {
+                    return Self::get_number(&str, pass - 1, word_span);
+                }
+                return Ok((ENext::Word((str, pass - 1, None)), word_span));
+            }
+            let signed: bool = char == '-' && content[pass..].starts_with(|c: char| c.is_ascii_digit());
+            if (char.is_ascii_digit() || signed) && str.is_empty() {
+                numeric = true;
+            } else if numeric && !char.is_ascii_digit() && char != '.' && !char.is_ascii_whitespace() && !break_chars.contains(&char) && !special_chars.contains(&char) {
+                return Err((ENextErr::NumericFirst(), Span::new(start, pass)));
             }
             if char.is_ascii_whitespace() && str.is_empty() {
                 continue;
             }
             let mut breakable: Option<char> = None;
-            if break_chars.iter().any(|&c| c == char) {
+            if break_chars.contains(&char) {
                 breakable = Some(char);
             }
             if breakable.is_some() && str.is_empty() {
                 match char {
-                    ';' => return Ok(ENext::Semicolon(pass)),
-                    '{' => return Ok(ENext::OpenStruct(pass)),
-                    '}' => return Ok(ENext::CloseStruct(pass)),
-                    '?' => return Ok(ENext::Optional(pass)),
-                    ':' => return Ok(ENext::PathSpliter(pass)),
+                    ';' => return Ok((ENext::Semicolon(pass), char_span)),
+                    '{' => return Ok((ENext::OpenStruct(pass), char_span)),
+                    '}' => return Ok((ENext::CloseStruct(pass), char_span)),
+                    '?' => return Ok((ENext::Optional(pass), char_span)),
+                    ':' => return Ok((ENext::PathSpliter(pass), char_span)),
+                    '=' => return Ok((ENext::Assign(pass), char_span)),
+                    '@' => return Ok((ENext::IdMark(pass), char_span)),
+                    '<' => return Ok((ENext::OpenGeneric(pass), char_span)),
+                    '>' => return Ok((ENext::CloseGeneric(pass), char_span)),
+                    ',' => return Ok((ENext::Comma(pass), char_span)),
+                    '(' => return Ok((ENext::OpenArgs(pass), char_span)),
+                    ')' => return Ok((ENext::CloseArgs(pass), char_span)),
                     _ => {}
                 };
             }
-            let special: bool = special_chars.iter().any(|&c| c == char);
+            let special: bool = special_chars.contains(&char);
             if special {
                 match char {
                     '[' => {
                         if !str.is_empty() {
                             breakable = Some(char);
+                        } else if let Some(end) = content[pass..].find([']', '\n']).filter(|end| content[pass + end..].starts_with(']')) {
+                            let size: &str = content[pass..pass + end].trim();
+                            if size.is_empty() {
+                                continue;
+                            }
+                            if !size.starts_with(|c: char| c.is_ascii_digit()) {
+                                return Ok((ENext::OpenConstraints(pass), char_span));
+                            }
+                            let size_span = Span::new(pass - 1, pass + end + 1);
+                            return match size.parse::<usize>() {
+                                Ok(size) => Ok((ENext::FixedSize((size, pass + end + 1)), size_span)),
+                                Err(_) => Err((ENextErr::NotSupported(format!(
+                                    "invalid size of array: {}",
+                                    size
+                                )), size_span)),
+                            };
                         } else {
-                            continue;
+                            return Ok((ENext::OpenConstraints(pass), char_span));
                         }
                     }
                     ']' => {
-                        if let Some(c) = str.chars().next() {
-                            if c != '[' {
-                                return Err(ENextErr::NotSupported(format!(
-                                    "found not supportable char: {}",
-                                    char
-                                )));
-                            }
+                        if str.is_empty() {
+                            return Ok((ENext::Repeated(pass), char_span));
                         }
-                        return Ok(ENext::Repeated(pass));
+                        // Closes constraints after value: [max_len=32]
+                        breakable = Some(char);
                     }
                     _ => {}
                 };
             }
-            if char.is_ascii_whitespace() || breakable.is_some() {
-                return Ok(ENext::Word((str, pass - 1, breakable)));
+            let word_span = Span::new(start, pass - 1);
+            if numeric && (char.is_ascii_whitespace() || breakable.is_some()) {
+                return Self::get_number(&str, pass - 1, word_span);
+            }
+            if char.is_ascii_whitespace() {
+                // Take the next meaningful char to let the parser know what follows the word
+                let next_char: Option<char> = content[pass..].chars().find(|c| !c.is_ascii_whitespace());
+                return Ok((ENext::Word((str, pass - 1, next_char)), word_span));
+            }
+            if breakable.is_some() {
+                return Ok((ENext::Word((str, pass - 1, breakable)), word_span));
             }
-            let allowed: bool = allowed_chars.iter().any(|&c| c == char);
+            let allowed: bool = allowed_chars.contains(&char) || (numeric && (char == '.' || char == '-'));
             if !char.is_ascii_alphanumeric() && !allowed {
-                return Err(ENextErr::NotSupported(format!(
+                return Err((ENextErr::NotSupported(format!(
                     "found not supportable char: {}",
                     char
-                )));
+                )), char_span));
+            }
+            if str.is_empty() {
+                start = pass - 1;
             }
             str.push(char);
         }
         if str.is_empty() {
-            Ok(ENext::End())
+            Ok((ENext::End(), Span::new(pass, pass)))
+        } else if numeric {
+            Self::get_number(&str, pass, Span::new(start, pass))
         } else {
-            Ok(ENext::Word((str, pass - 1, None)))
+            Ok((ENext::Word((str, pass, None)), Span::new(start, pass)))
+        }
+    }
+
+    // Comments: "# text", "// text" or "/* text */"
+    fn get_comment(content: &str, from: usize) -> Result<(ENext, Span), (ENextErr, Span)> {
+        let rest: &str = &content[from..];
+        let len: usize = if let Some(body) = rest.strip_prefix("/*") {
+            match body.find("*/") {
+                Some(pos) => pos + 4,
+                None => {
+                    return Err((ENextErr::NotSupported(String::from("comment isn't closed")), Span::new(from, content.len())));
+                }
+            }
+        } else {
+            rest.find('\n').unwrap_or(rest.len())
+        };
+        Ok((ENext::Comment((rest[..len].to_string(), from + len)), Span::new(from, from + len)))
+    }
+
+    // String literal in double quotes. Supports escaping of quotes, backslashes, \n and \t
+    fn get_literal(content: &str, from: usize) -> Result<(ENext, Span), (ENextErr, Span)> {
+        let mut value: String = String::new();
+        let mut escaped: bool = false;
+        for (pos, char) in content[from + 1..].char_indices() {
+            if char == '\n' {
+                break;
+            }
+            if escaped {
+                match char {
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    '"' | '\\' => value.push(char),
+                    _ => {
+                        return Err((ENextErr::InvalidEscape((format!(
+                            "unknown escape sequence: \\{}",
+                            char
+                        ), Self::get_literal_end(content, from + 2 + pos))), Span::new(from + pos, from + 2 + pos)));
+                    }
+                };
+                escaped = false;
+            } else if char == '\\' {
+                escaped = true;
+            } else if char == '"' {
+                let to = from + 2 + pos;
+                return Ok((ENext::Literal((value, to)), Span::new(from, to)));
+            } else {
+                value.push(char);
+            }
+        }
+        let to = content[from..].find('\n').map_or(content.len(), |pos| from + pos);
+        Err((ENextErr::NotSupported(String::from("string literal isn't closed")), Span::new(from, to)))
+    }
+
+    // Position after closing quote of string literal (or end of line, if literal isn't closed)
+    fn get_literal_end(content: &str, from: usize) -> usize {
+        let mut escaped: bool = false;
+        for (pos, char) in content[from..].char_indices() {
+            if char == '\n' {
+                return from + pos;
+            }
+            if escaped {
+                escaped = false;
+            } else if char == '\\' {
+                escaped = true;
+            } else if char == '"' {
+                return from + pos + 1;
+            }
+        }
+        content.len()
+    }
+
+    fn get_number(str: &str, offset: usize, span: Span) -> Result<(ENext, Span), (ENextErr, Span)> {
+        match str.parse::<usize>() {
+            Ok(num) => Ok((ENext::Number((num, offset)), span)),
+            Err(_) if str.parse::<f64>().is_ok() => Ok((ENext::Numeric((str.to_string(), offset)), span)),
+            // Range: MIN..MAX
+            Err(_) if str.split_once("..").is_some_and(|(min, max)| min.parse::<f64>().is_ok() && max.parse::<f64>().is_ok()) => {
+                Ok((ENext::Numeric((str.to_string(), offset)), span))
+            }
+            Err(e) => Err((ENextErr::NotSupported(format!(
+                "fail to parse number {}: {}",
+                str, e
+            )), span)),
         }
     }
 
-    pub fn get_content(&self, target: PathBuf) -> Result<String, String> {
+    pub fn get_content(&self, target: PathBuf) -> Result<String, ParseError> {
         if !target.exists() {
-            Err(format!(
+            Err(ParseError::Io(format!(
                 "File {} doesn't exists",
-                target.as_path().display().to_string()
-            ))
+                target.as_path().display()
+            )))
         } else {
             match fs::read_to_string(target.as_path()) {
                 Ok(content) => Ok(content),
-                Err(e) => Err(e.to_string()),
+                Err(e) => Err(ParseError::Io(e.to_string())),
             }
         }
     }
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::path::PathBuf;
+
+// Range of bytes [from, to) in source file
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Span {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Span {
+
+    pub fn new(from: usize, to: usize) -> Self {
+        Span { from, to }
+    }
+
+    pub fn shift(&self, offset: usize) -> Self {
+        Span {
+            from: self.from + offset,
+            to: self.to + offset,
+        }
+    }
+
+}
+
+// Human readable position of span in source file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Location {
+    pub file: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub len: usize,
+    pub source: String,
+}
+
+impl Location {
+
+    pub fn new(file: PathBuf, content: &str, span: &Span) -> Self {
+        let from = span.from.min(content.len());
+        let line_start = content[..from].rfind('\n').map_or(0, |pos| pos + 1);
+        let line_end = content[from..].find('\n').map_or(content.len(), |pos| from + pos);
+        let source = content[line_start..line_end].trim_end_matches('\r').to_string();
+        let line = content[..from].matches('\n').count() + 1;
+        // Column and length are counted in chars, because caret is placed under chars of line
+        let column = content[line_start..from].chars().count() + 1;
+        let len = content.get(from..span.to.min(line_end).max(from)).map_or(0, |text| text.chars().count()).max(1);
+        Location {
+            file,
+            line,
+            column,
+            len,
+            source,
+        }
+    }
+
+}
+
+impl fmt::Display for Location {
+
+    // Renders location in rustc style:
+    //   --> file.prot:2:5
+    //    |
+    //  2 |     str 1name;
+    //    |         ^^^^^
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}--> {}:{}:{}", gutter, self.file.display(), self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source)?;
+        write!(f, "{} | {}{}", gutter, " ".repeat(self.column - 1), "^".repeat(self.len))
+    }
+
+}
@@ -1,4 +1,15 @@
-use super::{ Field, Enum, Struct, Group, stop };
+use super::{ Alias, Const, Constraint, EValue, Field, Enum, Struct, Group, Relation, Identification, Location, ParseError, PrimitiveTypes };
+use super::identification::names as identification_names;
+use super::lock::{ Lock, kinds };
+use super::relations::keys as relation_keys;
+use super::constraints::names as constraint_names;
+use std::collections::HashMap;
+
+const MAX_ENTITY_ID: usize = u32::MAX as usize;
+const MAX_FIELD_ID: usize = u16::MAX as usize;
+
+// Entity, which gets ID: (kind, path, explicit ID, preferred ID, location of declaration)
+type IdCandidate = (&'static str, String, Option<usize>, usize, Option<Location>);
 
 #[derive(Debug, Clone)]
 pub struct Store {
@@ -6,11 +17,38 @@ pub struct Store {
     pub structs: Vec<Struct>,
     pub enums: Vec<Enum>,
     pub groups: Vec<Group>,
+    pub relations: Vec<Relation>,
+    pub consts: Vec<Const>,
+    pub aliases: Vec<Alias>,
+    // Templates of structs; instances of templates are added into structs by order()
+    pub templates: Vec<Struct>,
+    // IDs of structs, which are sent by producer without request
+    pub broadcasts: Vec<usize>,
+    pub identification: Option<Identification>,
     c_struct: Option<Struct>,
+    // Structs, which wait for closing of struct or enum, declared inside of them
+    c_owners: Vec<Struct>,
     c_group: Option<Group>,
     c_enum: Option<Enum>,
     c_field: Option<Field>,
+    c_relation: Option<Relation>,
+    c_const: Option<Const>,
+    c_alias: Option<Alias>,
+    c_broadcasts: bool,
+    c_identification: Option<Identification>,
+    // Name of constraint of field, which waits for value
+    c_constraint: Option<String>,
+    // Path to parent struct of opened struct: struct AdminUser extends User { ... }
+    c_extends: Vec<String>,
+    c_extends_location: Option<Location>,
+    // Instances of templates: ("Path.Template<Path.Arg, ...>", ID of struct)
+    instances: Vec<(String, usize)>,
     path: Vec<usize>,
+    doc: Vec<String>,
+    // Reason of deprecation of next struct, field or enum item: @deprecated("reason")
+    deprecated: Option<String>,
+    // Next enum is C-like: @numeric enum Role { ... }
+    numeric: bool,
 }
 
 impl Store {
@@ -22,36 +60,59 @@ impl Store {
             structs: vec![],
             enums: vec![],
             groups: vec![],
+            relations: vec![],
+            consts: vec![],
+            aliases: vec![],
+            templates: vec![],
+            broadcasts: vec![],
+            identification: None,
             c_struct: None,
+            c_owners: vec![],
             c_enum: None,
             c_field: None,
             c_group: None,
+            c_relation: None,
+            c_const: None,
+            c_alias: None,
+            c_broadcasts: false,
+            c_identification: None,
+            c_constraint: None,
+            c_extends: vec![],
+            c_extends_location: None,
+            instances: vec![],
             path: vec![],
+            doc: vec![],
+            deprecated: None,
+            numeric: false,
         }
     }
 
     pub fn get_struct(&self, id: usize) -> Option<Struct> {
-        if let Some(strct) = self.structs.iter().find(|s| s.id == id) {
-            Some(strct.clone())
-        } else {
-            None
-        }
+        self.structs.iter().find(|s| s.id == id).cloned()
     }
 
     pub fn get_enum(&self, id: usize) -> Option<Enum> {
-        if let Some(enums) = self.enums.iter().find(|s| s.id == id) {
-            Some(enums.clone())
-        } else {
-            None
-        }
+        self.enums.iter().find(|s| s.id == id).cloned()
     }
 
     pub fn get_group(&self, id: usize) -> Option<Group> {
-        if let Some(group) = self.groups.iter().find(|s| s.id == id) {
-            Some(group.clone())
-        } else {
-            None
-        }
+        self.groups.iter().find(|s| s.id == id).cloned()
+    }
+
+    pub fn get_relation(&self, id: usize) -> Option<Relation> {
+        self.relations.iter().find(|s| s.id == id).cloned()
+    }
+
+    pub fn get_const(&self, id: usize) -> Option<Const> {
+        self.consts.iter().find(|c| c.id == id).cloned()
+    }
+
+    pub fn get_alias(&self, id: usize) -> Option<Alias> {
+        self.aliases.iter().find(|a| a.id == id).cloned()
+    }
+
+    pub fn is_broadcast(&self, id: usize) -> bool {
+        self.broadcasts.contains(&id)
     }
 
     pub fn get_child_groups(&mut self, parent_id: usize) -> Vec<Group> {
@@ -64,75 +125,546 @@ impl Store {
         groups
     }
 
-    pub fn open_struct(&mut self, name: String) {
-        if self.c_struct.is_some() {
-            stop!("Struct cannot be defined inside struct");
-        }
+    // Entity is scoped, if it's declared inside of struct. Such entities aren't messages
+    pub fn is_scoped(&self, parent: usize) -> bool {
+        self.groups.iter().any(|group| group.id == parent && group.owner.is_some())
+    }
+
+    pub fn open_struct(&mut self, name: String) -> Result<(), ParseError> {
         if self.c_enum.is_some() {
-            stop!("Struct cannot be defined inside enum");
+            return Err(ParseError::Structure(String::from("Struct cannot be defined inside enum")));
+        }
+        if self.c_relation.is_some() {
+            return Err(ParseError::Structure(String::from("Struct cannot be defined inside relation")));
+        }
+        if self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Struct cannot be defined inside @Identification")));
+        }
+        if self.c_struct.is_some() {
+            self.open_struct_scope()?;
         }
         self.sequence += 1;
         self.bind_struct_with_group(self.sequence);
-        self.c_struct = Some(Struct::new(self.sequence, self.get_group_id(), name));
+        let mut c_struct = Struct::new(self.sequence, self.get_group_id(), name);
+        c_struct.set_doc(self.take_doc());
+        c_struct.set_deprecated(self.deprecated.take());
+        self.c_struct = Some(c_struct);
+        Ok(())
     }
 
-    pub fn open_enum(&mut self, name: String) {
-        if self.c_struct.is_some() {
-            stop!("Enum cannot be defined inside struct");
+    pub fn add_struct_param(&mut self, name: &str) -> Result<(), ParseError> {
+        if !self.c_owners.is_empty() {
+            return Err(ParseError::Structure(String::from("Template cannot be defined inside struct")));
+        }
+        if let Some(c_struct) = self.c_struct.as_mut() {
+            c_struct.add_param(name)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set parameter of template, because no open struct.")))
+        }
+    }
+
+    pub fn add_struct_parent_path(&mut self, type_str: &str) -> Result<(), ParseError> {
+        if self.c_struct.is_none() {
+            return Err(ParseError::Structure(String::from("Fail to set parent of struct, because no open struct.")));
         }
+        self.c_extends.push(type_str.to_string());
+        Ok(())
+    }
+
+    pub fn is_struct_extending(&self) -> bool {
+        self.c_struct.is_some() && !self.c_extends.is_empty()
+    }
+
+    // Fields of parent struct are copied into opened struct. Inherited fields keep own IDs,
+    // so they have same wire IDs as fields of parent
+    pub fn extend_struct(&mut self) -> Result<(), ParseError> {
+        if self.c_extends.is_empty() {
+            return Ok(());
+        }
+        let parent_path: Vec<String> = self.c_extends.drain(..).collect();
+        let location = self.c_extends_location.take();
+        let mut c_struct = if let Some(c_struct) = self.c_struct.take() {
+            c_struct
+        } else {
+            return Err(ParseError::Structure(String::from("Fail to extend struct, because no open struct.")));
+        };
+        let result = self.inherit_fields(&mut c_struct, &parent_path).map_err(|err| err.at_entity(&location));
+        self.c_struct = Some(c_struct);
+        result
+    }
+
+    // Entity, which has been just opened, gets location of its declaration. Location is used to report
+    // errors, which are found after parsing: conflicts of IDs, failed expanding of templates
+    pub fn locate<F: FnOnce() -> Location>(&mut self, location: F) {
+        if let Some(slot) = self.get_unlocated() {
+            *slot = Some(location());
+        }
+    }
+
+    fn get_unlocated(&mut self) -> Option<&mut Option<Location>> {
+        if self.c_field.as_ref().is_some_and(|field| field.location.is_none()) {
+            self.c_field.as_mut().map(|field| &mut field.location)
+        } else if !self.c_extends.is_empty() && self.c_extends_location.is_none() {
+            Some(&mut self.c_extends_location)
+        } else if self.c_struct.as_ref().is_some_and(|strct| strct.location.is_none()) {
+            self.c_struct.as_mut().map(|strct| &mut strct.location)
+        } else if let Some(c_enum) = self.c_enum.as_mut() {
+            c_enum.get_unlocated()
+        } else {
+            None
+        }
+    }
+
+    // References of field are resolved from root group; it's used, when field is moved to another struct
+    fn set_absolute_type_path(&self, field: &mut Field) {
+        let type_path = field.ref_type_id.or(field.newtype).or(field.numeric).map(|type_id| self.get_entity_path(type_id));
+        if let Some(path) = type_path.and_then(|type_path| self.find_by_path(0, &type_path)) {
+            field.set_absolute_type_path(&path);
+        }
+    }
+
+    fn inherit_fields(&self, c_struct: &mut Struct, parent_path: &[String]) -> Result<(), ParseError> {
+        let path = if let Some(path) = self.find_by_path(c_struct.parent, parent_path) {
+            path
+        } else if let Some(path) = self.find_by_path(0, parent_path) {
+            path
+        } else {
+            return Err(ParseError::UnknownType(format!("Fail to find parent struct {} of struct {}", parent_path.join(":"), c_struct.name)));
+        };
+        let (_, parent_id) = path[path.len() - 1];
+        let parent = if let Some(parent) = self.get_struct(parent_id) {
+            parent
+        } else {
+            return Err(ParseError::UnknownType(format!("Struct {} can extend only struct; {} isn't", c_struct.name, parent_path.join(":"))));
+        };
+        for mut field in parent.fields.into_iter() {
+            if self.c_identification.is_some() && (field.ref_type_id.is_some() || field.newtype.is_some() || field.numeric.is_some() || field.key.is_some() || !field.generic.is_empty()) {
+                return Err(ParseError::UnknownType(format!("Field \"{}\" of @Identification should have primitive type", field.name)));
+            }
+            // Parent can be declared in another group
+            self.set_absolute_type_path(&mut field);
+            c_struct.add_field(field)?;
+        }
+        Ok(())
+    }
+
+    pub fn open_enum(&mut self, name: String) -> Result<(), ParseError> {
         if self.c_enum.is_some() {
-            stop!("Enum cannot be defined inside enum");
+            return Err(ParseError::Structure(String::from("Enum cannot be defined inside enum")));
+        }
+        if self.c_relation.is_some() {
+            return Err(ParseError::Structure(String::from("Enum cannot be defined inside relation")));
+        }
+        if self.c_broadcasts {
+            return Err(ParseError::Structure(String::from("Enum cannot be defined inside @broadcasts")));
+        }
+        if self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Enum cannot be defined inside @Identification")));
+        }
+        if self.c_struct.is_some() {
+            self.open_struct_scope()?;
         }
         self.sequence += 1;
         self.bind_enum_with_group(self.sequence);
-        self.c_enum = Some(Enum::new(self.sequence, self.get_group_id(), name));
+        let mut c_enum = Enum::new(self.sequence, self.get_group_id(), name);
+        c_enum.set_doc(self.take_doc());
+        c_enum.set_numeric(std::mem::take(&mut self.numeric));
+        self.c_enum = Some(c_enum);
+        Ok(())
+    }
+
+    // Struct or enum, which is declared inside of struct, is placed into the scope of struct: group,
+    // which is named as struct and owned by it. Struct waits, while nested entity is defined
+    fn open_struct_scope(&mut self) -> Result<(), ParseError> {
+        let c_struct = if let Some(c_struct) = self.c_struct.take() {
+            c_struct
+        } else {
+            return Err(ParseError::Structure(String::from("Fail to open scope of struct, because no open struct")));
+        };
+        if c_struct.is_template() {
+            let err = ParseError::Structure(format!("Struct or enum cannot be defined inside template {}", c_struct.name));
+            self.c_struct = Some(c_struct);
+            return Err(err);
+        }
+        let scope = if let Some(pos) = self.groups.iter().position(|g| g.owner == Some(c_struct.id)) {
+            // Scope has been created by previous nested entity
+            if let Some(c_group) = self.c_group.take() {
+                self.groups.push(c_group);
+            }
+            self.groups.remove(pos)
+        } else {
+            let parent: usize = self.get_group_id();
+            self.sequence += 1;
+            self.bind_group_with_group(self.sequence);
+            let mut scope = Group::new(self.sequence, parent, c_struct.name.clone());
+            scope.set_owner(c_struct.id);
+            scope
+        };
+        self.path.push(scope.id);
+        self.c_group = Some(scope);
+        self.c_owners.push(c_struct);
+        Ok(())
+    }
+
+    // Nested struct or enum is closed; struct, which owns it, gets fields again
+    fn close_struct_scope(&mut self) -> Result<(), ParseError> {
+        if let Some(owner) = self.c_owners.pop() {
+            self.c_struct = Some(owner);
+            self.close_group()?;
+        }
+        Ok(())
+    }
+
+    // Returns group, from which type should be resolved, and name of struct, if type is declared
+    // inside of opened struct; such type is referred with name of struct as prefix. Types of
+    // group, where struct is declared, are available inside of nested structs and enums
+    fn get_type_scope(&self, type_path: &[String]) -> (usize, Option<String>) {
+        let group_id = self.get_group_id();
+        if let Some(c_struct) = self.c_struct.as_ref() {
+            if let Some(scope) = self.groups.iter().find(|g| g.owner == Some(c_struct.id)) {
+                if self.find_by_path(scope.id, type_path).is_some() {
+                    return (group_id, Some(c_struct.name.clone()));
+                }
+            }
+        }
+        let mut parent = group_id;
+        while let Some(scope) = self.groups.iter().chain(self.c_group.iter()).find(|g| g.id == parent && g.owner.is_some()) {
+            if self.find_by_path(parent, type_path).is_some() {
+                break;
+            }
+            parent = scope.parent;
+        }
+        (parent, None)
+    }
+
+    pub fn open_relation(&mut self, name: String) -> Result<(), ParseError> {
+        if self.c_struct.is_some() || self.c_enum.is_some() || self.c_relation.is_some() || self.c_broadcasts || self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Relation can be defined only in root or in group")));
+        }
+        if self.relations.iter().any(|r| r.name == name && r.parent == self.get_group_id()) {
+            return Err(ParseError::Duplicate(format!("Relation \"{}\" already exists", name)));
+        }
+        self.sequence += 1;
+        self.bind_relation_with_group(self.sequence);
+        let mut c_relation = Relation::new(self.sequence, self.get_group_id(), name);
+        c_relation.set_doc(self.take_doc());
+        self.c_relation = Some(c_relation);
+        Ok(())
+    }
+
+    pub fn set_relation_key(&mut self, key: &str) -> Result<(), ParseError> {
+        if key != relation_keys::CONCLUSION {
+            // Only conclusions have docs
+            self.take_doc();
+        }
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.set_key(key)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set relation key, because no open relation")))
+        }
+    }
+
+    pub fn set_relation_optional(&mut self) -> Result<(), ParseError> {
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.set_optional()
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set broadcast as optional, because no open relation")))
+        }
+    }
+
+    // Conclusion's block is opened right after type of conclusion, so type has to be accepted first
+    pub fn open_conclusion_block(&mut self) -> Result<(), ParseError> {
+        self.close_relation_record()?;
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.open_block()
+        } else {
+            Err(ParseError::Structure(String::from("Fail to open conclusion, because no open relation")))
+        }
+    }
+
+    // Structs, which are defined in @broadcasts block, are registered as broadcasts
+    pub fn open_broadcasts(&mut self) -> Result<(), ParseError> {
+        if self.c_struct.is_some() || self.c_enum.is_some() || self.c_relation.is_some() || self.c_broadcasts || self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("@broadcasts can be defined only in root or in group")));
+        }
+        self.take_doc();
+        self.c_broadcasts = true;
+        Ok(())
+    }
+
+    pub fn open_const(&mut self, kind: &str) -> Result<(), ParseError> {
+        if self.c_struct.is_some() || self.c_enum.is_some() || self.c_relation.is_some() || self.c_broadcasts || self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Constant can be defined only in root or in group")));
+        }
+        self.sequence += 1;
+        let mut c_const = Const::new(self.sequence, self.get_group_id(), kind.to_string())?;
+        c_const.set_doc(self.take_doc());
+        self.c_const = Some(c_const);
+        Ok(())
+    }
+
+    pub fn set_const_name(&mut self, name: &str) -> Result<(), ParseError> {
+        let parent = self.get_group_id();
+        if self.consts.iter().any(|c| c.name == name && c.parent == parent) {
+            return Err(ParseError::Duplicate(format!("Constant {} already exists", name)));
+        }
+        if let Some(c_const) = self.c_const.as_mut() {
+            c_const.set_name(name.to_string());
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set name of constant, because no open constant")))
+        }
+    }
+
+    pub fn set_value(&mut self, value: EValue) -> Result<(), ParseError> {
+        if let Some(c_const) = self.c_const.as_mut() {
+            c_const.set_value(value)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set value, because no open constant")))
+        }
+    }
+
+    pub fn close_const(&mut self) -> Result<(), ParseError> {
+        if let Some(c_const) = self.c_const.take() {
+            c_const.validate()?;
+            self.bind_const_with_group(c_const.id);
+            self.consts.push(c_const);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to close constant, because no open constant")))
+        }
+    }
+
+    pub fn open_alias(&mut self, newtype: bool) -> Result<(), ParseError> {
+        if self.c_struct.is_some() || self.c_enum.is_some() || self.c_relation.is_some() || self.c_broadcasts || self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Alias can be defined only in root or in group")));
+        }
+        self.sequence += 1;
+        let mut c_alias = Alias::new(self.sequence, self.get_group_id(), newtype);
+        c_alias.set_doc(self.take_doc());
+        self.c_alias = Some(c_alias);
+        Ok(())
+    }
+
+    pub fn set_alias_name(&mut self, name: &str) -> Result<(), ParseError> {
+        let parent = self.get_group_id();
+        if self.find_by_path(parent, &[name.to_string()]).is_some() {
+            return Err(ParseError::Duplicate(format!("Type {} already exists", name)));
+        }
+        if let Some(c_alias) = self.c_alias.as_mut() {
+            c_alias.set_name(name.to_string());
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set name of alias, because no open alias")))
+        }
     }
 
-    pub fn open_group(&mut self, name: String) {
+    // Alias can refer to another alias; in this case it gets type of referred alias
+    pub fn set_alias_type(&mut self, type_str: &str) -> Result<(), ParseError> {
+        let referred = self.find_alias(type_str);
+        if let Some(c_alias) = self.c_alias.as_mut() {
+            match referred {
+                Some(alias) if alias.newtype => Err(ParseError::UnknownType(format!("Alias {} cannot refer to newtype {}", c_alias.name, alias.name))),
+                Some(alias) => c_alias.set_type(&alias.kind),
+                None => c_alias.set_type(type_str),
+            }
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set type of alias, because no open alias")))
+        }
+    }
+
+    // Looks for alias in own group and after in root
+    fn find_alias(&self, name: &str) -> Option<Alias> {
+        let path = [name.to_string()];
+        self.find_by_path(self.get_group_id(), &path)
+            .or_else(|| self.find_by_path(0, &path))
+            .and_then(|path| self.get_alias(path[path.len() - 1].1))
+    }
+
+    pub fn close_alias(&mut self) -> Result<(), ParseError> {
+        if let Some(c_alias) = self.c_alias.take() {
+            c_alias.validate()?;
+            self.bind_alias_with_group(c_alias.id);
+            self.aliases.push(c_alias);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to close alias, because no open alias")))
+        }
+    }
+
+    // @Identification is a group with predefined structs of consumer's keys
+    pub fn open_identification(&mut self) -> Result<(), ParseError> {
+        if !self.is_root() {
+            return Err(ParseError::Structure(String::from("@Identification can be defined only on top level")));
+        }
+        if self.identification.is_some() || self.groups.iter().any(|g| g.name == identification_names::GROUP && g.parent == 0) {
+            return Err(ParseError::Duplicate(format!("Group {} already exists", identification_names::GROUP)));
+        }
+        self.open_group(identification_names::GROUP.to_string())?;
+        self.c_identification = Some(Identification::new(self.sequence));
+        Ok(())
+    }
+
+    pub fn open_identification_key(&mut self, key: &str) -> Result<(), ParseError> {
+        let name = Identification::get_struct_name(key)?;
+        if let Some(mut c_identification) = self.c_identification.take() {
+            let result = c_identification.set_key(key, self.sequence + 1);
+            self.c_identification = Some(c_identification);
+            result?;
+        } else {
+            return Err(ParseError::Structure(String::from("Fail to open key, because no open @Identification")));
+        }
+        self.sequence += 1;
+        self.bind_struct_with_group(self.sequence);
+        let mut c_struct = Struct::new(self.sequence, self.get_group_id(), name.to_string());
+        c_struct.set_doc(self.take_doc());
+        self.c_struct = Some(c_struct);
+        Ok(())
+    }
+
+    pub fn set_relation_conclusion(&mut self, name: &str) -> Result<(), ParseError> {
+        let doc = self.take_doc();
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.set_conclusion_name(name, doc)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set conclusion, because no open relation")))
+        }
+    }
+
+    pub fn set_relation_type(&mut self, type_str: &str) -> Result<(), ParseError> {
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.add_type_path(type_str);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set relation type, because no open relation")))
+        }
+    }
+
+    pub fn close_relation_record(&mut self) -> Result<(), ParseError> {
+        if let Some(mut c_relation) = self.c_relation.take() {
+            let result = c_relation.accept_type(self);
+            self.c_relation = Some(c_relation);
+            result
+        } else {
+            Err(ParseError::Structure(String::from("Fail to close relation record, because no open relation")))
+        }
+    }
+
+    pub fn open_group(&mut self, name: String) -> Result<(), ParseError> {
         if self.c_struct.is_some() {
-            stop!("Group cannot be defined inside struct");
+            return Err(ParseError::Structure(String::from("Group cannot be defined inside struct")));
         }
         if self.c_enum.is_some() {
-            stop!("Group cannot be defined inside enum");
+            return Err(ParseError::Structure(String::from("Group cannot be defined inside enum")));
+        }
+        if self.c_relation.is_some() {
+            return Err(ParseError::Structure(String::from("Group cannot be defined inside relation")));
+        }
+        if self.c_broadcasts {
+            return Err(ParseError::Structure(String::from("Group cannot be defined inside @broadcasts")));
         }
+        if self.c_identification.is_some() {
+            return Err(ParseError::Structure(String::from("Group cannot be defined inside @Identification")));
+        }
+        // Groups don't have docs
+        self.take_doc();
         let parent: usize = self.get_group_id();
         self.sequence += 1;
         self.bind_group_with_group(self.sequence);
         self.c_group = Some(Group::new(self.sequence, parent, name));
         self.path.push(self.sequence);
+        Ok(())
     }
 
-    pub fn set_field_type(&mut self, type_str: &str) {
+    pub fn set_field_type(&mut self, type_str: &str) -> Result<(), ParseError> {
         if self.c_struct.is_none() {
-            stop!("Fail to create new field, because no open struct.");
+            return Err(ParseError::Structure(String::from("Fail to create new field, because no open struct.")));
         }
         let mut c_field = if let Some(field) = self.c_field.take() {
             field
         } else {
             self.sequence += 1;
-            Field::new(self.sequence, 0, type_str.to_string())
+            let mut field = Field::new(self.sequence, 0, type_str.to_string());
+            field.set_doc(self.take_doc());
+            field.set_deprecated(self.deprecated.take());
+            field
         };
         c_field.add_type_path(type_str);
         self.c_field = Some(c_field);
+        Ok(())
+    }
+
+    // Opens field of map<K, V>; type of value is set with set_field_type
+    pub fn open_map_field(&mut self) -> Result<(), ParseError> {
+        if self.c_struct.is_none() {
+            return Err(ParseError::Structure(String::from("Fail to create new field, because no open struct.")));
+        }
+        if self.c_field.is_some() {
+            return Err(ParseError::Structure(String::from("Map can be defined only as type of field")));
+        }
+        self.sequence += 1;
+        let mut field = Field::new(self.sequence, 0, String::new());
+        field.set_doc(self.take_doc());
+        field.set_deprecated(self.deprecated.take());
+        field.open_map();
+        self.c_field = Some(field);
+        Ok(())
+    }
+
+    // Opens map, which is value of map: map<K, map<K1, V>>
+    pub fn open_nested_map(&mut self) -> Result<(), ParseError> {
+        if let Some(c_field) = self.c_field.as_mut().filter(|field| field.is_map_opened()) {
+            c_field.open_map();
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Nested map can be defined only as value of map")))
+        }
+    }
+
+    // Returns true, if closed map is value of other map, which is still opened
+    pub fn close_map(&mut self) -> Result<bool, ParseError> {
+        if let Some(c_field) = self.c_field.as_mut().filter(|field| field.is_map_opened()) {
+            Ok(c_field.close_map())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to close map, because it wasn't opened")))
+        }
+    }
+
+    pub fn set_map_key(&mut self, kind: &str) -> Result<(), ParseError> {
+        let alias = self.find_alias(kind);
+        if let Some(mut c_field) = self.c_field.take() {
+            let result = match alias {
+                Some(alias) if alias.newtype => Err(ParseError::UnknownType(format!("Newtype {} cannot be used as key of map", alias.name))),
+                Some(alias) => c_field.set_key(&alias.kind),
+                None => c_field.set_key(kind),
+            };
+            self.c_field = Some(c_field);
+            result
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set key of map, because field wasn't opened.")))
+        }
     }
 
-    pub fn find_by_path(&self, from: usize, path: &Vec<String>) -> Option<Vec<(String, usize)>> {
+    pub fn find_by_path(&self, from: usize, path: &[String]) -> Option<Vec<(String, usize)>> {
         let mut results: Vec<(String, usize)> = vec![];
         let last = path.len() - 1;
         let mut parent: usize = from;
         for (pos, type_str) in path.iter().enumerate() {
             if pos == last {
-                if let Some(enum_ref) = self.enums.iter().find(|i| i.name == String::from(type_str) && i.parent == parent) {
+                if let Some(enum_ref) = self.enums.iter().find(|i| i.name == *type_str && i.parent == parent) {
                     // Check enum in own group
                     results.push((String::from(type_str), enum_ref.id));
-                } else if let Some(struct_ref) = self.structs.iter().find(|i| i.name == String::from(type_str) && i.parent == parent) {
+                } else if let Some(struct_ref) = self.structs.iter().find(|i| i.name == *type_str && i.parent == parent) {
                     // Check struct in own group
                     results.push((String::from(type_str), struct_ref.id));
+                } else if let Some(alias_ref) = self.aliases.iter().find(|i| i.name == *type_str && i.parent == parent) {
+                    // Check alias in own group
+                    results.push((String::from(type_str), alias_ref.id));
                 } else {
                     return None;
                 }
             } else {
-                if let Some(group_ref) = self.groups.iter().find(|i| i.name == String::from(type_str) && i.parent == parent) {
+                if let Some(group_ref) = self.groups.iter().chain(self.c_group.iter()).find(|i| i.name == *type_str && i.parent == parent) {
                     results.push((String::from(type_str), group_ref.id));
                     parent = group_ref.id;
                 } else {
@@ -143,82 +675,345 @@ impl Store {
         Some(results)
     }
 
-    pub fn set_field_type_as_repeated(&mut self) {
+    pub fn open_generic_arg(&mut self) -> Result<(), ParseError> {
+        if let Some(c_field) = self.c_field.as_mut() {
+            c_field.open_generic_arg();
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set argument of template, because no open field")))
+        }
+    }
+
+    pub fn add_generic_arg_path(&mut self, type_str: &str) -> Result<(), ParseError> {
+        if let Some(c_field) = self.c_field.as_mut() {
+            c_field.add_generic_arg_path(type_str)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set argument of template, because no open field")))
+        }
+    }
+
+    pub fn is_generic_opened(&self) -> bool {
+        self.c_field.as_ref().is_some_and(|field| !field.generic.is_empty())
+    }
+
+    pub fn set_field_type_as_repeated(&mut self) -> Result<(), ParseError> {
         if let Some(mut c_enum) = self.c_enum.take() {
-            c_enum.set_as_repeated();
+            let result = c_enum.set_as_repeated();
             self.c_enum = Some(c_enum);
+            result
         } else if let Some(mut c_field) = self.c_field.take() {
             c_field.set_as_repeated();
             self.c_field = Some(c_field);
+            Ok(())
         } else {
-            stop!("Fail to set field as repeated, because it wasn't opened.");
+            Err(ParseError::Structure(String::from("Fail to set field as repeated, because it wasn't opened.")))
         }
     }
 
-    pub fn set_field_type_as_optional(&mut self) {
-        if let Some(mut c_field) = self.c_field.take() {
+    pub fn set_constraint_name(&mut self, name: &str) -> Result<(), ParseError> {
+        if self.c_field.is_none() {
+            return Err(ParseError::Structure(String::from("Fail to set constraint, because no open field")));
+        }
+        self.c_constraint = Some(name.to_string());
+        Ok(())
+    }
+
+    pub fn set_constraint_value(&mut self, value: EValue) -> Result<(), ParseError> {
+        let name = if let Some(name) = self.c_constraint.take() {
+            name
+        } else {
+            return Err(ParseError::Structure(String::from("Fail to set value of constraint, because name of constraint isn't defined")));
+        };
+        if let Some(c_field) = self.c_field.as_mut() {
+            if name == constraint_names::DEFAULT {
+                c_field.set_default(value)
+            } else {
+                c_field.add_constraint(Constraint::new(&name, value)?)
+            }
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set constraint, because no open field")))
+        }
+    }
+
+    pub fn set_field_type_as_fixed(&mut self, size: usize) -> Result<(), ParseError> {
+        if self.c_enum.is_some() {
+            Err(ParseError::Unexpected(String::from("Fixed-size array can be used only as type of field of struct")))
+        } else if let Some(mut c_field) = self.c_field.take() {
+            let result = c_field.set_as_fixed(size);
+            self.c_field = Some(c_field);
+            result
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set field as fixed-size array, because it wasn't opened.")))
+        }
+    }
+
+    pub fn set_field_type_as_optional(&mut self) -> Result<(), ParseError> {
+        if let Some(mut c_enum) = self.c_enum.take() {
+            let result = c_enum.set_item_as_optional();
+            self.c_enum = Some(c_enum);
+            result
+        } else if let Some(mut c_field) = self.c_field.take() {
             c_field.set_as_optional();
             self.c_field = Some(c_field);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set field as optional, because it wasn't opened.")))
+        }
+    }
+
+    pub fn set_field_items_as_optional(&mut self) -> Result<(), ParseError> {
+        if let Some(mut c_enum) = self.c_enum.take() {
+            let result = c_enum.set_items_as_optional();
+            self.c_enum = Some(c_enum);
+            result
+        } else if let Some(mut c_field) = self.c_field.take() {
+            c_field.set_items_as_optional();
+            self.c_field = Some(c_field);
+            Ok(())
         } else {
-            stop!("Fail to set field as optional, because it wasn't opened.");
+            Err(ParseError::Structure(String::from("Fail to set items of field as optional, because it wasn't opened.")))
         }
     }
 
-    pub fn set_field_name(&mut self, name_str: &str) {
+    pub fn set_field_name(&mut self, name_str: &str) -> Result<(), ParseError> {
         if self.c_struct.is_none() {
-            stop!("Fail to set name of field, because no open struct.");
+            return Err(ParseError::Structure(String::from("Fail to set name of field, because no open struct.")));
         }
         if let Some(mut c_field) = self.c_field.take() {
             c_field.set_name(name_str.to_string());
-            c_field.accept_type(&self, if let Some(group) = self.c_group.clone() { group.id } else { 0 });
+            let params: Vec<String> = self.c_struct.as_ref().map(|s| s.params.clone()).unwrap_or_default();
+            let type_path = c_field.get_full_name();
+            let result = if !c_field.generic.is_empty() {
+                self.accept_generic(&mut c_field, &params)
+            } else if let (1, Some(pos)) = (type_path.len(), params.iter().position(|p| *p == type_path[0])) {
+                // Type of field is defined by instance of template
+                c_field.set_param(pos);
+                Ok(())
+            } else {
+                let (group_id, scope) = self.get_type_scope(&type_path);
+                if let Some(scope) = scope {
+                    c_field.set_type_path([vec![scope], type_path].concat());
+                }
+                c_field.accept_type(self, group_id)
+            };
             self.c_field = Some(c_field);
+            result
         } else {
-            stop!("Fail to set name of field, while it wasn't opened.");
+            Err(ParseError::Structure(String::from("Fail to set name of field, while it wasn't opened.")))
         }
     }
 
-    pub fn close_field(&mut self) {
+    pub fn close_field(&mut self) -> Result<(), ParseError> {
         if let Some(mut c_struct) = self.c_struct.take() {
-            if let Some(c_field) = self.c_field.take() {
-                c_struct.add_field(c_field);
-                self.c_struct = Some(c_struct);
-                self.c_field = None;
+            let result = if let Some(c_field) = self.c_field.take() {
+                if self.c_identification.is_some() && (c_field.ref_type_id.is_some() || c_field.newtype.is_some() || c_field.numeric.is_some() || c_field.key.is_some() || !c_field.generic.is_empty()) {
+                    self.c_struct = Some(c_struct);
+                    return Err(ParseError::UnknownType(format!("Field \"{}\" of @Identification should have primitive type", c_field.name)));
+                }
+                c_struct.add_field(c_field)
             } else {
-                stop!("Fail to close field, while it wasn't opened.");
-            }
+                Err(ParseError::Structure(String::from("Fail to close field, while it wasn't opened.")))
+            };
+            self.c_struct = Some(c_struct);
+            result
         } else {
-            stop!("Fail to close new field, because no open struct.");
+            Err(ParseError::Structure(String::from("Fail to close new field, because no open struct.")))
         }
     }
 
-    pub fn set_enum_type(&mut self, type_str: &str) {
+    pub fn set_field_id(&mut self, id: usize) -> Result<(), ParseError> {
+        if let Some(mut c_field) = self.c_field.take() {
+            c_field.set_explicit_id(id);
+            self.c_field = Some(c_field);
+            Ok(())
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set ID of field, because it wasn't opened.")))
+        }
+    }
+
+    pub fn set_entity_id(&mut self, id: usize) -> Result<(), ParseError> {
+        if let Some(mut c_struct) = self.c_struct.take() {
+            c_struct.set_explicit_id(id);
+            self.c_struct = Some(c_struct);
+        } else if let Some(mut c_enum) = self.c_enum.take() {
+            c_enum.set_explicit_id(id);
+            self.c_enum = Some(c_enum);
+        } else {
+            return Err(ParseError::Structure(String::from("Fail to set ID, because no open struct or enum.")));
+        }
+        Ok(())
+    }
+
+    pub fn set_enum_item_id(&mut self, id: usize) -> Result<(), ParseError> {
         if let Some(mut c_enum) = self.c_enum.take() {
-            c_enum.add_type_path(type_str);
+            let result = c_enum.set_item_id(id);
             self.c_enum = Some(c_enum);
+            result
         } else {
-            stop!("Fail to create new enum item, because no open enum.");
+            Err(ParseError::Structure(String::from("Fail to set ID of enum item, because no open enum.")))
         }
     }
 
-    pub fn set_simple_enum_item(&mut self, word: &str) {
+    pub fn set_enum_type(&mut self, type_str: &str) -> Result<(), ParseError> {
         if let Some(mut c_enum) = self.c_enum.take() {
-            c_enum.set_simple(word);
+            let is_new = c_enum.get_current_option().is_none();
+            let result = c_enum.add_type_path(type_str);
+            if is_new {
+                c_enum.set_item_doc(self.take_doc());
+                c_enum.set_item_deprecated(self.deprecated.take());
+            }
             self.c_enum = Some(c_enum);
+            result
         } else {
-            stop!("Fail to create new enum item, because no open enum.");
+            Err(ParseError::Structure(String::from("Fail to create new enum item, because no open enum.")))
         }
     }
 
-    pub fn set_enum_name(&mut self, name: &str) {
+    pub fn set_simple_enum_item(&mut self, word: &str) -> Result<(), ParseError> {
         if let Some(mut c_enum) = self.c_enum.take() {
-            c_enum.accept_type(&self, if let Some(group) = self.c_group.clone() { group.id } else { 0 });
-            c_enum.set_name(name.to_string());
+            let result = c_enum.set_simple(word);
+            if result.is_ok() {
+                c_enum.set_item_doc(self.take_doc());
+                c_enum.set_item_deprecated(self.deprecated.take());
+            }
             self.c_enum = Some(c_enum);
+            result
         } else {
-            stop!("Fail to set enum item name, because no open enum.");
+            Err(ParseError::Structure(String::from("Fail to create new enum item, because no open enum.")))
         }
     }
 
+    pub fn set_enum_name(&mut self, name: &str) -> Result<(), ParseError> {
+        if let Some(mut c_enum) = self.c_enum.take() {
+            let type_path: Vec<String> = c_enum.get_current_option().map(|item| item.type_path.clone()).unwrap_or_default();
+            let (group_id, _) = self.get_type_scope(&type_path);
+            let result = c_enum.accept_type(self, group_id).and_then(|_| c_enum.set_name(name.to_string()));
+            self.c_enum = Some(c_enum);
+            result
+        } else {
+            Err(ParseError::Structure(String::from("Fail to set enum item name, because no open enum.")))
+        }
+    }
+
+    // Keeps doc comment until next struct, enum, field or enum item will be created
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    fn take_doc(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.doc)
+    }
+
+    // Keeps reason of deprecation until next struct, field or enum item will be created
+    pub fn set_deprecated(&mut self, reason: String) -> Result<(), ParseError> {
+        if self.c_field.is_some() {
+            return Err(ParseError::Structure(String::from("@deprecated should be defined before field")));
+        }
+        self.deprecated = Some(reason);
+        Ok(())
+    }
+
+    pub fn set_numeric(&mut self) -> Result<(), ParseError> {
+        if self.c_enum.is_some() || self.c_field.is_some() {
+            return Err(ParseError::Structure(String::from("@numeric should be defined before enum")));
+        }
+        self.numeric = true;
+        Ok(())
+    }
+
+    // Fields of struct can be reserved: reserved 5, "old_name";
+    pub fn open_reserved(&mut self) -> Result<(), ParseError> {
+        if self.c_struct.is_none() || self.c_field.is_some() {
+            return Err(ParseError::Structure(String::from("IDs and names of fields can be reserved only in struct")));
+        }
+        Ok(())
+    }
+
+    pub fn add_reserved_id(&mut self, id: usize) -> Result<(), ParseError> {
+        if id > MAX_FIELD_ID {
+            return Err(ParseError::InvalidId(format!("Reserved ID {} is out of range. Max value is {}", id, MAX_FIELD_ID)));
+        }
+        if let Some(c_struct) = self.c_struct.as_mut() {
+            c_struct.add_reserved_id(id)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to reserve ID, because no open struct")))
+        }
+    }
+
+    pub fn add_reserved_name(&mut self, name: &str) -> Result<(), ParseError> {
+        if let Some(c_struct) = self.c_struct.as_mut() {
+            c_struct.add_reserved_name(name)
+        } else {
+            Err(ParseError::Structure(String::from("Fail to reserve name, because no open struct")))
+        }
+    }
+
+    // Checks, that all entities were closed by the end of source
+    pub fn finish(&self) -> Result<(), ParseError> {
+        if let Some(c_struct) = self.c_struct.as_ref() {
+            Err(ParseError::Structure(format!("Struct {} isn't closed", c_struct.name)))
+        } else if let Some(c_enum) = self.c_enum.as_ref() {
+            Err(ParseError::Structure(format!("Enum {} isn't closed", c_enum.name)))
+        } else if let Some(c_relation) = self.c_relation.as_ref() {
+            Err(ParseError::Structure(format!("Relation {} isn't closed", c_relation.name)))
+        } else if let Some(c_const) = self.c_const.as_ref() {
+            Err(ParseError::Structure(format!("Constant {} isn't closed", c_const.name)))
+        } else if let Some(c_alias) = self.c_alias.as_ref() {
+            Err(ParseError::Structure(format!("Alias {} isn't closed", c_alias.name)))
+        } else if self.c_broadcasts {
+            Err(ParseError::Structure(String::from("@broadcasts isn't closed")))
+        } else if let Some(c_group) = self.c_group.as_ref() {
+            Err(ParseError::Structure(format!("Group {} isn't closed", c_group.name)))
+        } else {
+            Ok(())
+        }
+    }
+
+    // Drops partially defined field or enum item. Used to recover after error
+    pub fn discard_pending(&mut self) {
+        self.c_field = None;
+        self.c_constraint = None;
+        self.c_const = None;
+        self.c_alias = None;
+        self.c_extends.clear();
+        self.c_extends_location = None;
+        self.deprecated = None;
+        self.numeric = false;
+        self.take_doc();
+        if let Some(c_enum) = self.c_enum.as_mut() {
+            c_enum.discard_current();
+        }
+        if let Some(c_relation) = self.c_relation.as_mut() {
+            c_relation.discard_current();
+        }
+    }
+
+    pub fn is_root(&self) -> bool {
+        self.c_group.is_none() && self.c_struct.is_none() && self.c_enum.is_none() && self.c_relation.is_none() && !self.c_broadcasts
+    }
+
+    // True if @Identification is opened and waits for consumer or producer
+    pub fn is_identification_opened(&self) -> bool {
+        self.c_identification.is_some() && self.c_struct.is_none()
+    }
+
+    // True if struct, enum or relation is opened
+    pub fn is_entity_opened(&self) -> bool {
+        self.c_struct.is_some() || self.c_enum.is_some() || self.c_relation.is_some()
+    }
+
+    pub fn is_const_opened(&self) -> bool {
+        self.c_const.is_some()
+    }
+
+    pub fn is_alias_opened(&self) -> bool {
+        self.c_alias.is_some()
+    }
+
+    pub fn is_relation_opened(&self) -> bool {
+        self.c_relation.is_some()
+    }
+
     pub fn is_enum_opened(&mut self) -> bool {
         self.c_enum.is_some()
     }
@@ -227,23 +1022,83 @@ impl Store {
         self.c_field.is_some()
     }
 
-    pub fn open(&mut self) {
-        if self.c_group.is_none() && self.c_struct.is_none() && self.c_enum.is_none() {
-            stop!("No created struct or enum");
+    pub fn is_map_opened(&self) -> bool {
+        self.c_field.as_ref().is_some_and(|field| field.is_map_opened())
+    }
+
+    pub fn open(&mut self) -> Result<(), ParseError> {
+        if self.c_group.is_none() && self.c_struct.is_none() && self.c_enum.is_none() && self.c_relation.is_none() && !self.c_broadcasts {
+            return Err(ParseError::Structure(String::from("No created struct or enum")));
         }
+        Ok(())
     }
 
-    pub fn close(&mut self) {
-        if self.c_group.is_none() && self.c_struct.is_none() && self.c_enum.is_none() {
-            stop!("No opened group or struct or enum");
+    pub fn close(&mut self) -> Result<(), ParseError> {
+        self.take_doc();
+        if self.c_group.is_none() && self.c_struct.is_none() && self.c_enum.is_none() && self.c_relation.is_none() && !self.c_broadcasts {
+            return Err(ParseError::Structure(String::from("No opened group or struct or enum")));
         }
-        if let Some(c_enum) = self.c_enum.take() {
+        if let Some(mut c_relation) = self.c_relation.take() {
+            if c_relation.close_block() {
+                self.c_relation = Some(c_relation);
+                return Ok(());
+            }
+            c_relation.validate()?;
+            // Producer dispatches request to single observer
+            if let Some(relation) = self.relations.iter().find(|r| r.request == c_relation.request) {
+                return Err(ParseError::Duplicate(format!("Request of relation \"{}\" is already used by relation \"{}\"", c_relation.name, relation.name)));
+            }
+            // Structs, which are broadcasted by relation, are broadcasts too
+            for id in c_relation.get_broadcasts() {
+                if !self.broadcasts.contains(&id) {
+                    self.broadcasts.push(id);
+                }
+            }
+            self.relations.push(c_relation);
+        } else if let Some(c_enum) = self.c_enum.take() {
+            if c_enum.numeric && c_enum.variants.is_empty() {
+                return Err(ParseError::Structure(format!("Numeric enum {} should have at least one item", c_enum.name)));
+            }
             self.enums.push(c_enum);
             self.c_enum = None;
-        } else if let Some(c_struct) = self.c_struct.take() {
+            self.close_struct_scope()?;
+        } else if let Some(mut c_struct) = self.c_struct.take() {
+            if self.c_identification.is_some() {
+                // Keys are merged, so any field can be skipped
+                c_struct.fields.iter_mut().for_each(|f| f.set_as_optional());
+            }
+            if c_struct.is_template() {
+                // Template is rendered only as instances, which are bound with group on expanding
+                self.unbind_struct_from_group(c_struct.id);
+                self.templates.push(c_struct);
+                return Ok(());
+            }
+            if self.c_broadcasts && self.c_owners.is_empty() && !self.broadcasts.contains(&c_struct.id) {
+                self.broadcasts.push(c_struct.id);
+            }
             self.structs.push(c_struct);
             self.c_struct = None;
-        } else if let Some(c_group) = self.c_group.take() {
+            self.close_struct_scope()?;
+        } else if self.c_broadcasts {
+            self.c_broadcasts = false;
+        } else if let Some(c_identification) = self.c_identification.take() {
+            // Group is closed in any case; invalid identification is dropped
+            let result = c_identification.validate();
+            if result.is_ok() {
+                self.add_identification_response();
+                self.identification = Some(c_identification);
+            }
+            self.close()?;
+            return result;
+        } else if self.c_group.is_some() {
+            self.close_group()?;
+        }
+        Ok(())
+    }
+
+    // Stores opened group; parent group becomes opened
+    fn close_group(&mut self) -> Result<(), ParseError> {
+        if let Some(c_group) = self.c_group.take() {
             self.groups.push(c_group);
             self.path.remove(self.path.len() - 1);
             if self.path.is_empty() {
@@ -251,66 +1106,391 @@ impl Store {
             } else if let Some(pos) = self.groups.iter().position(|s| s.id == self.path[self.path.len() - 1]) {
                 self.c_group = Some(self.groups.remove(pos));
             } else {
-                stop!("Cannot find group from path");
+                return Err(ParseError::Structure(String::from("Cannot find group from path")));
             }
         }
+        Ok(())
     }
 
-    pub fn order(&mut self) -> Result<(), String> {
+    pub fn order(&mut self, mut lock: Option<&mut Lock>) -> Result<(), ParseError> {
         let mut parents: Vec<usize> = vec!();
         for strct in &self.structs {
             if strct.parent != 0 && parents.iter().find(|id| id == &&strct.parent).is_none() {
                 parents.push(strct.parent);
             }
         }
+        let empty = Lock::new();
+        let locked: &Lock = if let Some(lock) = lock.as_ref() { lock } else { &empty };
+        self.expand_templates()?;
+        let mut actual: Vec<(&str, String, usize)> = vec![];
+        self.resolve_entities_ids(locked, &mut actual)?;
+        self.resolve_fields_ids(locked, &mut actual)?;
+        self.resolve_enum_items_ids(locked, &mut actual)?;
+        if let Some(lock) = lock.as_mut() {
+            lock.update(actual);
+        }
         Ok(())
     }
 
-    pub fn get_struct_path(&self, id: usize) -> Vec<String> {
-        if let Some(strct) = self.structs.iter().find(|s| s.id == id) {
-            let mut path: Vec<String> = vec![strct.name.clone()];
-            let mut parent = strct.parent;
-            loop {
-                if parent == 0 {
-                    break;
+    // Fields of generic types get references to instances of templates. Instances are created
+    // after all entities, so IDs of declared entities don't depend on templates
+    fn expand_templates(&mut self) -> Result<(), ParseError> {
+        let mut pos = 0;
+        while pos < self.structs.len() {
+            let id = self.structs[pos].id;
+            self.expand_struct(id)?;
+            // Instances are inserted before struct, which uses it
+            pos = self.structs.iter().position(|s| s.id == id).unwrap_or(pos) + 1;
+        }
+        Ok(())
+    }
+
+    fn expand_struct(&mut self, id: usize) -> Result<(), ParseError> {
+        let fields: Vec<Field> = self.get_struct(id).map(|s| s.fields).unwrap_or_default();
+        for (index, mut field) in fields.into_iter().enumerate() {
+            if field.generic.is_empty() {
+                continue;
+            }
+            field.set_type_path(self.get_instance(&field, id).map_err(|err| err.at_entity(&field.location))?);
+            field.generic = vec![];
+            field.accept_type(self, 0).map_err(|err| err.at_entity(&field.location))?;
+            if let Some(strct) = self.structs.iter_mut().find(|s| s.id == id) {
+                strct.fields[index] = field;
+            }
+        }
+        Ok(())
+    }
+
+    // Returns path to instance of template; instance is created, if it doesn't exist yet.
+    // Instance is placed into group of last argument, which is declared in group (or into group of template),
+    // before owner of field, because renderers keep order of structs
+    fn get_instance(&mut self, field: &Field, owner: usize) -> Result<Vec<String>, ParseError> {
+        let template_path = field.get_full_name();
+        let template = if let Some(template) = self.templates.iter().find(|t| self.get_template_path(t) == template_path) {
+            template.clone()
+        } else {
+            return Err(ParseError::UnknownType(format!("Fail to find template: {}", template_path.join("."))));
+        };
+        let key = format!("{}<{}>", template_path.join("."), field.generic.iter().map(|arg| arg.join(".")).collect::<Vec<String>>().join(", "));
+        if let Some((_, id)) = self.instances.iter().find(|(k, _)| *k == key) {
+            return Ok(self.get_struct_path(*id));
+        }
+        let parent = field.generic.iter().rev().filter(|arg| arg.len() > 1).find_map(|arg| {
+            self.find_by_path(0, arg).map(|path| path[path.len() - 2].1)
+        }).unwrap_or(template.parent);
+        // Page<Chat:Room> becomes Chat:PageRoom
+        let name = field.generic.iter().filter_map(|arg| arg.last()).fold(template.name.clone(), |name, type_name| {
+            let mut chars = type_name.chars();
+            match chars.next() {
+                Some(first) => format!("{}{}{}", name, first.to_uppercase(), chars.as_str()),
+                None => name,
+            }
+        });
+        if self.find_by_path(parent, std::slice::from_ref(&name)).is_some() {
+            return Err(ParseError::Duplicate(format!("Fail to create {} for {}, because entity with same name already exist", name, key)));
+        }
+        self.sequence += 1;
+        let id = self.sequence;
+        let mut instance = Struct::new(id, parent, name);
+        instance.set_doc(template.doc.clone());
+        // Instance is declared by the first field, which uses it
+        instance.location = field.location.clone();
+        for field in self.instantiate(&template, &field.generic)? {
+            instance.add_field(field)?;
+        }
+        if let Some(group) = self.groups.iter_mut().find(|g| g.id == parent) {
+            let pos = group.structs.iter().position(|s| *s == owner).unwrap_or(group.structs.len());
+            group.structs.insert(pos, id);
+        }
+        let pos = self.structs.iter().position(|s| s.id == owner).unwrap_or(self.structs.len());
+        self.structs.insert(pos, instance);
+        self.instances.push((key, id));
+        // Instance can have fields of generic types as well
+        self.expand_struct(id)?;
+        Ok(self.get_struct_path(id))
+    }
+
+    // Fields of template with types of parameters get types of arguments
+    fn instantiate(&self, template: &Struct, args: &[Vec<String>]) -> Result<Vec<Field>, ParseError> {
+        let mut fields: Vec<Field> = vec![];
+        for field in template.fields.iter() {
+            let mut field = field.clone();
+            if let Some(pos) = field.param {
+                field.set_type_path(args[pos].clone());
+                field.accept_type(self, 0)?;
+            } else {
+                self.set_absolute_type_path(&mut field);
+            }
+            fields.push(field);
+        }
+        Ok(fields)
+    }
+
+    // Generic field refers to template and arguments by absolute paths. Instance is created on order(),
+    // but arguments are checked here to report an error at place of field
+    fn accept_generic(&self, field: &mut Field, params: &[String]) -> Result<(), ParseError> {
+        let type_path = field.get_full_name();
+        let template = if let Some(template) = self.find_template(self.get_group_id(), &type_path) {
+            template
+        } else if let Some(template) = self.find_template(0, &type_path) {
+            template
+        } else {
+            return Err(ParseError::UnknownType(format!("Fail to find template: {}", type_path.join("."))));
+        };
+        if template.params.len() != field.generic.len() {
+            return Err(ParseError::UnknownType(format!(
+                "Template {} expects {} argument(s), but {} were given; field \"{}\"",
+                template.name, template.params.len(), field.generic.len(), field.name
+            )));
+        }
+        let mut args: Vec<Vec<String>> = vec![];
+        for arg in field.generic.iter() {
+            if arg.len() == 1 && params.contains(&arg[0]) {
+                return Err(ParseError::UnknownType(format!("Parameter {} cannot be used as argument of template; field \"{}\"", arg[0], field.name)));
+            }
+            if arg.len() == 1 && PrimitiveTypes::is_valid(&arg[0]) {
+                args.push(arg.clone());
+                continue;
+            }
+            // Argument is resolved in same way as type of field: Page<Inner> refers to Inner of opened struct
+            let (group_id, scope) = self.get_type_scope(arg);
+            let arg = if let Some(scope) = scope { [vec![scope], arg.clone()].concat() } else { arg.clone() };
+            let path = if let Some(path) = self.find_by_path(group_id, &arg) {
+                path
+            } else if let Some(path) = self.find_by_path(0, &arg) {
+                path
+            } else {
+                return Err(ParseError::UnknownType(format!("Fail to find type: {}", arg.join("."))));
+            };
+            args.push(self.get_entity_path(path[path.len() - 1].1));
+        }
+        self.instantiate(template, &args)?;
+        field.set_type_path(self.get_template_path(template));
+        field.generic = args;
+        Ok(())
+    }
+
+    fn find_template(&self, from: usize, path: &[String]) -> Option<&Struct> {
+        let mut parent: usize = from;
+        for group_name in path[0..path.len() - 1].iter() {
+            parent = self.groups.iter().chain(self.c_group.iter()).find(|g| g.name == *group_name && g.parent == parent)?.id;
+        }
+        self.templates.iter().find(|t| t.name == path[path.len() - 1] && t.parent == parent)
+    }
+
+    fn get_template_path(&self, template: &Struct) -> Vec<String> {
+        let mut path: Vec<String> = self.get_group_path(template.parent);
+        path.push(template.name.clone());
+        path
+    }
+
+    fn resolve_entities_ids(&mut self, lock: &Lock, actual: &mut Vec<(&str, String, usize)>) -> Result<(), ParseError> {
+        // Structs and enums share one namespace of messages IDs
+        let mut entities: Vec<IdCandidate> = vec![];
+        for enums in self.enums.iter() {
+            entities.push((kinds::ENUM, self.get_enum_path(enums.id).join("."), enums.explicit_id, enums.id, enums.location.clone()));
+        }
+        for strct in self.structs.iter() {
+            entities.push((kinds::STRUCT, self.get_struct_path(strct.id).join("."), strct.explicit_id, strct.id, strct.location.clone()));
+        }
+        let ids = Self::resolve_ids(&entities, lock.get(&[kinds::STRUCT, kinds::ENUM], None), &[], MAX_ENTITY_ID)?;
+        for (pos, enums) in self.enums.iter_mut().enumerate() {
+            enums.wire_id = ids[pos];
+        }
+        let offset = self.enums.len();
+        for (pos, strct) in self.structs.iter_mut().enumerate() {
+            strct.wire_id = ids[offset + pos];
+        }
+        for (pos, (kind, path, ..)) in entities.into_iter().enumerate() {
+            actual.push((kind, path, ids[pos]));
+        }
+        Ok(())
+    }
+
+    fn resolve_fields_ids(&mut self, lock: &Lock, actual: &mut Vec<(&str, String, usize)>) -> Result<(), ParseError> {
+        let paths: Vec<String> = self.structs.iter().map(|s| self.get_struct_path(s.id).join(".")).collect();
+        for (strct, path) in self.structs.iter_mut().zip(paths.iter()) {
+            let fields: Vec<IdCandidate> = strct.fields.iter().map(|f| {
+                (kinds::FIELD, format!("{}.{}", path, f.name), f.explicit_id, f.id, f.location.clone())
+            }).collect();
+            let ids = Self::resolve_ids(&fields, lock.get(&[kinds::FIELD], Some(path)), &strct.reserved_ids, MAX_FIELD_ID)?;
+            for (pos, field) in strct.fields.iter_mut().enumerate() {
+                field.wire_id = ids[pos];
+            }
+            for (pos, (kind, path, ..)) in fields.into_iter().enumerate() {
+                actual.push((kind, path, ids[pos]));
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_enum_items_ids(&mut self, lock: &Lock, actual: &mut Vec<(&str, String, usize)>) -> Result<(), ParseError> {
+        let paths: Vec<String> = self.enums.iter().map(|e| self.get_enum_path(e.id).join(".")).collect();
+        for (enums, path) in self.enums.iter_mut().zip(paths.iter()) {
+            let items: Vec<IdCandidate> = enums.variants.iter().enumerate().map(|(pos, item)| {
+                (kinds::ITEM, format!("{}.{}", path, item.name), item.explicit_id, pos, item.location.clone())
+            }).collect();
+            let ids = if enums.numeric {
+                Self::resolve_discriminants(&items)?
+            } else {
+                Self::resolve_ids(&items, lock.get(&[kinds::ITEM], Some(path)), &[], MAX_FIELD_ID)?
+            };
+            for (pos, item) in enums.variants.iter_mut().enumerate() {
+                item.wire_id = ids[pos];
+            }
+            for (pos, (kind, path, ..)) in items.into_iter().enumerate() {
+                actual.push((kind, path, ids[pos]));
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolves discriminants of items of numeric enum like C does: item without explicit ID gets
+    /// discriminant of previous item + 1, first item gets 0. Lock file isn't used, because discriminants
+    /// are defined by order of items only. Same discriminant of two items is an error
+    fn resolve_discriminants(items: &[IdCandidate]) -> Result<Vec<usize>, ParseError> {
+        let mut used: HashMap<usize, String> = HashMap::new();
+        let mut resolved: Vec<usize> = vec![];
+        let mut next: usize = 0;
+        for (kind, path, explicit_id, _, location) in items.iter() {
+            let id = explicit_id.unwrap_or(next);
+            if id > MAX_FIELD_ID {
+                return Err(ParseError::InvalidId(format!("ID {} of {} {} is out of range. Max value is {}", id, kind, path, MAX_FIELD_ID)).at_entity(location));
+            }
+            if let Some(owner) = used.get(&id) {
+                return Err(ParseError::InvalidId(format!("ID {} of {} {} is already used by {}", id, kind, path, owner)).at_entity(location));
+            }
+            used.insert(id, format!("{} {}", kind, path));
+            resolved.push(id);
+            next = id + 1;
+        }
+        Ok(resolved)
+    }
+
+    /// Resolves IDs for entities of one namespace. Entity is (kind, path, explicit ID, preferred ID, location).
+    /// Explicit IDs go first, then IDs from lock file. Others get preferred ID if it was never used
+    /// (including removed entities in lock file) or next ID after the biggest used one. Reserved IDs are never given
+    fn resolve_ids(entities: &[IdCandidate], locked: Vec<(String, usize)>, reserved: &[usize], max: usize) -> Result<Vec<usize>, ParseError> {
+        let mut used: HashMap<usize, String> = reserved.iter().map(|id| (*id, String::from("reserved ID"))).collect();
+        let mut ids: Vec<Option<usize>> = vec![None; entities.len()];
+        for (pos, (kind, path, explicit_id, _, location)) in entities.iter().enumerate() {
+            if let Some(id) = explicit_id {
+                if *id > max {
+                    return Err(ParseError::InvalidId(format!("ID {} of {} {} is out of range. Max value is {}", id, kind, path, max)).at_entity(location));
                 }
-                if let Some(group) = self.groups.iter().find(|s| s.id == parent) {
-                    path.push(group.name.clone());
-                    parent = group.parent;
-                } else {
-                    stop!("Fail to find a group id: {} for struct {}", strct.parent, strct.name);
+                if let Some(owner) = used.get(id) {
+                    return Err(ParseError::InvalidId(format!("ID {} of {} {} is already used by {}", id, kind, path, owner)).at_entity(location));
+                }
+                used.insert(*id, format!("{} {}", kind, path));
+                ids[pos] = Some(*id);
+            }
+        }
+        for (pos, (kind, path, _, _, location)) in entities.iter().enumerate() {
+            if ids[pos].is_some() {
+                continue;
+            }
+            let key = format!("{} {}", kind, path);
+            if let Some((_, id)) = locked.iter().find(|(k, _)| *k == key) {
+                if let Some(owner) = used.get(id) {
+                    return Err(ParseError::InvalidId(format!("ID {} is locked for {}, but it's used by {}", id, key, owner)).at_entity(location));
                 }
+                used.insert(*id, key);
+                ids[pos] = Some(*id);
+            }
+        }
+        for (key, id) in locked.into_iter() {
+            used.entry(id).or_insert(key);
+        }
+        let mut next: usize = used.keys().max().map_or(0, |id| id + 1);
+        let mut resolved: Vec<usize> = vec![];
+        for (pos, (kind, path, _, preferred, location)) in entities.iter().enumerate() {
+            let id = if let Some(id) = ids[pos] {
+                id
+            } else if !used.contains_key(preferred) {
+                *preferred
+            } else {
+                next
+            };
+            if id > max {
+                return Err(ParseError::InvalidId(format!("Fail to allocate ID for {} {}; all IDs up to {} are used", kind, path, max)).at_entity(location));
+            }
+            if id >= next {
+                next = id + 1;
             }
-            path.reverse();
+            used.insert(id, format!("{} {}", kind, path));
+            resolved.push(id);
+        }
+        Ok(resolved)
+    }
+
+    // Returns path from root group to struct. Path is empty if struct doesn't exist
+    pub fn get_struct_path(&self, id: usize) -> Vec<String> {
+        if let Some(strct) = self.structs.iter().find(|s| s.id == id) {
+            let mut path: Vec<String> = self.get_group_path(strct.parent);
+            path.push(strct.name.clone());
             path
         } else {
-            stop!("Fail to find a struct {}", id);
+            vec![]
         }
     }
 
+    // Returns path from root group to enum. Path is empty if enum doesn't exist
     pub fn get_enum_path(&self, id: usize) -> Vec<String> {
-        if let Some(strct) = self.enums.iter().find(|s| s.id == id) {
-            let mut path: Vec<String> = vec![strct.name.clone()];
-            let mut parent = strct.parent;
-            loop {
-                if parent == 0 {
-                    break;
-                }
-                if let Some(group) = self.groups.iter().find(|s| s.id == parent) {
-                    path.push(group.name.clone());
-                    parent = group.parent;
-                } else {
-                    stop!("Fail to find a group id: {} for struct {}", strct.parent, strct.name);
-                }
-            }
-            path.reverse();
+        if let Some(enums) = self.enums.iter().find(|s| s.id == id) {
+            let mut path: Vec<String> = self.get_group_path(enums.parent);
+            path.push(enums.name.clone());
+            path
+        } else {
+            vec![]
+        }
+    }
+
+    // Returns path from root group to alias. Path is empty if alias doesn't exist
+    pub fn get_alias_path(&self, id: usize) -> Vec<String> {
+        if let Some(alias) = self.aliases.iter().find(|a| a.id == id) {
+            let mut path: Vec<String> = self.get_group_path(alias.parent);
+            path.push(alias.name.clone());
+            path
+        } else {
+            vec![]
+        }
+    }
+
+    // Returns path from root group to struct, enum or alias
+    pub fn get_entity_path(&self, id: usize) -> Vec<String> {
+        if self.structs.iter().any(|s| s.id == id) {
+            self.get_struct_path(id)
+        } else if self.enums.iter().any(|e| e.id == id) {
+            self.get_enum_path(id)
+        } else {
+            self.get_alias_path(id)
+        }
+    }
+
+    // Returns path from root group to relation. Path is empty if relation doesn't exist
+    pub fn get_relation_path(&self, id: usize) -> Vec<String> {
+        if let Some(relation) = self.relations.iter().find(|r| r.id == id) {
+            let mut path: Vec<String> = self.get_group_path(relation.parent);
+            path.push(relation.name.clone());
             path
         } else {
-            stop!("Fail to find a struct {}", id);
+            vec![]
+        }
+    }
+
+    pub fn get_group_path(&self, id: usize) -> Vec<String> {
+        let mut path: Vec<String> = vec![];
+        let mut parent = id;
+        // Opened group isn't stored yet, but entities of it can be referred already
+        while let Some(group) = self.groups.iter().chain(self.c_group.iter()).find(|g| g.id == parent) {
+            path.push(group.name.clone());
+            parent = group.parent;
         }
+        path.reverse();
+        path
     }
 
-    fn get_group_id(&mut self) -> usize {
+    fn get_group_id(&self) -> usize {
         if let Some(c_group) = self.c_group.clone() {
             c_group.id
         } else {
@@ -318,6 +1498,19 @@ impl Store {
         }
     }
 
+    // Producer responds on SelfKey with SelfKeyResponse
+    fn add_identification_response(&mut self) {
+        self.sequence += 1;
+        self.bind_struct_with_group(self.sequence);
+        let mut response = Struct::new(self.sequence, self.get_group_id(), identification_names::SELF_KEY_RESPONSE.to_string());
+        self.sequence += 1;
+        let mut uuid = Field::new(self.sequence, 0, String::from("str"));
+        uuid.set_name(String::from("uuid"));
+        uuid.add_type_path("str");
+        let _ = response.add_field(uuid);
+        self.structs.push(response);
+    }
+
     fn bind_struct_with_group(&mut self, id: usize) {
         if let Some(mut c_group) = self.c_group.take() {
             c_group.bind_struct(id);
@@ -325,6 +1518,12 @@ impl Store {
         }
     }
 
+    fn unbind_struct_from_group(&mut self, id: usize) {
+        if let Some(c_group) = self.c_group.as_mut() {
+            c_group.structs.retain(|s| *s != id);
+        }
+    }
+
     fn bind_enum_with_group(&mut self, id: usize) {
         if let Some(mut c_group) = self.c_group.take() {
             c_group.bind_enum(id);
@@ -332,6 +1531,27 @@ impl Store {
         }
     }
 
+    fn bind_const_with_group(&mut self, id: usize) {
+        if let Some(mut c_group) = self.c_group.take() {
+            c_group.bind_const(id);
+            self.c_group = Some(c_group);
+        }
+    }
+
+    fn bind_alias_with_group(&mut self, id: usize) {
+        if let Some(mut c_group) = self.c_group.take() {
+            c_group.bind_alias(id);
+            self.c_group = Some(c_group);
+        }
+    }
+
+    fn bind_relation_with_group(&mut self, id: usize) {
+        if let Some(mut c_group) = self.c_group.take() {
+            c_group.bind_relation(id);
+            self.c_group = Some(c_group);
+        }
+    }
+
     fn bind_group_with_group(&mut self, id: usize) {
         if let Some(mut c_group) = self.c_group.take() {
             c_group.bind_group(id);
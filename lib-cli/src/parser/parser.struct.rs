@@ -1,4 +1,4 @@
-use super::{ Field, stop };
+use super::{ Field, Location, ParseError, PrimitiveTypes };
 
 #[derive(Debug, Clone)]
 pub struct Struct {
@@ -6,6 +6,18 @@ pub struct Struct {
     pub parent: usize,
     pub name: String,
     pub fields: Vec<Field>,
+    pub explicit_id: Option<usize>,
+    pub wire_id: usize,
+    pub doc: Vec<String>,
+    // Parameters of template: struct Page<T> { ... }; template isn't rendered, but its instances are
+    pub params: Vec<String>,
+    // Reason of deprecation; it's empty, if reason isn't defined
+    pub deprecated: Option<String>,
+    // IDs and names of removed fields, which cannot be used again: reserved 5, "old_name";
+    pub reserved_ids: Vec<usize>,
+    pub reserved_names: Vec<String>,
+    // Place of declaration; it's used to report errors, which are found after parsing
+    pub location: Option<Location>,
 }
 
 impl Struct {
@@ -16,15 +28,82 @@ impl Struct {
             parent,
             name,
             fields: vec![],
+            explicit_id: None,
+            wire_id: id,
+            doc: vec![],
+            params: vec![],
+            deprecated: None,
+            reserved_ids: vec![],
+            reserved_names: vec![],
+            location: None,
         }
     }
 
-    pub fn add_field(&mut self, mut field: Field) {
+    pub fn set_explicit_id(&mut self, id: usize) {
+        self.explicit_id = Some(id);
+    }
+
+    pub fn set_doc(&mut self, doc: Vec<String>) {
+        self.doc = doc;
+    }
+
+    pub fn set_deprecated(&mut self, deprecated: Option<String>) {
+        self.deprecated = deprecated;
+    }
+
+    pub fn add_reserved_id(&mut self, id: usize) -> Result<(), ParseError> {
+        if self.reserved_ids.contains(&id) {
+            return Err(ParseError::Duplicate(format!("ID {} is already reserved in \"{}\"", id, self.name)));
+        }
+        if let Some(field) = self.fields.iter().find(|f| f.explicit_id == Some(id)) {
+            return Err(ParseError::InvalidId(format!("ID {} cannot be reserved in \"{}\", because it's used by field \"{}\"", id, self.name, field.name)));
+        }
+        self.reserved_ids.push(id);
+        Ok(())
+    }
+
+    pub fn add_reserved_name(&mut self, name: &str) -> Result<(), ParseError> {
+        if self.reserved_names.iter().any(|n| n == name) {
+            return Err(ParseError::Duplicate(format!("Name \"{}\" is already reserved in \"{}\"", name, self.name)));
+        }
+        if self.fields.iter().any(|f| f.name == name) {
+            return Err(ParseError::Duplicate(format!("Name \"{}\" cannot be reserved in \"{}\", because it's used by field", name, self.name)));
+        }
+        self.reserved_names.push(name.to_string());
+        Ok(())
+    }
+
+    pub fn add_param(&mut self, name: &str) -> Result<(), ParseError> {
+        if !self.fields.is_empty() {
+            return Err(ParseError::Structure(format!("Parameters of template \"{}\" should be defined before fields", self.name)));
+        }
+        if PrimitiveTypes::is_valid(name) {
+            return Err(ParseError::Unexpected(format!("Primitive type {} cannot be used as parameter of template \"{}\"", name, self.name)));
+        }
+        if self.params.iter().any(|p| p == name) {
+            return Err(ParseError::Duplicate(format!("Parameter {} of template \"{}\" is already defined", name, self.name)));
+        }
+        self.params.push(name.to_string());
+        Ok(())
+    }
+
+    pub fn is_template(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    pub fn add_field(&mut self, mut field: Field) -> Result<(), ParseError> {
         if self.fields.iter().any(|f| f.name == field.name) {
-            stop!("Fail to add field \"{}\" into \"{}\" because field with same name already exist", field.name, self.name);
+            return Err(ParseError::Duplicate(format!("Fail to add field \"{}\" into \"{}\" because field with same name already exist", field.name, self.name)));
+        }
+        if self.reserved_names.contains(&field.name) {
+            return Err(ParseError::Duplicate(format!("Fail to add field \"{}\" into \"{}\" because name is reserved", field.name, self.name)));
+        }
+        if let Some(id) = field.explicit_id.filter(|id| self.reserved_ids.contains(id)) {
+            return Err(ParseError::InvalidId(format!("Fail to add field \"{}\" into \"{}\" because ID {} is reserved", field.name, self.name, id)));
         }
         field.parent = self.id;
         self.fields.push(field);
+        Ok(())
     }
 
     
@@ -18,6 +18,10 @@ pub mod PrimitiveTypes {
         Ef32,
         Ef64,
         Estr,
+        Ebytes,
+        Etimestamp,
+        Eduration,
+        Euuid,
     }
 
     #[allow(non_upper_case_globals)]
@@ -37,6 +41,13 @@ pub mod PrimitiveTypes {
         pub const Tf32: &str = "f32";
         pub const Tf64: &str = "f64";
         pub const Tstr: &str = "str";
+        // Opaque binary data: Vec<u8> in Rust and Uint8Array in TypeScript
+        pub const Tbytes: &str = "bytes";
+        // Well-known types: timestamp and duration are sent as u64 count of milliseconds,
+        // uuid as 16 raw bytes
+        pub const Ttimestamp: &str = "timestamp";
+        pub const Tduration: &str = "duration";
+        pub const Tuuid: &str = "uuid";
     }
 
     pub fn is_valid(str: &str) -> bool {
@@ -60,10 +71,27 @@ pub mod PrimitiveTypes {
             ctypes::Tf32 => Some(ETypes::Ef32),
             ctypes::Tf64 => Some(ETypes::Ef64),
             ctypes::Tstr => Some(ETypes::Estr),
+            ctypes::Tbytes => Some(ETypes::Ebytes),
+            ctypes::Ttimestamp => Some(ETypes::Etimestamp),
+            ctypes::Tduration => Some(ETypes::Eduration),
+            ctypes::Tuuid => Some(ETypes::Euuid),
             _ => None
         }
     }
 
+    // Size of value in bytes; types of variable size (str, bytes) have no size
+    pub fn get_size(str: &str) -> Option<usize> {
+        match get_entity(str)? {
+            ETypes::Ebool | ETypes::Ei8 | ETypes::Eu8 => Some(1),
+            ETypes::Ei16 | ETypes::Eu16 => Some(2),
+            ETypes::Echar | ETypes::Ei32 | ETypes::Eu32 | ETypes::Ef32 => Some(4),
+            ETypes::Ei64 | ETypes::Eu64 | ETypes::Eisize | ETypes::Eusize | ETypes::Ef64 => Some(8),
+            ETypes::Etimestamp | ETypes::Eduration => Some(8),
+            ETypes::Euuid => Some(16),
+            ETypes::Estr | ETypes::Ebytes => None,
+        }
+    }
+
     pub fn get_entity_as_string(kind: ETypes) -> Option<String> {
         match kind {
             ETypes::Ebool => Some(ctypes::Tbool.to_string()),
@@ -80,7 +108,11 @@ pub mod PrimitiveTypes {
             ETypes::Eusize => Some(ctypes::Tusize.to_string()),
             ETypes::Ef32 => Some(ctypes::Tf32.to_string()),
             ETypes::Ef64 => Some(ctypes::Tf64.to_string()),
-            ETypes::Estr => Some(ctypes::Tstr.to_string())
+            ETypes::Estr => Some(ctypes::Tstr.to_string()),
+            ETypes::Ebytes => Some(ctypes::Tbytes.to_string()),
+            ETypes::Etimestamp => Some(ctypes::Ttimestamp.to_string()),
+            ETypes::Eduration => Some(ctypes::Tduration.to_string()),
+            ETypes::Euuid => Some(ctypes::Tuuid.to_string())
         }
     }
 
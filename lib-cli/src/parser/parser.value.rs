@@ -0,0 +1,77 @@
+use super::{ ParseError, PrimitiveTypes };
+use PrimitiveTypes::ETypes;
+
+// Literal value, which is used as default of field or as value of constant.
+// Numbers are kept as they were written
+#[derive(Debug, Clone, PartialEq)]
+pub enum EValue {
+    Bool(bool),
+    Number(String),
+    Str(String),
+}
+
+impl EValue {
+
+    pub fn from_word(word: &str) -> Result<Self, ParseError> {
+        match word {
+            "true" => Ok(EValue::Bool(true)),
+            "false" => Ok(EValue::Bool(false)),
+            _ => Err(ParseError::Unexpected(format!("Expecting value: number, string or bool. Value {}", word))),
+        }
+    }
+
+    // Checks, that value can be assigned to primitive type
+    pub fn validate(&self, kind: &str) -> Result<(), ParseError> {
+        let etype = if let Some(etype) = PrimitiveTypes::get_entity(kind) {
+            etype
+        } else {
+            return Err(ParseError::UnknownType(format!("Value can be assigned only to primitive type; type {} isn't primitive", kind)));
+        };
+        let valid = match (etype, self) {
+            (ETypes::Ebool, EValue::Bool(_)) => true,
+            (ETypes::Estr, EValue::Str(_)) => true,
+            (ETypes::Echar, EValue::Str(value)) => value.chars().count() == 1,
+            (ETypes::Ef32, EValue::Number(value)) | (ETypes::Ef64, EValue::Number(value)) => value.parse::<f64>().is_ok(),
+            (etype, EValue::Number(value)) => {
+                if let (Ok(value), Some((min, max))) = (value.parse::<i128>(), Self::get_range(etype)) {
+                    value >= min && value <= max
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(ParseError::UnknownType(format!("Value {} doesn't match type {}", self, kind)))
+        }
+    }
+
+    fn get_range(etype: ETypes) -> Option<(i128, i128)> {
+        match etype {
+            ETypes::Ei8 => Some((i8::MIN as i128, i8::MAX as i128)),
+            ETypes::Ei16 => Some((i16::MIN as i128, i16::MAX as i128)),
+            ETypes::Ei32 => Some((i32::MIN as i128, i32::MAX as i128)),
+            ETypes::Ei64 | ETypes::Eisize => Some((i64::MIN as i128, i64::MAX as i128)),
+            ETypes::Eu8 => Some((0, u8::MAX as i128)),
+            ETypes::Eu16 => Some((0, u16::MAX as i128)),
+            ETypes::Eu32 => Some((0, u32::MAX as i128)),
+            ETypes::Eu64 | ETypes::Eusize => Some((0, u64::MAX as i128)),
+            _ => None,
+        }
+    }
+
+}
+
+impl std::fmt::Display for EValue {
+
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EValue::Bool(value) => write!(f, "{}", value),
+            EValue::Number(value) => write!(f, "{}", value),
+            EValue::Str(value) => write!(f, "{:?}", value),
+        }
+    }
+
+}
@@ -0,0 +1,602 @@
+use super::parser::fields::Field;
+use super::parser::identification::{ keys as identification_keys, names as identification_names };
+use super::parser::relations::{Broadcast, Relation};
+use super::parser::store::Store;
+use super::Render;
+
+// Renders producer's (server side) layer based on relations of protocol: observer
+// of each request with hooks for conclusions, broadcasting structs and dispatcher
+// of incoming messages. It expects, that the producer crate has modules:
+// consumer_context (Context), consumer_identification (Filter, FilterCallback), observer
+// (RequestObserverErrors), protocol (PackingStruct) and alias Protocol.
+pub struct ProducerRender {
+    _embedded: bool,
+    _signature: u16,
+}
+
+impl ProducerRender {
+    fn relations(&self, relation: &Relation, store: &Store, level: u8) -> String {
+        let request = self.get_struct_ref(relation.request, store);
+        let error = if relation.error.is_some() {
+            self.get_struct_ref(relation.error, store)
+        } else {
+            String::from("String")
+        };
+        let mut body = format!("{}#[allow(non_snake_case)]\n", self.spaces(level));
+        body = format!("{}{}pub mod {}Observer {{\n", body, self.spaces(level), self.get_name(relation, store));
+        body = format!("{}{}use super::*;\n", body, self.spaces(level + 1));
+        if !relation.conclusions.is_empty() {
+            body = format!("{}\n{}#[derive(Debug, Clone)]\n", body, self.spaces(level + 1));
+            body = format!("{}{}pub enum Conclusion {{\n", body, self.spaces(level + 1));
+            for conclusion in &relation.conclusions {
+                body = format!(
+                    "{}{}{}({}),\n",
+                    body,
+                    self.spaces(level + 2),
+                    conclusion.name,
+                    self.get_struct_ref(Some(conclusion.response), store)
+                );
+            }
+            body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        }
+        for conclusion in &relation.conclusions {
+            if !conclusion.broadcasts.is_empty() {
+                body = format!(
+                    "{}\n{}",
+                    body,
+                    self.broadcasting(&conclusion.name, &conclusion.broadcasts, store, level + 1)
+                );
+            }
+        }
+        if !relation.broadcasts.is_empty() {
+            body = format!(
+                "{}\n{}",
+                body,
+                self.broadcasting("Response", &relation.broadcasts, store, level + 1)
+            );
+        }
+        body = format!("{}\n{}", body, self.doc(&relation.doc, level + 1));
+        body = format!("{}{}#[allow(unused_variables)]\n", body, self.spaces(level + 1));
+        body = format!("{}{}#[allow(non_snake_case)]\n", body, self.spaces(level + 1));
+        body = format!("{}{}pub trait Observer {{\n", body, self.spaces(level + 1));
+        body = format!(
+            "{}\n{}fn conclusion<UCX: 'static + Sync + Send + Clone>(\n",
+            body,
+            self.spaces(level + 2)
+        );
+        body = format!("{}{}request: {},\n", body, self.spaces(level + 3), request);
+        body = format!("{}{}cx: &dyn Context,\n", body, self.spaces(level + 3));
+        body = format!("{}{}ucx: UCX,\n", body, self.spaces(level + 3));
+        body = format!(
+            "{}{}) -> Result<{}, {}> {{\n",
+            body,
+            self.spaces(level + 2),
+            if relation.conclusions.is_empty() {
+                self.get_struct_ref(relation.response, store)
+            } else {
+                String::from("Conclusion")
+            },
+            error
+        );
+        body = format!(
+            "{}{}panic!(\"conclusion method isn't implemented\");\n",
+            body,
+            self.spaces(level + 3)
+        );
+        body = format!("{}{}}}\n", body, self.spaces(level + 2));
+        for conclusion in &relation.conclusions {
+            body = format!(
+                "{}\n{}",
+                body,
+                self.hook(
+                    &conclusion.name,
+                    &conclusion.doc,
+                    !conclusion.broadcasts.is_empty(),
+                    &request,
+                    level + 2
+                )
+            );
+        }
+        if !relation.broadcasts.is_empty() {
+            body = format!(
+                "{}\n{}",
+                body,
+                self.hook("Response", &[], true, &request, level + 2)
+            );
+        }
+        body = format!("{}\n{}", body, self.emit(relation, store, &request, level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}\n{}#[derive(Clone)]\n", body, self.spaces(level + 1));
+        body = format!("{}{}pub struct ObserverRequest {{}}\n", body, self.spaces(level + 1));
+        body = format!("{}\n{}#[allow(clippy::new_without_default)]\n", body, self.spaces(level + 1));
+        body = format!("{}{}impl ObserverRequest {{\n", body, self.spaces(level + 1));
+        body = format!("{}{}pub fn new() -> Self {{\n", body, self.spaces(level + 2));
+        body = format!("{}{}ObserverRequest {{}}\n", body, self.spaces(level + 3));
+        body = format!("{}{}}}\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        format!("{}{}}}\n", body, self.spaces(level))
+    }
+
+    fn broadcasting(&self, name: &str, broadcasts: &[Broadcast], store: &Store, level: u8) -> String {
+        let mut body = format!("{}#[allow(non_snake_case)]\n", self.spaces(level));
+        body = format!("{}{}pub struct {}Broadcasting {{\n", body, self.spaces(level), name);
+        for (field, broadcast) in self.get_broadcasts_fields(broadcasts, store) {
+            let declaration = format!("(Filter, {})", self.get_struct_ref(Some(broadcast.id), store));
+            body = format!(
+                "{}{}pub {}: {},\n",
+                body,
+                self.spaces(level + 1),
+                field,
+                if broadcast.optional {
+                    format!("Option<{}>", declaration)
+                } else {
+                    declaration
+                }
+            );
+        }
+        format!("{}{}}}\n", body, self.spaces(level))
+    }
+
+    fn hook(&self, name: &str, doc: &[String], broadcasting: bool, request: &str, level: u8) -> String {
+        let mut body = self.doc(doc, level);
+        body = format!("{}{}fn {}<UCX: 'static + Sync + Send + Clone>(\n", body, self.spaces(level), name);
+        body = format!("{}{}cx: &dyn Context,\n", body, self.spaces(level + 1));
+        body = format!("{}{}ucx: UCX,\n", body, self.spaces(level + 1));
+        body = format!("{}{}request: {},\n", body, self.spaces(level + 1), request);
+        body = format!(
+            "{}{}) -> Result<{}, String> {{\n",
+            body,
+            self.spaces(level),
+            if broadcasting {
+                format!("{}Broadcasting", name)
+            } else {
+                String::from("()")
+            }
+        );
+        body = format!(
+            "{}{}Err(String::from(\"{} method isn't implemented\"))\n",
+            body,
+            self.spaces(level + 1),
+            name
+        );
+        format!("{}{}}}\n", body, self.spaces(level))
+    }
+
+    fn emit(&self, relation: &Relation, store: &Store, request: &str, level: u8) -> String {
+        let mut body = format!("{}fn emit<UCX: 'static + Sync + Send + Clone>(\n", self.spaces(level));
+        body = format!("{}{}&self,\n", body, self.spaces(level + 1));
+        body = format!("{}{}cx: &dyn Context,\n", body, self.spaces(level + 1));
+        body = format!("{}{}ucx: UCX,\n", body, self.spaces(level + 1));
+        body = format!("{}{}sequence: u32,\n", body, self.spaces(level + 1));
+        body = format!("{}{}request: {},\n", body, self.spaces(level + 1), request);
+        body = format!(
+            "{}{}broadcast: &dyn Fn(Filter, Vec<u8>) -> Result<(), String>,\n",
+            body,
+            self.spaces(level + 1)
+        );
+        body = format!(
+            "{}{}) -> Result<(), RequestObserverErrors> {{\n",
+            body,
+            self.spaces(level)
+        );
+        // Request and context are passed to hooks of conclusions as well
+        let hooks = !relation.conclusions.is_empty() || !relation.broadcasts.is_empty();
+        body = format!(
+            "{}{}match Self::conclusion({}, cx, {}) {{\n",
+            body,
+            self.spaces(level + 1),
+            if hooks { "request.clone()" } else { "request" },
+            if hooks { "ucx.clone()" } else { "ucx" }
+        );
+        if relation.conclusions.is_empty() {
+            body = format!("{}{}Ok(mut response) => {{\n", body, self.spaces(level + 2));
+            body = format!(
+                "{}{}",
+                body,
+                self.result("Response", &relation.broadcasts, store, level + 3)
+            );
+            body = format!("{}{}}}\n", body, self.spaces(level + 2));
+        } else {
+            body = format!("{}{}Ok(conclusion) => match conclusion {{\n", body, self.spaces(level + 2));
+            for conclusion in &relation.conclusions {
+                body = format!(
+                    "{}{}Conclusion::{}(mut response) => {{\n",
+                    body,
+                    self.spaces(level + 3),
+                    conclusion.name
+                );
+                body = format!(
+                    "{}{}",
+                    body,
+                    self.result(&conclusion.name, &conclusion.broadcasts, store, level + 4)
+                );
+                body = format!("{}{}}}\n", body, self.spaces(level + 3));
+            }
+            body = format!("{}{}}},\n", body, self.spaces(level + 2));
+        }
+        if relation.error.is_some() {
+            body = format!(
+                "{}{}Err(mut error) => respond(cx, sequence, &mut error),\n",
+                body,
+                self.spaces(level + 2)
+            );
+        } else {
+            body = format!(
+                "{}{}Err(error) => Err(RequestObserverErrors::GettingConclusionError(error)),\n",
+                body,
+                self.spaces(level + 2)
+            );
+        }
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        format!("{}{}}}\n", body, self.spaces(level))
+    }
+
+    // Calls hook of conclusion, sends response and broadcasts messages, which hook returned
+    fn result(&self, name: &str, broadcasts: &[Broadcast], store: &Store, level: u8) -> String {
+        let has_hook = !broadcasts.is_empty() || name != "Response";
+        let mut body = String::new();
+        if has_hook && broadcasts.is_empty() {
+            body = format!(
+                "{}{}if let Err(error) = Self::{}(cx, ucx, request) {{\n",
+                body,
+                self.spaces(level),
+                name
+            );
+            body = format!(
+                "{}{}return Err(RequestObserverErrors::AfterConclusionError(error));\n",
+                body,
+                self.spaces(level + 1)
+            );
+            body = format!("{}{}}}\n", body, self.spaces(level));
+        } else if has_hook {
+            body = format!(
+                "{}{}let broadcasts = match Self::{}(cx, ucx, request) {{\n",
+                body,
+                self.spaces(level),
+                name
+            );
+            body = format!("{}{}Ok(broadcasts) => broadcasts,\n", body, self.spaces(level + 1));
+            body = format!(
+                "{}{}Err(error) => return Err(RequestObserverErrors::AfterConclusionError(error)),\n",
+                body,
+                self.spaces(level + 1)
+            );
+            body = format!("{}{}}};\n", body, self.spaces(level));
+        }
+        if broadcasts.is_empty() {
+            return format!("{}{}respond(cx, sequence, &mut response)\n", body, self.spaces(level));
+        }
+        body = format!("{}{}respond(cx, sequence, &mut response)?;\n", body, self.spaces(level));
+        for (field, broadcast) in self.get_broadcasts_fields(broadcasts, store) {
+            if broadcast.optional {
+                body = format!(
+                    "{}{}if let Some((filter, mut msg)) = broadcasts.{} {{\n",
+                    body,
+                    self.spaces(level),
+                    field
+                );
+                body = format!(
+                    "{}{}broadcasting(cx, filter, &mut msg, broadcast)?;\n",
+                    body,
+                    self.spaces(level + 1)
+                );
+                body = format!("{}{}}}\n", body, self.spaces(level));
+            } else {
+                body = format!(
+                    "{}{}let (filter, mut msg) = broadcasts.{};\n",
+                    body,
+                    self.spaces(level),
+                    field
+                );
+                body = format!(
+                    "{}{}broadcasting(cx, filter, &mut msg, broadcast)?;\n",
+                    body,
+                    self.spaces(level)
+                );
+            }
+        }
+        format!("{}{}Ok(())\n", body, self.spaces(level))
+    }
+
+    fn observers(&self, store: &Store) -> String {
+        let mut body = String::from("#[allow(non_snake_case)]\n");
+        body = format!("{}pub struct Observers {{\n", body);
+        for relation in &store.relations {
+            let name = self.get_name(relation, store);
+            body = format!(
+                "{}{}pub {}: Arc<RwLock<{}Observer::ObserverRequest>>,\n",
+                body,
+                self.spaces(1),
+                name,
+                name
+            );
+        }
+        body = format!("{}}}\n\n", body);
+        body = format!("{}#[allow(clippy::new_without_default)]\n", body);
+        body = format!("{}impl Observers {{\n", body);
+        body = format!("{}{}pub fn new() -> Self {{\n", body, self.spaces(1));
+        body = format!("{}{}Observers {{\n", body, self.spaces(2));
+        for relation in &store.relations {
+            let name = self.get_name(relation, store);
+            body = format!(
+                "{}{}{}: Arc::new(RwLock::new({}Observer::ObserverRequest::new())),\n",
+                body,
+                self.spaces(3),
+                name,
+                name
+            );
+        }
+        body = format!("{}{}}}\n", body, self.spaces(2));
+        body = format!("{}{}}}\n\n", body, self.spaces(1));
+        body = format!(
+            "{}{}// Routes request to its observer. Returns None if message isn't a request of any relation\n",
+            body,
+            self.spaces(1)
+        );
+        body = format!("{}{}#[allow(unreachable_patterns)]\n", body, self.spaces(1));
+        body = format!("{}{}pub fn emit<UCX: 'static + Sync + Send + Clone>(\n", body, self.spaces(1));
+        body = format!("{}{}&self,\n", body, self.spaces(2));
+        body = format!("{}{}message: Protocol::AvailableMessages,\n", body, self.spaces(2));
+        body = format!("{}{}cx: &dyn Context,\n", body, self.spaces(2));
+        body = format!("{}{}ucx: UCX,\n", body, self.spaces(2));
+        body = format!("{}{}sequence: u32,\n", body, self.spaces(2));
+        body = format!(
+            "{}{}broadcast: &dyn Fn(Filter, Vec<u8>) -> Result<(), String>,\n",
+            body,
+            self.spaces(2)
+        );
+        body = format!("{}{}) -> Option<Result<(), String>> {{\n", body, self.spaces(1));
+        body = format!("{}{}match message {{\n", body, self.spaces(2));
+        for relation in &store.relations {
+            let name = self.get_name(relation, store);
+            body = format!(
+                "{}{}{} => Some(match self.{}.write() {{\n",
+                body,
+                self.spaces(3),
+                self.get_message_pattern(relation.request, store),
+                name
+            );
+            body = format!("{}{}Ok(observer) => {{\n", body, self.spaces(4));
+            body = format!("{}{}use {}Observer::Observer;\n", body, self.spaces(5), name);
+            body = format!(
+                "{}{}observer.emit(cx, ucx, sequence, request, broadcast).map_err(|e| format!(\"Fail to emit {} due error: {{:?}}\", e))\n",
+                body,
+                self.spaces(5),
+                name
+            );
+            body = format!("{}{}}}\n", body, self.spaces(4));
+            body = format!(
+                "{}{}Err(e) => Err(format!(\"Fail to access to {} due error: {{}}\", e)),\n",
+                body,
+                self.spaces(4),
+                name
+            );
+            body = format!("{}{}}}),\n", body, self.spaces(3));
+        }
+        body = format!("{}{}_ => None,\n", body, self.spaces(3));
+        body = format!("{}{}}}\n", body, self.spaces(2));
+        body = format!("{}{}}}\n", body, self.spaces(1));
+        format!("{}}}\n", body)
+    }
+
+    // Merging of keys and filters by fields of keys, which are declared in @Identification
+    fn identification(&self, store: &Store) -> String {
+        let identification = if let Some(identification) = store.identification.as_ref() {
+            identification
+        } else {
+            return String::new();
+        };
+        let keys = [
+            (identification_keys::CONSUMER, "key", identification_names::SELF_KEY, identification.consumer),
+            (identification_keys::PRODUCER, "assigned", identification_names::ASSIGNED_KEY, identification.producer),
+        ];
+        let mut body = String::from("#[allow(non_snake_case)]\n#[allow(unused_variables)]\n");
+        body = format!("{}pub mod identification {{\n", body);
+        body = format!("{}{}use super::*;\n", body, self.spaces(1));
+        for (key, arg, name, id) in keys.iter() {
+            let fields: Vec<Field> = id.and_then(|id| store.get_struct(id)).map(|s| s.fields).unwrap_or_default();
+            let type_ref = format!("Protocol::{}::{}", identification_names::GROUP, name);
+            body = format!("{}\n{}// Copies defined fields of {} into existing one\n", body, self.spaces(1), name);
+            body = format!(
+                "{}{}pub fn merge_{}(existing: &mut {}, {}: {}) {{\n",
+                body,
+                self.spaces(1),
+                arg,
+                type_ref,
+                arg,
+                type_ref
+            );
+            for field in fields.iter() {
+                body = format!("{}{}if let Some({}) = {}.{} {{\n", body, self.spaces(2), field.name, arg, field.name);
+                body = format!("{}{}existing.{} = Some({});\n", body, self.spaces(3), field.name, field.name);
+                body = format!("{}{}}}\n", body, self.spaces(2));
+            }
+            body = format!("{}{}}}\n", body, self.spaces(1));
+            for field in fields.iter() {
+                body = format!(
+                    "{}\n{}// Accepts consumers, which have {}.{} equal to value\n",
+                    body,
+                    self.spaces(1),
+                    name,
+                    field.name
+                );
+                body = format!(
+                    "{}{}pub fn {}_{}(value: {}) -> Box<FilterCallback> {{\n",
+                    body,
+                    self.spaces(1),
+                    key,
+                    field.name,
+                    self.get_type_ref(field)
+                );
+                body = format!(
+                    "{}{}Box::new(move |_, key, assigned| matches!({}, Some({}) if {}.{}.as_ref() == Some(&value)))\n",
+                    body,
+                    self.spaces(2),
+                    arg,
+                    arg,
+                    arg,
+                    field.name
+                );
+                body = format!("{}{}}}\n", body, self.spaces(1));
+            }
+        }
+        format!("{}}}\n", body)
+    }
+
+    fn includes(&self, store: &Store) -> String {
+        let mut body = String::from("use super::consumer_context::{ Context };\n");
+        body = format!(
+            "{}use super::consumer_identification::{{ {} }};\n",
+            body,
+            if store.identification.is_some() { "Filter, FilterCallback" } else { "Filter" }
+        );
+        body = format!("{}use super::observer::{{ RequestObserverErrors }};\n", body);
+        body = format!("{}use super::protocol::{{ PackingStruct }};\n", body);
+        body = format!("{}use super::Protocol;\n", body);
+        body = format!("{}use std::sync::{{ Arc, RwLock }};\n\n", body);
+        body = format!(
+            "{}fn respond<T: PackingStruct>(cx: &dyn Context, sequence: u32, msg: &mut T) -> Result<(), RequestObserverErrors> {{\n",
+            body
+        );
+        body = format!("{}{}match msg.pack(sequence, Some(cx.uuid().to_string())) {{\n", body, self.spaces(1));
+        body = format!(
+            "{}{}Ok(buffer) => cx.send(buffer).map_err(RequestObserverErrors::ResponsingError),\n",
+            body,
+            self.spaces(2)
+        );
+        body = format!(
+            "{}{}Err(e) => Err(RequestObserverErrors::EncodingResponseError(e)),\n",
+            body,
+            self.spaces(2)
+        );
+        body = format!("{}{}}}\n", body, self.spaces(1));
+        body = format!("{}}}\n\n", body);
+        body = format!(
+            "{}#[allow(dead_code)]\nfn broadcasting<T: PackingStruct>(\n",
+            body
+        );
+        body = format!("{}{}cx: &dyn Context,\n", body, self.spaces(1));
+        body = format!("{}{}filter: Filter,\n", body, self.spaces(1));
+        body = format!("{}{}msg: &mut T,\n", body, self.spaces(1));
+        body = format!(
+            "{}{}broadcast: &dyn Fn(Filter, Vec<u8>) -> Result<(), String>,\n",
+            body,
+            self.spaces(1)
+        );
+        body = format!("{}) -> Result<(), RequestObserverErrors> {{\n", body);
+        body = format!("{}{}match msg.pack(0, Some(cx.uuid().to_string())) {{\n", body, self.spaces(1));
+        body = format!(
+            "{}{}Ok(buffer) => broadcast(filter, buffer).map_err(RequestObserverErrors::BroadcastingError),\n",
+            body,
+            self.spaces(2)
+        );
+        body = format!(
+            "{}{}Err(e) => Err(RequestObserverErrors::EncodingResponseError(e)),\n",
+            body,
+            self.spaces(2)
+        );
+        body = format!("{}{}}}\n", body, self.spaces(1));
+        format!("{}}}\n", body)
+    }
+
+    // Name of broadcast in broadcasting struct is a name of struct; full path is used
+    // only if names of broadcasts are same
+    fn get_broadcasts_fields<'a>(&self, broadcasts: &'a [Broadcast], store: &Store) -> Vec<(String, &'a Broadcast)> {
+        let names: Vec<String> = broadcasts.iter().map(|b| {
+            store.get_struct(b.id).map(|s| s.name).unwrap_or_default()
+        }).collect();
+        broadcasts.iter().enumerate().map(|(pos, broadcast)| {
+            let name = if names.iter().filter(|n| **n == names[pos]).count() > 1 {
+                store.get_struct_path(broadcast.id).join("")
+            } else {
+                names[pos].clone()
+            };
+            (name, broadcast)
+        }).collect()
+    }
+
+    // Fields of keys in @Identification are always primitive
+    fn get_type_ref(&self, field: &Field) -> String {
+        let type_ref = match field.kind.as_str() {
+            "str" => String::from("String"),
+            "bytes" => String::from("Vec<u8>"),
+            "timestamp" => String::from("std::time::SystemTime"),
+            "duration" => String::from("std::time::Duration"),
+            "uuid" => String::from("uuid::Uuid"),
+            kind => kind.to_string(),
+        };
+        if let Some(size) = field.fixed {
+            format!("[{}; {}]", type_ref, size)
+        } else if field.repeated && field.optional_items {
+            format!("Vec<Option<{}>>", type_ref)
+        } else if field.repeated {
+            format!("Vec<{}>", type_ref)
+        } else {
+            type_ref
+        }
+    }
+
+    fn get_name(&self, relation: &Relation, store: &Store) -> String {
+        store.get_relation_path(relation.id).join("")
+    }
+
+    fn get_struct_ref(&self, id: Option<usize>, store: &Store) -> String {
+        let path: Vec<String> = id.map(|id| store.get_struct_path(id)).unwrap_or_default();
+        if path.is_empty() {
+            crate::stop!("Fail to find struct of relation, id: {:?}", id);
+        }
+        format!("Protocol::{}", path.join("::"))
+    }
+
+    // Pattern to match request in Protocol::AvailableMessages
+    fn get_message_pattern(&self, id: Option<usize>, store: &Store) -> String {
+        let path: Vec<String> = id.map(|id| store.get_struct_path(id)).unwrap_or_default();
+        if path.is_empty() {
+            crate::stop!("Fail to find request of relation, id: {:?}", id);
+        }
+        let mut pattern = String::from("request");
+        for pos in (0..path.len()).rev() {
+            pattern = format!(
+                "Protocol::{}AvailableMessages::{}({})",
+                path[0..pos].iter().map(|p| format!("{}::", p)).collect::<String>(),
+                path[pos],
+                pattern
+            );
+        }
+        pattern
+    }
+
+    fn doc(&self, doc: &[String], level: u8) -> String {
+        doc.iter().map(|line| {
+            if line.is_empty() {
+                format!("{}///\n", self.spaces(level))
+            } else {
+                format!("{}/// {}\n", self.spaces(level), line)
+            }
+        }).collect::<Vec<String>>().join("")
+    }
+
+    fn spaces(&self, level: u8) -> String {
+        "    ".repeat(level as usize)
+    }
+}
+
+impl Render for ProducerRender {
+    fn new(embedded: bool, signature: u16) -> Self {
+        ProducerRender {
+            _embedded: embedded,
+            _signature: signature,
+        }
+    }
+
+    fn render(&self, store: Store) -> String {
+        let mut body = format!("{}\n", self.includes(&store));
+        if store.identification.is_some() {
+            body = format!("{}{}\n", body, self.identification(&store));
+        }
+        for relation in &store.relations {
+            body = format!("{}{}\n", body, self.relations(relation, &store, 0));
+        }
+        format!("{}{}", body, self.observers(&store))
+    }
+}
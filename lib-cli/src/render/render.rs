@@ -1,6 +1,9 @@
 use super::*;
 use super::parser::store::Store;
 
+#[path = "./render.producer.rs"]
+pub mod producer;
+
 #[path = "./render.rust.rs"]
 pub mod rust;
 
@@ -1,9 +1,13 @@
+use super::parser::aliases::Alias;
+use super::parser::constraints::Constraint;
+use super::parser::consts::Const;
 use super::parser::enums::{Enum, EnumItem};
 use super::parser::fields::Field;
 use super::parser::groups::Group;
 use super::parser::store::Store;
 use super::parser::structs::Struct;
 use super::parser::types::PrimitiveTypes;
+use super::parser::values::EValue;
 use super::Render;
 use regex::Regex;
 use std::include_str;
@@ -15,21 +19,34 @@ pub struct RustRender {
 
 impl RustRender {
     fn groups(&self, group: &Group, store: &mut Store, level: u8) -> String {
-        let mut body = format!("{}pub mod {} {{\n", self.spaces(level), group.name);
+        let mut body = format!("{}pub mod {} {{\n", self.spaces(level), self.get_module_name(group));
         body = format!("{}{}use super::*;\n", body, self.spaces(level + 1));
         body = format!("{}{}use std::io::Cursor;\n", body, self.spaces(level + 1));
         body = format!("{}{}use bytes::{{ Buf }};\n", body, self.spaces(level + 1));
-        body = format!(
-            "{}{}",
-            body,
-            self.get_messages_list(Some(group), &mut store.clone(), level + 1)
-        );
+        if group.owner.is_none() {
+            // Structs and enums, declared inside of struct, aren't messages
+            body = format!(
+                "{}{}",
+                body,
+                self.get_messages_list(Some(group), &mut store.clone(), level + 1)
+            );
+        }
+        for const_id in &group.consts {
+            if let Some(consts) = store.get_const(*const_id) {
+                body = format!("{}\n{}", body, self.consts(&consts, level + 1));
+            }
+        }
+        for alias_id in &group.aliases {
+            if let Some(alias) = store.get_alias(*alias_id) {
+                body = format!("{}\n{}", body, self.aliases(&alias, level + 1));
+            }
+        }
         for enum_id in &group.enums {
             if let Some(enums) = store.get_enum(*enum_id) {
                 body = format!(
                     "{}\n{}",
                     body,
-                    self.enums(&enums, level + 1)
+                    self.enums(&enums, &mut store.clone(), level + 1)
                 );
             }
         }
@@ -53,23 +70,82 @@ impl RustRender {
         format!("{}\n{}}}\n", body, self.spaces(level))
     }
 
+    fn consts(&self, consts: &Const, level: u8) -> String {
+        let mut body = self.doc(&consts.doc, level);
+        if consts.name.to_uppercase() != consts.name {
+            body = format!("{}{}#[allow(non_upper_case_globals)]\n", body, self.spaces(level));
+        }
+        let value = consts.value.as_ref().map(|value| self.value(value, &consts.kind)).unwrap_or_default();
+        format!(
+            "{}{}pub const {}: {} = {};\n",
+            body,
+            self.spaces(level),
+            consts.name,
+            if consts.kind == "str" { String::from("&str") } else { consts.kind.clone() },
+            value
+        )
+    }
+
+    // Newtype is a tuple struct, which is encoded and decoded as wrapped type
+    fn aliases(&self, alias: &Alias, level: u8) -> String {
+        let mut body = self.doc(&alias.doc, level);
+        let type_ref = self.get_primitive_type_ref(&alias.kind);
+        if !alias.newtype {
+            return format!("{}{}pub type {} = {};\n", body, self.spaces(level), alias.name, type_ref);
+        }
+        body = format!("{}{}#[derive(Debug, Clone, PartialEq)]\n", body, self.spaces(level));
+        body = format!("{}{}pub struct {}(pub {});\n", body, self.spaces(level), alias.name, type_ref);
+        body = format!("{}{}impl Encode for {} {{\n", body, self.spaces(level), alias.name);
+        body = format!("{}{}fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {{\n", body, self.spaces(level + 1));
+        body = format!("{}{}self.0.get_buf_to_store(id)\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}impl Decode<{}> for {} {{\n", body, self.spaces(level), alias.name, alias.name);
+        body = format!("{}{}fn get_from_storage(source: Source, id: Option<u16>) -> Result<{}, String> {{\n", body, self.spaces(level + 1), alias.name);
+        body = format!("{}{}Ok({}(<{}>::get_from_storage(source, id)?))\n", body, self.spaces(level + 2), alias.name, type_ref);
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        if alias.kind == "bytes" {
+            // bytes cannot be repeated
+            return body;
+        }
+        body = format!("{}{}impl Encode for Vec<{}> {{\n", body, self.spaces(level), alias.name);
+        body = format!("{}{}fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {{\n", body, self.spaces(level + 1));
+        body = format!("{}{}let mut items: Vec<{}> = self.iter().map(|item| item.0.clone()).collect();\n", body, self.spaces(level + 2), type_ref);
+        body = format!("{}{}items.get_buf_to_store(id)\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}impl Decode<Vec<{}>> for Vec<{}> {{\n", body, self.spaces(level), alias.name, alias.name);
+        body = format!("{}{}fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<{}>, String> {{\n", body, self.spaces(level + 1), alias.name);
+        body = format!("{}{}Ok(<Vec<{}>>::get_from_storage(source, id)?.into_iter().map({}).collect())\n", body, self.spaces(level + 2), type_ref, alias.name);
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body
+    }
+
     fn structs(&self, strct: &Struct, store: &mut Store, level: u8) -> String {
-        let mut body = format!("{}#[derive(Debug, Clone, PartialEq)]\n", self.spaces(level));
+        let allow = self.allow_deprecated(self.uses_deprecated(strct.id, store, &mut vec![]), level);
+        let mut body = format!(
+            "{}{}{}{}#[derive(Debug, Clone, PartialEq)]\n",
+            self.doc(&strct.doc, level),
+            self.deprecated(&strct.deprecated, level),
+            allow,
+            self.spaces(level)
+        );
         body = format!("{}{}pub struct {} {{", body, self.spaces(level), strct.name);
         for field in &strct.fields {
             body = format!(
-                "{}\n{}{}",
+                "{}\n{}{}{}pub {}: {},",
                 body,
+                self.doc(&field.doc, level + 1),
+                self.deprecated(&field.deprecated, level + 1),
                 self.spaces(level + 1),
-                format!(
-                    "pub {}: {},",
-                    field.name,
-                    self.get_declare_type_ref(field)
-                ),
+                field.name,
+                self.get_declare_type_ref(field, store)
             );
         }
         body = format!("{}\n{}}}\n", body, self.spaces(level));
-        body = format!("{}{}#[allow(unused_variables)]\n", body, self.spaces(level));
+        body = format!("{}{}{}#[allow(unused_variables)]\n", body, allow, self.spaces(level));
         body = format!("{}{}#[allow(unused_mut)]\n", body, self.spaces(level));
         body = format!(
             "{}{}impl StructDecode for {} {{\n",
@@ -78,7 +154,7 @@ impl RustRender {
             strct.name
         );
         body = format!("{}{}fn get_id() -> u32 {{\n", body, self.spaces(level + 1));
-        body = format!("{}{}{}\n", body, self.spaces(level + 2), strct.id);
+        body = format!("{}{}{}\n", body, self.spaces(level + 2), strct.wire_id);
         body = format!("{}{}}}\n", body, self.spaces(level + 1));
         body = format!(
             "{}{}fn defaults() -> {} {{\n",
@@ -105,12 +181,22 @@ impl RustRender {
         for field in &strct.fields {
             if field.optional {
                 if let Some(id) = field.ref_type_id {
-                    if let Some(enums) = store.get_enum(id) {
+                    if store.get_enum(id).is_some() {
+                        let enum_ref = self.get_type_ref(field, store);
+                        let type_ref = if let Some(key) = field.key.as_ref() {
+                            format!("HashMap::<{}, {}>", self.get_primitive_type_ref(key), enum_ref)
+                        } else if field.repeated && field.optional_items {
+                            format!("Vec::<Option<{}>>", enum_ref)
+                        } else if field.repeated {
+                            format!("Vec::<{}>", enum_ref)
+                        } else {
+                            enum_ref
+                        };
                         body = format!(
                             "{}{}if let Some(buf) = storage.get({}) {{\n",
                             body,
                             self.spaces(level + 2),
-                            field.id
+                            field.wire_id
                         );
                         body = format!("{}{}if buf.is_empty() {{\n", body, self.spaces(level + 3));
                         body = format!(
@@ -120,7 +206,7 @@ impl RustRender {
                             field.name
                         );
                         body = format!("{}{}}} else {{\n", body, self.spaces(level + 3));
-                        body = format!("{}{}self.{} = match {}::get_from_storage(Source::Storage(&mut storage), Some({})) {{\n", body, self.spaces(level + 4), field.name, enums.name, field.id);
+                        body = format!("{}{}self.{} = match {}::get_from_storage(Source::Storage(&mut storage), Some({})) {{\n", body, self.spaces(level + 4), field.name, type_ref, field.wire_id);
                         body = format!("{}{}Ok(val) => Some(val),\n", body, self.spaces(level + 5));
                         body = format!(
                             "{}{}Err(e) => {{ return Err(e) }},\n",
@@ -146,8 +232,8 @@ impl RustRender {
                 body,
                 self.spaces(level + 2),
                 field.name,
-                self.get_decode_type_ref(&field),
-                field.id
+                self.get_decode_type_ref(field, store),
+                field.wire_id
             );
             body = format!("{}{}Ok(val) => val,\n", body, self.spaces(level + 3));
             body = format!(
@@ -157,10 +243,13 @@ impl RustRender {
             );
             body = format!("{}{}}};\n", body, self.spaces(level + 2));
         }
+        for field in &strct.fields {
+            body = format!("{}{}", body, self.field_constraints(strct, field, level + 2));
+        }
         body = format!("{}{}Ok(())\n", body, self.spaces(level + 2));
         body = format!("{}{}}}\n", body, self.spaces(level + 1));
         body = format!("{}{}}}\n", body, self.spaces(level));
-        body = format!("{}{}#[allow(unused_variables)]\n", body, self.spaces(level));
+        body = format!("{}{}{}#[allow(unused_variables)]\n", body, allow, self.spaces(level));
         body = format!("{}{}#[allow(unused_mut)]\n", body, self.spaces(level));
         body = format!(
             "{}{}impl StructEncode for {} {{\n",
@@ -172,7 +261,7 @@ impl RustRender {
             "{}{}fn get_id(&self) -> u32 {{ {} }}\n",
             body,
             self.spaces(level + 1),
-            strct.id
+            strct.wire_id
         );
         body = format!(
             "{}{}fn get_signature(&self) -> u16 {{ {} }}\n",
@@ -204,7 +293,7 @@ impl RustRender {
                             "{}{}match val.get_buf_to_store(Some({})) {{\n",
                             body,
                             self.spaces(level + 3),
-                            field.id
+                            field.wire_id
                         );
                         body = format!(
                             "{}{}Ok(mut buf) => {{ buffer.append(&mut buf); }},\n",
@@ -222,7 +311,7 @@ impl RustRender {
                             "{}{}match get_empty_buffer_val(Some({})) {{\n",
                             body,
                             self.spaces(level + 3),
-                            field.id
+                            field.wire_id
                         );
                         body = format!(
                             "{}{}Ok(mut buf) => {{ buffer.append(&mut buf); }},\n",
@@ -245,7 +334,7 @@ impl RustRender {
                 body,
                 self.spaces(level + 2),
                 field.name,
-                field.id
+                field.wire_id
             );
             body = format!(
                 "{}{}Ok(mut buf) => {{ buffer.append(&mut buf); }}\n",
@@ -263,39 +352,92 @@ impl RustRender {
         body = format!("{}{}}}\n", body, self.spaces(level + 1));
         body = format!("{}{}}}\n", body, self.spaces(level));
         body = format!(
-            "{}{}impl PackingStruct for {} {{ }}\n",
+            "{}{}{}impl PackingStruct for {} {{ }}\n",
             body,
+            allow,
             self.spaces(level),
             strct.name
         );
         body
     }
 
-    fn enums(&self, enums: &Enum, level: u8) -> String {
-        let mut body = format!("{}#[derive(Debug, Clone, PartialEq)]\n", self.spaces(level));
+    // Numeric enum is encoded and decoded as u16, which is ID of item
+    fn numeric_enum(&self, enums: &Enum, store: &Store, level: u8) -> String {
+        let allow = self.allow_deprecated(self.uses_deprecated(enums.id, store, &mut vec![]), level);
+        let mut body = format!("{}{}{}#[derive(Debug, Clone, Copy, PartialEq)]\n", self.doc(&enums.doc, level), allow, self.spaces(level));
+        body = format!("{}{}#[repr(u16)]\n", body, self.spaces(level));
         body = format!("{}{}pub enum {} {{\n", body, self.spaces(level), enums.name);
         for item in &enums.variants {
-            let item_type = self.enum_item_type(item.clone());
             body = format!(
-                "{}{}{},\n",
+                "{}{}{}{}{} = {},\n",
                 body,
+                self.doc(&item.doc, level + 1),
+                self.deprecated(&item.deprecated, level + 1),
                 self.spaces(level + 1),
-                format!(
-                    "{}({})",
-                    item.name,
-                    if item.repeated {
-                        format!("Vec<{}>", item_type)
-                    } else {
-                        item_type
-                    }
-                ),
+                item.name,
+                item.wire_id
+            );
+        }
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}{}impl std::convert::TryFrom<u16> for {} {{\n", body, allow, self.spaces(level), enums.name);
+        body = format!("{}{}type Error = String;\n", body, self.spaces(level + 1));
+        body = format!("{}{}fn try_from(value: u16) -> Result<{}, String> {{\n", body, self.spaces(level + 1), enums.name);
+        body = format!("{}{}match value {{\n", body, self.spaces(level + 2));
+        for item in &enums.variants {
+            body = format!("{}{}{} => Ok({}::{}),\n", body, self.spaces(level + 3), item.wire_id, enums.name, item.name);
+        }
+        body = format!("{}{}_ => Err(format!(\"Invalid value {{}} of enum {}\", value)),\n", body, self.spaces(level + 3), enums.name);
+        body = format!("{}{}}}\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}{}impl Encode for {} {{\n", body, allow, self.spaces(level), enums.name);
+        body = format!("{}{}fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {{\n", body, self.spaces(level + 1));
+        body = format!("{}{}(*self as u16).get_buf_to_store(id)\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}{}impl Decode<{}> for {} {{\n", body, allow, self.spaces(level), enums.name, enums.name);
+        body = format!("{}{}fn get_from_storage(source: Source, id: Option<u16>) -> Result<{}, String> {{\n", body, self.spaces(level + 1), enums.name);
+        body = format!("{}{}<{} as std::convert::TryFrom<u16>>::try_from(<u16>::get_from_storage(source, id)?)\n", body, self.spaces(level + 2), enums.name);
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}{}impl Encode for Vec<{}> {{\n", body, allow, self.spaces(level), enums.name);
+        body = format!("{}{}fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {{\n", body, self.spaces(level + 1));
+        body = format!("{}{}let mut items: Vec<u16> = self.iter().map(|item| *item as u16).collect();\n", body, self.spaces(level + 2));
+        body = format!("{}{}items.get_buf_to_store(id)\n", body, self.spaces(level + 2));
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body = format!("{}{}{}impl Decode<Vec<{}>> for Vec<{}> {{\n", body, allow, self.spaces(level), enums.name, enums.name);
+        body = format!("{}{}fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<{}>, String> {{\n", body, self.spaces(level + 1), enums.name);
+        body = format!("{}{}<Vec<u16>>::get_from_storage(source, id)?.into_iter().map(<{} as std::convert::TryFrom<u16>>::try_from).collect()\n", body, self.spaces(level + 2), enums.name);
+        body = format!("{}{}}}\n", body, self.spaces(level + 1));
+        body = format!("{}{}}}\n", body, self.spaces(level));
+        body
+    }
+
+    fn enums(&self, enums: &Enum, store: &mut Store, level: u8) -> String {
+        if enums.numeric {
+            return self.numeric_enum(enums, store, level);
+        }
+        let allow = self.allow_deprecated(self.uses_deprecated(enums.id, store, &mut vec![]), level);
+        let mut body = format!("{}{}{}#[derive(Debug, Clone, PartialEq)]\n", self.doc(&enums.doc, level), allow, self.spaces(level));
+        body = format!("{}{}pub enum {} {{\n", body, self.spaces(level), enums.name);
+        for item in &enums.variants {
+            body = format!(
+                "{}{}{}{}{}({}),\n",
+                body,
+                self.doc(&item.doc, level + 1),
+                self.deprecated(&item.deprecated, level + 1),
+                self.spaces(level + 1),
+                item.name,
+                self.enum_item_declare_type(item, store)
             );
         }
         body = format!("{}{}Defaults,\n", body, self.spaces(level + 1));
         body = format!("{}{}}}\n", body, self.spaces(level));
         body = format!(
-            "{}{}impl EnumDecode for {} {{\n",
+            "{}{}{}impl EnumDecode for {} {{\n",
             body,
+            allow,
             self.spaces(level),
             enums.name
         );
@@ -303,7 +445,7 @@ impl RustRender {
             "{}{}fn get_id(&self) -> u32 {{ {} }}\n",
             body,
             self.spaces(level + 1),
-            enums.id
+            enums.wire_id
         );
         body = format!(
             "{}{}fn extract(buf: Vec<u8>) -> Result<{}, String> {{\n",
@@ -312,7 +454,7 @@ impl RustRender {
             enums.name
         );
         body = format!(
-            "{}{}if buf.len() <= sizes::U16_LEN {{\n",
+            "{}{}if buf.len() < sizes::U16_LEN {{\n",
             body,
             self.spaces(level + 2)
         );
@@ -340,17 +482,19 @@ impl RustRender {
             self.spaces(level + 2)
         );
         body = format!("{}{}match index {{\n", body, self.spaces(level + 2));
-        for (index, item) in enums.variants.iter().enumerate() {
-            let item_type = self.enum_item_type(item.clone());
+        for item in enums.variants.iter() {
             body = format!(
                 "{}{}{} => match {}::decode(&body_buf) {{\n",
                 body,
                 self.spaces(level + 3),
-                index,
-                if item.repeated {
-                    format!("Vec::<{}>", item_type)
+                item.wire_id,
+                if item.repeated || item.optional {
+                    // Static methods of generic type can be called only with turbofish
+                    self.enum_item_declare_type(item, store).replacen('<', "::<", 1)
+                } else if let Some(PrimitiveTypes::ETypes::Ebytes) = item.types {
+                    String::from("Vec::<u8>")
                 } else {
-                    item_type
+                    self.enum_item_type(item.clone(), store)
                 }
             );
             body = format!(
@@ -373,8 +517,9 @@ impl RustRender {
         body = format!("{}{}}}\n", body, self.spaces(level + 1));
         body = format!("{}{}}}\n", body, self.spaces(level));
         body = format!(
-            "{}{}impl EnumEncode for {} {{\n",
+            "{}{}{}impl EnumEncode for {} {{\n",
             body,
+            allow,
             self.spaces(level),
             enums.name
         );
@@ -382,7 +527,7 @@ impl RustRender {
             "{}{}fn get_id(&self) -> u32 {{ {} }}\n",
             body,
             self.spaces(level + 1),
-            enums.id,
+            enums.wire_id,
         );
         body = format!(
             "{}{}fn get_signature(&self) -> u16 {{ {} }}\n",
@@ -400,13 +545,13 @@ impl RustRender {
             body,
             self.spaces(level + 2)
         );
-        for (index, item) in enums.variants.iter().enumerate() {
+        for item in enums.variants.iter() {
             body = format!(
                 "{}{}Self::{}(v) => (v.encode(), {}),\n",
                 body,
                 self.spaces(level + 3),
                 item.name,
-                index
+                item.wire_id
             );
         }
         body = format!(
@@ -446,17 +591,18 @@ impl RustRender {
         body = format!("{}{}}}\n", body, self.spaces(level + 1));
         body = format!("{}{}}}\n", body, self.spaces(level));
         body = format!(
-            "{}{}impl PackingEnum for {} {{}}\n",
+            "{}{}{}impl PackingEnum for {} {{}}\n",
             body,
+            allow,
             self.spaces(level),
             enums.name
         );
         body
     }
 
-    fn enum_item_type(&self, item: EnumItem) -> String {
+    fn enum_item_type(&self, item: EnumItem, store: &Store) -> String {
         if let Some(type_ref) = item.types {
-            return match type_ref {
+            match type_ref {
                 PrimitiveTypes::ETypes::Ei8 => "i8",
                 PrimitiveTypes::ETypes::Ei16 => "i16",
                 PrimitiveTypes::ETypes::Ei32 => "i32",
@@ -468,17 +614,35 @@ impl RustRender {
                 PrimitiveTypes::ETypes::Ef32 => "f32",
                 PrimitiveTypes::ETypes::Ef64 => "f64",
                 PrimitiveTypes::ETypes::Ebool => "bool",
+                PrimitiveTypes::ETypes::Echar => "char",
+                PrimitiveTypes::ETypes::Eisize => "isize",
+                PrimitiveTypes::ETypes::Eusize => "usize",
                 PrimitiveTypes::ETypes::Estr => "String",
-                _ => {
-                    panic!("Unknown type ref {:?} for {}", type_ref, item.name);
-                }
+                PrimitiveTypes::ETypes::Ebytes => "Vec<u8>",
+                PrimitiveTypes::ETypes::Etimestamp => "std::time::SystemTime",
+                PrimitiveTypes::ETypes::Eduration => "std::time::Duration",
+                PrimitiveTypes::ETypes::Euuid => "uuid::Uuid",
             }
-            .to_string();
+            .to_string()
         } else {
-            return item.get_full_name();
+            self.get_type_path(&item.type_path, &item.ref_type_path, store).join("::")
         }
     }
 
+    fn enum_item_declare_type(&self, item: &EnumItem, store: &Store) -> String {
+        let mut type_str = self.enum_item_type(item.clone(), store);
+        if item.optional_items {
+            type_str = format!("Option<{}>", type_str);
+        }
+        if item.repeated {
+            type_str = format!("Vec<{}>", type_str);
+        }
+        if item.optional {
+            type_str = format!("Option<{}>", type_str);
+        }
+        type_str
+    }
+
     fn entity_default(&self, entity_id: usize, store: &mut Store, level: u8) -> String {
         if let Some(strct) = store.get_struct(entity_id) {
             let mut body = format!("{} {{\n", strct.name);
@@ -494,19 +658,37 @@ impl RustRender {
         } else if let Some(enums) = store.get_enum(entity_id) {
             format!("{}::Defaults", enums.name)
         } else {
-            panic!(format!("Fail to find a struct/enum id: {}", entity_id));
+            panic!("Fail to find a struct/enum id: {}", entity_id);
         }
     }
 
     fn field_default(&self, field: &Field, store: &mut Store, level: u8) -> String {
-        let path: String = if field.get_path().is_empty() { String::from("") } else { format!("{}::", field.get_path().join("::")) };
+        // Default value of struct is inlined, so entity is referred from root
+        let parent = field.ref_type_id.and_then(|id| store.get_struct(id).map(|s| s.parent).or_else(|| store.get_enum(id).map(|e| e.parent)));
+        let path: Vec<String> = parent.map(|parent| self.get_path(parent, store)).unwrap_or_default();
+        let path: String = if path.is_empty() { String::from("") } else { format!("{}::", path.join("::")) };
         let mut body = format!("{}: {}", field.name, path);
-        if field.repeated && !field.optional {
-            body = format!("{}vec![],", body);
+        if field.key.is_some() && !field.optional {
+            body = format!("{}: HashMap::new(),", field.name);
+        } else if let (Some(size), false) = (field.fixed, field.optional) {
+            let value = self.type_default_value(&field.kind).unwrap_or_else(|| panic!("Invalid type of fixed-size array {}", field.name));
+            body = format!("{}: [{}; {}],", field.name, value, size);
+        } else if field.repeated && !field.optional {
+            body = format!("{}: vec![],", field.name);
         } else if field.optional {
-            body = format!("{}None,", body);
+            body = format!("{}: None,", field.name);
+        } else if let Some(enums) = field.numeric.and_then(|id| store.get_enum(id)) {
+            // Numeric enum gets its first item
+            let name = self.get_full_name(enums.name.clone(), enums.parent, store);
+            body = format!("{}: {}::{},", field.name, name, enums.variants[0].name);
+        } else if let Some(value) = field.default.as_ref() {
+            let mut value = self.value(value, &field.kind);
+            if field.kind == "str" {
+                value = format!("String::from({})", value);
+            }
+            body = format!("{}: {},", field.name, self.newtype_value(field, &value, store));
         } else if let Some(default_value) = self.type_default_value(&field.kind) {
-            body = format!("{}{},", body, default_value);
+            body = format!("{}: {},", field.name, self.newtype_value(field, default_value, store));
         } else if let Some(struct_id) = field.ref_type_id {
             body = format!(
                 "{}{},",
@@ -517,6 +699,15 @@ impl RustRender {
         body
     }
 
+    // Value of newtype is wrapped into it
+    fn newtype_value(&self, field: &Field, value: &str, store: &Store) -> String {
+        if let Some(alias_id) = field.newtype {
+            format!("{}({})", store.get_alias_path(alias_id).join("::"), value)
+        } else {
+            value.to_string()
+        }
+    }
+
     fn type_default_value(&self, type_ref: &str) -> Option<&str> {
         match type_ref {
             "bool" => Some("true"),
@@ -530,35 +721,240 @@ impl RustRender {
             "u64" => Some("0"),
             "f32" => Some("0.0"),
             "f64" => Some("0.0"),
+            "char" => Some("'\\0'"),
+            "isize" => Some("0"),
+            "usize" => Some("0"),
             "str" => Some("String::from(\"\")"),
+            "bytes" => Some("vec![]"),
+            "timestamp" => Some("std::time::UNIX_EPOCH"),
+            "duration" => Some("std::time::Duration::from_millis(0)"),
+            "uuid" => Some("uuid::Uuid::nil()"),
             _ => None,
         }
     }
 
-    fn get_decode_type_ref(&self, field: &Field) -> String {
-        let mut type_str = self.get_type_ref(field);
-        if field.repeated {
+    // Checks of constraints of field, which are done after field was extracted
+    fn field_constraints(&self, strct: &Struct, field: &Field, level: u8) -> String {
+        let mut body = String::new();
+        if field.constraints.is_empty() {
+            return body;
+        }
+        let (target, level) = if field.optional {
+            body = format!(
+                "{}{}if let Some(value) = self.{}.as_ref() {{\n",
+                body,
+                self.spaces(level),
+                field.name
+            );
+            (String::from("value"), level + 1)
+        } else {
+            (format!("self.{}", field.name), level)
+        };
+        // Value of newtype is checked
+        let inner = if field.newtype.is_some() { ".0" } else { "" };
+        let each = field.repeated && field.key.is_none();
+        let (value, value_ref) = if each {
+            (format!("item{}", inner), if inner.is_empty() { String::from("*item") } else { format!("&item{}", inner) })
+        } else if field.optional && inner.is_empty() {
+            (target.clone(), target.clone())
+        } else {
+            (format!("{}{}", target, inner), format!("&{}{}", target, inner))
+        };
+        let len = if each {
+            format!("{}.len()", target)
+        } else if field.kind == "str" && field.key.is_none() {
+            format!("{}.chars().count()", value)
+        } else {
+            format!("{}.len()", value)
+        };
+        for constraint in &field.constraints {
+            match constraint {
+                Constraint::MinLen(min) => {
+                    let condition = match len.strip_suffix(".len()") {
+                        Some(target) if *min == 1 => format!("{}.is_empty()", target),
+                        _ => format!("{} < {}", len, min),
+                    };
+                    body = format!("{}{}if {} {{\n", body, self.spaces(level), condition);
+                    body = format!(
+                        "{}{}return Err(format!(\"Field \\\"{}\\\" of {} should have length at least {}, but length is {{}}\", {}));\n",
+                        body,
+                        self.spaces(level + 1),
+                        field.name,
+                        strct.name,
+                        min,
+                        len
+                    );
+                    body = format!("{}{}}}\n", body, self.spaces(level));
+                }
+                Constraint::MaxLen(max) => {
+                    body = format!("{}{}if {} > {} {{\n", body, self.spaces(level), len, max);
+                    body = format!(
+                        "{}{}return Err(format!(\"Field \\\"{}\\\" of {} should have length at most {}, but length is {{}}\", {}));\n",
+                        body,
+                        self.spaces(level + 1),
+                        field.name,
+                        strct.name,
+                        max,
+                        len
+                    );
+                    body = format!("{}{}}}\n", body, self.spaces(level));
+                }
+                Constraint::Pattern(pattern) => {
+                    // Pattern is compiled once on first decoding; static is scoped by function, so name of field is enough.
+                    // Crate with generated code should depend on regex and use rust 1.70+ (std::sync::OnceLock)
+                    let name = format!("PATTERN_{}", field.name.to_uppercase());
+                    body = format!(
+                        "{}{}static {}: std::sync::OnceLock<Result<regex::Regex, String>> = std::sync::OnceLock::new();\n",
+                        body,
+                        self.spaces(level),
+                        name
+                    );
+                    body = format!(
+                        "{}{}let pattern = {}.get_or_init(|| regex::Regex::new({:?}).map_err(|e| e.to_string())).as_ref().map_err(|e| e.clone())?;\n",
+                        body,
+                        self.spaces(level),
+                        name,
+                        pattern
+                    );
+                    if each {
+                        body = format!(
+                            "{}{}if let Some(item) = {}.iter().find(|item| !pattern.is_match(item)) {{\n",
+                            body,
+                            self.spaces(level),
+                            target
+                        );
+                    } else {
+                        body = format!(
+                            "{}{}if !pattern.is_match({}) {{\n",
+                            body,
+                            self.spaces(level),
+                            value_ref
+                        );
+                    }
+                    body = format!(
+                        "{}{}return Err(format!(\"Field \\\"{}\\\" of {} should match pattern {{}}, but value is {{:?}}\", pattern, {}));\n",
+                        body,
+                        self.spaces(level + 1),
+                        field.name,
+                        strct.name,
+                        value
+                    );
+                    body = format!("{}{}}}\n", body, self.spaces(level));
+                }
+                Constraint::Range(min, max) => {
+                    let range = format!(
+                        "({}..={})",
+                        self.value(&EValue::Number(min.to_string()), &field.kind),
+                        self.value(&EValue::Number(max.to_string()), &field.kind)
+                    );
+                    if each {
+                        body = format!(
+                            "{}{}if let Some(item) = {}.iter().find(|item| !{}.contains({})) {{\n",
+                            body,
+                            self.spaces(level),
+                            target,
+                            range,
+                            value_ref
+                        );
+                    } else {
+                        body = format!(
+                            "{}{}if !{}.contains({}) {{\n",
+                            body,
+                            self.spaces(level),
+                            range,
+                            value_ref
+                        );
+                    }
+                    body = format!(
+                        "{}{}return Err(format!(\"Field \\\"{}\\\" of {} should be in range {}..{}, but value is {{}}\", {}));\n",
+                        body,
+                        self.spaces(level + 1),
+                        field.name,
+                        strct.name,
+                        min,
+                        max,
+                        value
+                    );
+                    body = format!("{}{}}}\n", body, self.spaces(level));
+                }
+            }
+        }
+        if field.optional {
+            body = format!("{}{}}}\n", body, self.spaces(level - 1));
+        }
+        body
+    }
+
+    // Literal of value declared in protocol
+    fn value(&self, value: &EValue, kind: &str) -> String {
+        match value {
+            EValue::Str(value) if kind == "char" => format!("{:?}", value.chars().next().unwrap_or_default()),
+            EValue::Number(value) if (kind == "f32" || kind == "f64") && !value.contains('.') => format!("{}.0", value),
+            value => value.to_string(),
+        }
+    }
+
+    fn get_decode_type_ref(&self, field: &Field, store: &Store) -> String {
+        let mut type_str = self.get_type_ref(field, store);
+        if let Some(size) = field.fixed {
+            type_str = format!("[{}; {}]", type_str, size);
+            if !field.optional {
+                // Array type can be used in path of static method only in angle brackets
+                return format!("<{}>", type_str);
+            }
+        } else if field.repeated && field.optional_items {
+            type_str = format!("Vec::<Option<{}>>", type_str);
+        } else if field.repeated {
             type_str = format!("Vec::<{}>", type_str);
         }
+        if let Some(key) = field.key.as_ref() {
+            for key in field.value_keys.iter().rev() {
+                type_str = format!("HashMap::<{}, {}>", self.get_primitive_type_ref(key), type_str);
+            }
+            type_str = format!("HashMap::<{}, {}>", self.get_primitive_type_ref(key), type_str);
+        }
         if field.optional {
             type_str = format!("Option::<{}>", type_str);
+        } else if field.key.is_none() && field.newtype.is_none() && field.kind == "bytes" {
+            // Static methods of generic type can be called only with turbofish
+            type_str = String::from("Vec::<u8>");
         }
         type_str
     }
 
-    fn get_declare_type_ref(&self, field: &Field) -> String {
-        let mut type_str = self.get_type_ref(field);
-        if field.repeated {
+    fn get_declare_type_ref(&self, field: &Field, store: &Store) -> String {
+        let mut type_str = self.get_type_ref(field, store);
+        if let Some(size) = field.fixed {
+            type_str = format!("[{}; {}]", type_str, size);
+        } else if field.repeated && field.optional_items {
+            type_str = format!("Vec<Option<{}>>", type_str);
+        } else if field.repeated {
             type_str = format!("Vec<{}>", type_str);
         }
+        if let Some(key) = field.key.as_ref() {
+            for key in field.value_keys.iter().rev() {
+                type_str = format!("HashMap<{}, {}>", self.get_primitive_type_ref(key), type_str);
+            }
+            type_str = format!("HashMap<{}, {}>", self.get_primitive_type_ref(key), type_str);
+        }
         if field.optional {
             type_str = format!("Option<{}>", type_str);
         }
         type_str
     }
 
-    fn get_type_ref(&self, field: &Field) -> String {
-        match field.kind.clone().as_str() {
+    fn get_type_ref(&self, field: &Field, store: &Store) -> String {
+        if field.ref_type_id.is_some() || field.newtype.is_some() || field.numeric.is_some() {
+            self.get_type_path(&field.get_full_name(), &field.ref_type_path, store).join("::")
+        } else if PrimitiveTypes::is_valid(&field.kind) {
+            self.get_primitive_type_ref(&field.kind)
+        } else {
+            panic!("Invalid type definition for field {}", field.name);
+        }
+    }
+
+    fn get_primitive_type_ref(&self, kind: &str) -> String {
+        match kind {
             "bool" => String::from("bool"),
             "i8" => String::from("i8"),
             "i16" => String::from("i16"),
@@ -570,19 +966,25 @@ impl RustRender {
             "u64" => String::from("u64"),
             "f32" => String::from("f32"),
             "f64" => String::from("f64"),
+            "char" => String::from("char"),
+            "isize" => String::from("isize"),
+            "usize" => String::from("usize"),
             "str" => String::from("String"),
-            _ => {
-                if let Some(_ref_type_id) = field.ref_type_id {
-                    field.get_full_name().join("::")
-                } else {
-                    panic!("Invalid type definition for field {}", field.name);
-                }
-            }
+            "bytes" => String::from("Vec<u8>"),
+            "timestamp" => String::from("std::time::SystemTime"),
+            "duration" => String::from("std::time::Duration"),
+            "uuid" => String::from("uuid::Uuid"),
+            _ => panic!("Invalid primitive type {}", kind),
         }
     }
 
     fn get_messages_list(&self, group: Option<&Group>, store: &mut Store, level: u8) -> String {
-        let mut body = format!("{}#[derive(Debug, Clone)]\n", self.spaces(level));
+        let parent = group.map_or(0, |group| group.id);
+        let allow = self.allow_deprecated(
+            store.structs.iter().any(|s| s.parent == parent && s.deprecated.is_some()),
+            level,
+        );
+        let mut body = format!("{}{}#[derive(Debug, Clone)]\n", allow, self.spaces(level));
         if let Some(group) = group {
             body = format!(
                 "{}{}pub enum AvailableMessages {{\n",
@@ -590,7 +992,7 @@ impl RustRender {
                 self.spaces(level)
             );
             for enum_id in &group.enums {
-                if let Some(enums) = store.get_enum(*enum_id) {
+                if let Some(enums) = store.get_enum(*enum_id).filter(|e| !e.numeric) {
                     body = format!(
                         "{}{}{}({}),\n",
                         body,
@@ -613,7 +1015,7 @@ impl RustRender {
             }
             let childs = store.get_child_groups(group.id);
             for child in childs {
-                if child.parent == group.id {
+                if child.parent == group.id && child.owner.is_none() {
                     body = format!(
                         "{}{}{}({}::AvailableMessages),\n",
                         body,
@@ -631,7 +1033,7 @@ impl RustRender {
                 self.spaces(level)
             );
             for enums in &store.enums {
-                if enums.parent == 0 {
+                if enums.parent == 0 && !enums.numeric {
                     body = format!(
                         "{}{}{}({}),\n",
                         body,
@@ -653,7 +1055,7 @@ impl RustRender {
                 }
             }
             for group in &store.groups {
-                if group.parent == 0 {
+                if group.parent == 0 && group.owner.is_none() {
                     body = format!(
                         "{}{}{}({}::AvailableMessages),\n",
                         body,
@@ -675,7 +1077,7 @@ impl RustRender {
                 break;
             }
             if let Some(group) = store.get_group(parent) {
-                path.push(group.name.clone());
+                path.push(self.get_module_name(&group));
                 parent = group.parent;
             } else {
                 break;
@@ -685,6 +1087,31 @@ impl RustRender {
         path
     }
 
+    // Path to type as it's written in protocol
+    fn get_type_path(&self, path: &[String], ids: &[usize], store: &Store) -> Vec<String> {
+        path.iter().enumerate().map(|(pos, name)| {
+            match ids.get(pos).and_then(|id| store.get_group(*id)) {
+                Some(group) if pos < path.len() - 1 => self.get_module_name(&group),
+                _ => name.clone(),
+            }
+        }).collect()
+    }
+
+    // Module of struct's scope is named in snake case, because module and struct cannot have same name
+    fn get_module_name(&self, group: &Group) -> String {
+        if group.owner.is_none() {
+            return group.name.clone();
+        }
+        let mut name = String::new();
+        for (pos, ch) in group.name.chars().enumerate() {
+            if ch.is_uppercase() && pos > 0 {
+                name.push('_');
+            }
+            name.push(ch.to_ascii_lowercase());
+        }
+        name
+    }
+
     fn get_full_name(&self, name: String, parent: usize, store: &mut Store) -> String {
         let path: Vec<String> = self.get_path(parent, store);
         if path.is_empty() {
@@ -745,17 +1172,24 @@ impl RustRender {
 
     fn buffer(&self, store: &mut Store) -> String {
         let mut body = format!(
-            "{}impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {{\n",
+            "{}{}impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {{\n",
+            self.allow_deprecated(
+                store.structs.iter().any(|s| !store.is_scoped(s.parent) && s.deprecated.is_some()),
+                0,
+            ),
             self.spaces(0)
         );
         body = format!("{}{}fn get_msg(&self, id: u32, buf: &[u8]) -> Result<AvailableMessages, String> {{\n", body, self.spaces(1));
         body = format!("{}{}match id {{\n", body, self.spaces(2));
         for enums in &store.enums {
+            if store.is_scoped(enums.parent) || enums.numeric {
+                continue;
+            }
             body = format!(
                 "{}{}{} => match {}::extract(buf.to_vec()) {{\n",
                 body,
                 self.spaces(3),
-                enums.id,
+                enums.wire_id,
                 self.get_full_name(enums.name.clone(), enums.parent, &mut store.clone())
             );
             body = format!(
@@ -768,11 +1202,14 @@ impl RustRender {
             body = format!("{}{}}},\n", body, self.spaces(3));
         }
         for structs in &store.structs {
+            if store.is_scoped(structs.parent) {
+                continue;
+            }
             body = format!(
                 "{}{}{} => match {}::extract(buf.to_vec()) {{\n",
                 body,
                 self.spaces(3),
-                structs.id,
+                structs.wire_id,
                 self.get_full_name(structs.name.clone(), structs.parent, &mut store.clone())
             );
             body = format!(
@@ -832,6 +1269,56 @@ impl RustRender {
         re.replace_all(content, "").to_string()
     }
 
+    fn doc(&self, doc: &[String], level: u8) -> String {
+        doc.iter().map(|line| {
+            if line.is_empty() {
+                format!("{}///\n", self.spaces(level))
+            } else {
+                format!("{}/// {}\n", self.spaces(level), line)
+            }
+        }).collect::<Vec<String>>().join("")
+    }
+
+    fn deprecated(&self, deprecated: &Option<String>, level: u8) -> String {
+        match deprecated {
+            Some(reason) if reason.is_empty() => format!("{}#[deprecated]\n", self.spaces(level)),
+            Some(reason) => format!("{}#[deprecated(note = {:?})]\n", self.spaces(level), reason),
+            None => String::new(),
+        }
+    }
+
+    // Generated code uses deprecated entities itself; only usage out of protocol should be warned.
+    // Attribute is put on each item, because inner attribute cannot be used in code embedded with include!
+    fn allow_deprecated(&self, used: bool, level: u8) -> String {
+        if used {
+            format!("{}#[allow(deprecated)]\n", self.spaces(level))
+        } else {
+            String::new()
+        }
+    }
+
+    // Defaults of nested structs are inlined, so entity uses deprecated items of all referred entities
+    fn uses_deprecated(&self, id: usize, store: &Store, visited: &mut Vec<usize>) -> bool {
+        if visited.contains(&id) {
+            return false;
+        }
+        visited.push(id);
+        if let Some(strct) = store.get_struct(id) {
+            strct.deprecated.is_some()
+                || strct.fields.iter().any(|f| {
+                    f.deprecated.is_some()
+                        || f.ref_type_id.is_some_and(|id| self.uses_deprecated(id, store, visited))
+                })
+        } else if let Some(enums) = store.get_enum(id) {
+            enums.variants.iter().any(|v| {
+                v.deprecated.is_some()
+                    || v.ref_type_id.is_some_and(|id| self.uses_deprecated(id, store, visited))
+            })
+        } else {
+            false
+        }
+    }
+
     fn spaces(&self, level: u8) -> String {
         "    ".repeat(level as usize)
     }
@@ -852,9 +1339,19 @@ impl Render for RustRender {
             body,
             self.get_messages_list(None, &mut store.clone(), 0)
         );
+        for consts in &store.consts {
+            if consts.parent == 0 {
+                body = format!("{}{}\n", body, self.consts(consts, 0));
+            }
+        }
+        for alias in &store.aliases {
+            if alias.parent == 0 {
+                body = format!("{}{}\n", body, self.aliases(alias, 0));
+            }
+        }
         for enums in &store.enums {
             if enums.parent == 0 {
-                body = format!("{}{}\n", body, self.enums(enums, 0));
+                body = format!("{}{}\n", body, self.enums(enums, &mut store.clone(), 0));
             }
         }
         for strct in &store.structs {
@@ -1,9 +1,13 @@
-use super::parser::enums::Enum;
+use super::parser::aliases::Alias;
+use super::parser::constraints::Constraint;
+use super::parser::consts::Const;
+use super::parser::enums::{Enum, EnumItem};
 use super::parser::fields::Field;
 use super::parser::groups::Group;
 use super::parser::store::Store;
 use super::parser::structs::Struct;
 use super::parser::types::PrimitiveTypes;
+use super::parser::values::EValue;
 use super::{ Render, stop };
 use regex::Regex;
 use std::include_str;
@@ -16,11 +20,24 @@ pub struct TypescriptRender {
 impl TypescriptRender {
     fn groups(&self, group: &Group, store: &mut Store, level: u8) -> String {
         let mut body = format!("{}export namespace {} {{\n", self.spaces(level), group.name);
-        body = format!(
-            "{}{}",
-            body,
-            self.get_messages_list(Some(group), &mut store.clone(), level + 1)
-        );
+        if group.owner.is_none() {
+            // Structs and enums, declared inside of struct, aren't messages
+            body = format!(
+                "{}{}",
+                body,
+                self.get_messages_list(Some(group), &mut store.clone(), level + 1)
+            );
+        }
+        for const_id in &group.consts {
+            if let Some(consts) = store.get_const(*const_id) {
+                body = format!("{}\n{}", body, self.consts(&consts, level + 1));
+            }
+        }
+        for alias_id in &group.aliases {
+            if let Some(alias) = store.get_alias(*alias_id) {
+                body = format!("{}\n{}", body, self.aliases(&alias, level + 1));
+            }
+        }
         for enum_id in &group.enums {
             if let Some(enums) = store.get_enum(*enum_id) {
                 body = format!(
@@ -50,24 +67,61 @@ impl TypescriptRender {
         format!("{}\n{}}}\n", body, self.spaces(level))
     }
 
+    fn consts(&self, consts: &Const, level: u8) -> String {
+        let etype = if let Some(etype) = PrimitiveTypes::get_entity(&consts.kind) {
+            etype
+        } else {
+            stop!("Unknown type of constant {}", consts.name);
+        };
+        let value = consts.value.as_ref().map(|value| self.value(value, &consts.kind)).unwrap_or_default();
+        format!(
+            "{}{}export const {}: {} = {};\n",
+            self.doc(&consts.doc, level),
+            self.spaces(level),
+            consts.name,
+            self.etype_ts(etype, false),
+            value
+        )
+    }
+
+    // Newtype is a branded type: value of wrapped type cannot be used as newtype without cast
+    fn aliases(&self, alias: &Alias, level: u8) -> String {
+        let type_ref = if let Some(type_ref) = self.get_primitive_type_ref(&alias.kind) {
+            type_ref
+        } else {
+            stop!("Unknown type of alias {}", alias.name);
+        };
+        format!(
+            "{}{}export type {} = {};\n",
+            self.doc(&alias.doc, level),
+            self.spaces(level),
+            alias.name,
+            if alias.newtype {
+                format!("{} & {{ readonly __brand: '{}' }}", type_ref, alias.name)
+            } else {
+                type_ref
+            }
+        )
+    }
+
     fn structs(&self, strct: &Struct, store: &mut Store, level: u8) -> String {
-        let mut body = format!("{}export interface I{} {{", self.spaces(level), strct.name);
+        let doc = self.deprecated_doc(&strct.doc, &strct.deprecated);
+        let mut body = format!("{}{}export interface I{} {{", self.doc(&doc, level), self.spaces(level), strct.name);
         for field in &strct.fields {
             body = format!(
-                "{}\n{}{}",
+                "{}\n{}{}{}: {};",
                 body,
+                self.doc(&self.deprecated_doc(&field.doc, &field.deprecated), level + 1),
                 self.spaces(level + 1),
-                format!(
-                    "{}: {};",
-                    field.name,
-                    self.get_declare_type_ref(field, &mut store.clone())
-                ),
+                field.name,
+                self.get_declare_type_ref(field, &mut store.clone())
             );
         }
         body = format!("{}\n{}}}\n", body, self.spaces(level));
         body = format!(
-            "{}{}export class {} extends Protocol.Convertor implements I{}, ISigned<{}> {{\n",
+            "{}{}{}export class {} extends Protocol.Convertor implements I{}, ISigned<{}> {{\n",
             body,
+            self.doc(&doc, level),
             self.spaces(level),
             strct.name,
             strct.name,
@@ -76,7 +130,7 @@ impl TypescriptRender {
         body = format!(
             "{}\n{}",
             body,
-            self.struct_map(&strct, &mut store.clone(), level + 1)
+            self.struct_map(strct, &mut store.clone(), level + 1)
         );
 
         body = format!(
@@ -93,31 +147,29 @@ impl TypescriptRender {
         );
         body = format!("{}\n{}}}\n", body, self.spaces(level + 1));
 
-        body = format!("{}\n{}", body, self.struct_validator(&strct, level + 1));
+        body = format!("{}\n{}", body, self.struct_validator(strct, level + 1));
 
-        body = format!("{}\n{}", body, self.struct_from(&strct, level + 1));
+        body = format!("{}\n{}", body, self.struct_from(strct, level + 1));
 
         for field in &strct.fields {
             body = format!(
-                "{}\n{}{}",
+                "{}\n{}{}public {}: {};",
                 body,
+                self.doc(&self.deprecated_doc(&field.doc, &field.deprecated), level + 1),
                 self.spaces(level + 1),
-                format!(
-                    "public {}: {};",
-                    field.name,
-                    self.get_declare_type_ref(field, &mut store.clone())
-                ),
+                field.name,
+                self.get_declare_type_ref(field, &mut store.clone())
             );
         }
 
         for field in &strct.fields {
-            if let Some(ref_type_id) = field.ref_type_id {
+            if let (Some(ref_type_id), None, false) = (field.ref_type_id, field.key.as_ref(), field.optional_items) {
                 if store.get_enum(ref_type_id).is_some() {
                     body = format!(
-                        "{}\n{}{}",
+                        "{}\n{}private _{}: Primitives.Enum;",
                         body,
                         self.spaces(level + 1),
-                        format!("private _{}: Primitives.Enum;", field.name,),
+                        field.name,
                     );
                 }
             }
@@ -133,13 +185,13 @@ impl TypescriptRender {
             "{}{}public static getId(): number {{ return {}; }}\n",
             body,
             self.spaces(level + 1),
-            strct.id
+            strct.wire_id
         );
         body = format!("{}\n", body);
         body = format!(
             "{}\n{}",
             body,
-            self.struct_constructor(&strct, &mut store.clone(), level + 1)
+            self.struct_constructor(strct, &mut store.clone(), level + 1)
         );
         body = format!("{}\n", body);
         body = format!(
@@ -167,7 +219,7 @@ impl TypescriptRender {
             "{}{}public getId(): number {{ return {}; }}\n",
             body,
             self.spaces(level + 1),
-            strct.id
+            strct.wire_id
         );
         body = format!("{}\n", body);
         body = format!(
@@ -234,7 +286,15 @@ impl TypescriptRender {
     fn enum_declaration(&self, enums: &Enum, store: &mut Store, level: u8) -> String {
         let mut body = "[".to_string();
         for variant in &enums.variants {
-            if let Some(prim_type_ref) = variant.types.clone() {
+            if variant.optional_items {
+                let (signature, _, _, _) = self.get_enum_item_refs(enums, variant, store);
+                body = format!(
+                    "{}\n{}Protocol.Primitives.Nullable.getSignature({}),",
+                    body,
+                    self.spaces(level),
+                    signature
+                );
+            } else if let Some(prim_type_ref) = variant.types.clone() {
                 body = format!(
                     "{}\n{}Protocol.Primitives.{}.getSignature(),",
                     body,
@@ -263,13 +323,21 @@ impl TypescriptRender {
 
     fn enum_getter(&self, enums: &Enum, store: &mut Store, level: u8) -> String {
         let mut body = format!("{}switch (id) {{", self.spaces(level));
-        for (pos, variant) in enums.variants.iter().enumerate() {
-            if let Some(prim_type_ref) = variant.types.clone() {
+        for variant in enums.variants.iter() {
+            if variant.optional || variant.optional_items {
+                body = format!(
+                    "{}\n{}case {}: return {};",
+                    body,
+                    self.spaces(level + 1),
+                    variant.wire_id,
+                    self.get_enum_item_signed(enums, variant, None, store)
+                );
+            } else if let Some(prim_type_ref) = variant.types.clone() {
                 body = format!(
                     "{}\n{}case {}: return new Protocol.Primitives.{}({});",
                     body,
                     self.spaces(level + 1),
-                    pos,
+                    variant.wire_id,
                     self.etype(prim_type_ref.clone(), variant.repeated),
                     self.etype_def(prim_type_ref, variant.repeated)
                 );
@@ -279,7 +347,7 @@ impl TypescriptRender {
                         "{}\n{}case {}: return {}.defaults();",
                         body,
                         self.spaces(level + 1),
-                        pos,
+                        variant.wire_id,
                         store.get_struct_path(strct.id).join(".")
                     );
                 } else {
@@ -302,8 +370,10 @@ impl TypescriptRender {
             self.spaces(level + 1)
         );
         body = format!("{}\n{}}}", body, self.spaces(level));
-        for (pos, variant) in enums.variants.iter().enumerate() {
-            let value = if let Some(prim_type_ref) = variant.types.clone() {
+        for variant in enums.variants.iter() {
+            let value = if variant.optional || variant.optional_items {
+                self.get_enum_item_signed(enums, variant, Some(format!("src.{}", variant.name)), store)
+            } else if let Some(prim_type_ref) = variant.types.clone() {
                 format!(
                     "new Protocol.Primitives.{}(src.{})",
                     self.etype(prim_type_ref.clone(), variant.repeated),
@@ -318,27 +388,14 @@ impl TypescriptRender {
                 );
             };
             //
-            let types = if let Some(prim_type_ref) = variant.types.clone() {
-                self.etype_ts(prim_type_ref, variant.repeated)
-            } else if let Some(ref_type_id) = variant.ref_type_id {
-                if let Some(strct) = store.get_struct(ref_type_id) {
-                    store.get_struct_path(strct.id).join(".")
-                } else {
-                    stop!("Unknown type of data in scope of enum {} / {}, ref_type_id: {}. Failed to find a struct. ", enums.name, variant.name, ref_type_id);
-                }
-            } else {
-                stop!(
-                    "Unknown type of data in scope of enum {} / {}",
-                    enums.name, variant.name
-                );
-            };
+            let types = self.get_enum_item_type(enums, variant, store);
             body = format!(
                 "{}\n{}if (src.{} !== undefined) {{",
                 body,
                 self.spaces(level),
                 variant.name
             );
-            body = format!("{}\n{}const err: Error | undefined = this.setValue(new Protocol.Primitives.Option<{}>({}, {}));", body, self.spaces(level + 1), types, pos, value);
+            body = format!("{}\n{}const err: Error | undefined = this.setValue(new Protocol.Primitives.Option<{}>({}, {}));", body, self.spaces(level + 1), types, variant.wire_id, value);
             body = format!(
                 "{}\n{}if (err instanceof Error) {{",
                 body,
@@ -353,26 +410,13 @@ impl TypescriptRender {
 
     fn get_enum_decode(&self, enums: &Enum, store: &mut Store, level: u8) -> String {
         let mut body = format!("{}switch (this.getValueIndex()) {{", self.spaces(level),);
-        for (pos, variant) in enums.variants.iter().enumerate() {
-            let types = if let Some(prim_type_ref) = variant.types.clone() {
-                self.etype_ts(prim_type_ref, variant.repeated)
-            } else if let Some(ref_type_id) = variant.ref_type_id {
-                if let Some(strct) = store.get_struct(ref_type_id) {
-                    store.get_struct_path(strct.id).join(".")
-                } else {
-                    stop!("Unknown type of data in scope of enum {} / {}, ref_type_id: {}. Failed to find a struct. ", enums.name, variant.name, ref_type_id);
-                }
-            } else {
-                stop!(
-                    "Unknown type of data in scope of enum {} / {}",
-                    enums.name, variant.name
-                );
-            };
+        for variant in enums.variants.iter() {
+            let types = self.get_enum_item_type(enums, variant, store);
             body = format!(
                 "{}\n{}case {}: target.{} = this.getValue<{}>(); break;",
                 body,
                 self.spaces(level + 1),
-                pos,
+                variant.wire_id,
                 variant.name,
                 types
             );
@@ -381,6 +425,110 @@ impl TypescriptRender {
         body
     }
 
+    // Type of enum's item: T, Array<T | undefined> for optional items and T | null for optional item
+    fn get_enum_item_type(&self, enums: &Enum, variant: &EnumItem, store: &mut Store) -> String {
+        let mut type_str = if let Some(prim_type_ref) = variant.types.clone() {
+            self.etype_ts(prim_type_ref, variant.repeated && !variant.optional_items)
+        } else if let Some(ref_type_id) = variant.ref_type_id {
+            if let Some(strct) = store.get_struct(ref_type_id) {
+                store.get_struct_path(strct.id).join(".")
+            } else {
+                stop!("Unknown type of data in scope of enum {} / {}, ref_type_id: {}. Failed to find a struct. ", enums.name, variant.name, ref_type_id);
+            }
+        } else {
+            stop!(
+                "Unknown type of data in scope of enum {} / {}",
+                enums.name, variant.name
+            );
+        };
+        if variant.optional_items {
+            type_str = format!("Array<{} | undefined>", type_str);
+        }
+        if variant.optional {
+            type_str = format!("{} | null", type_str);
+        }
+        type_str
+    }
+
+    // Returns signature, encoder, decoder and validator of enum's item. For optional items
+    // refs are related to single item of array
+    fn get_enum_item_refs(&self, enums: &Enum, variant: &EnumItem, store: &mut Store) -> (String, String, String, String) {
+        if let Some(prim_type_ref) = variant.types.clone() {
+            let primitive = self.etype(prim_type_ref, variant.repeated && !variant.optional_items);
+            (
+                format!("Protocol.Primitives.{}.getSignature()", primitive),
+                format!("Protocol.Primitives.{}.encode", primitive),
+                format!("Protocol.Primitives.{}.decode", primitive),
+                format!("Protocol.Primitives.{}", primitive),
+            )
+        } else if let Some(ref_type_id) = variant.ref_type_id {
+            if store.get_struct(ref_type_id).is_some() {
+                let path = store.get_struct_path(ref_type_id).join(".");
+                (
+                    format!("{}.getSignature()", path),
+                    format!("(value: {}) => value.encode()", path),
+                    format!("{}.from", path),
+                    format!("{}.getValidator({})", path, variant.repeated && !variant.optional_items),
+                )
+            } else {
+                stop!("Unknown type of data in scope of enum {} / {}, ref_type_id: {}. Failed to find a struct. ", enums.name, variant.name, ref_type_id);
+            }
+        } else {
+            stop!(
+                "Unknown type of data in scope of enum {} / {}",
+                enums.name, variant.name
+            );
+        }
+    }
+
+    // Signed value of optional enum's item or of enum's item with optional items. If value isn't
+    // defined, default value is used. Optional item gets null as Protocol.Primitives.Optional
+    fn get_enum_item_signed(&self, enums: &Enum, variant: &EnumItem, value: Option<String>, store: &mut Store) -> String {
+        let signed = |value: Option<String>, store: &mut Store| -> String {
+            if variant.optional_items {
+                let (signature, encoder, decoder, _) = self.get_enum_item_refs(enums, variant, store);
+                let item_type = self.get_enum_item_type(enums, &EnumItem { optional: false, optional_items: false, repeated: false, ..variant.clone() }, store);
+                format!(
+                    "new Protocol.Primitives.Nullable<{}>({}, {}, {}, {})",
+                    item_type,
+                    value.unwrap_or_else(|| "[]".to_string()),
+                    signature,
+                    encoder,
+                    decoder
+                )
+            } else if let Some(prim_type_ref) = variant.types.clone() {
+                format!(
+                    "new Protocol.Primitives.{}({})",
+                    self.etype(prim_type_ref.clone(), variant.repeated),
+                    value.unwrap_or_else(|| self.etype_def(prim_type_ref, variant.repeated))
+                )
+            } else if let Some(ref_type_id) = variant.ref_type_id {
+                value.unwrap_or_else(|| format!("{}.defaults()", store.get_struct_path(ref_type_id).join(".")))
+            } else {
+                stop!(
+                    "Unknown type of data in scope of enum {} / {}",
+                    enums.name, variant.name
+                );
+            }
+        };
+        if !variant.optional {
+            return signed(value, store);
+        }
+        let types = self.get_enum_item_type(enums, &EnumItem { optional: false, ..variant.clone() }, store);
+        if let Some(value) = value {
+            format!(
+                "new Protocol.Primitives.Optional<{}>({} === null ? {} : {}, {} === null)",
+                types,
+                value,
+                signed(None, store),
+                signed(Some(value.clone()), store),
+                value
+            )
+        } else {
+            format!("new Protocol.Primitives.Optional<{}>({})", types, signed(None, store))
+        }
+    }
+
     fn struct_constructor(&self, strct: &Struct, store: &mut Store, level: u8) -> String {
         let mut body = format!(
             "{}constructor(params: I{})  {{\n",
@@ -400,7 +548,7 @@ impl TypescriptRender {
         );
         body = format!("{}{}}});", body, self.spaces(level + 1));
         for field in &strct.fields {
-            if let Some(ref_type_id) = field.ref_type_id {
+            if let (Some(ref_type_id), None, false) = (field.ref_type_id, field.key.as_ref(), field.optional_items) {
                 if let Some(enums) = store.get_enum(ref_type_id) {
                     // -------
                     body = format!(
@@ -408,7 +556,7 @@ impl TypescriptRender {
                         body,
                         self.spaces(level + 1),
                         field.name,
-                        enums.name,
+                        self.get_enum_ref(&enums, store, false),
                     );
                     body = format!(
                         "{}\n{}{}this._{}.set(this.{});",
@@ -559,33 +707,43 @@ impl TypescriptRender {
         body
     }
 
+    // Numeric enum is encoded as u16, which is ID of item
+    fn numeric_enum(&self, enums: &Enum, level: u8) -> String {
+        let mut body = format!(
+            "{}{}export enum {} {{\n",
+            self.doc(&enums.doc, level),
+            self.spaces(level),
+            enums.name
+        );
+        for variant in &enums.variants {
+            body = format!(
+                "{}{}{}{} = {},\n",
+                body,
+                self.doc(&self.deprecated_doc(&variant.doc, &variant.deprecated), level + 1),
+                self.spaces(level + 1),
+                variant.name,
+                variant.wire_id
+            );
+        }
+        format!("{}{}}}\n", body, self.spaces(level))
+    }
+
     fn enums(&self, enums: &Enum, store: &mut Store, level: u8) -> String {
+        if enums.numeric {
+            return self.numeric_enum(enums, level);
+        }
         let mut body = format!(
-            "{}export interface I{} {{\n",
+            "{}{}export interface I{} {{\n",
+            self.doc(&enums.doc, level),
             self.spaces(level),
             enums.name
         );
         for variant in &enums.variants {
-            let variant_type = if let Some(prim_type_ref) = variant.types.clone() {
-                self.etype_ts(prim_type_ref.clone(), variant.repeated)
-            } else if let Some(ref_type_id) = variant.ref_type_id {
-                if let Some(strct) = store.get_struct(ref_type_id) {
-                    store.get_struct_path(strct.id).join(".")
-                } else {
-                    stop!(
-                        "Unknown type of data in scope of enum {} / {}, ref_type_id: {}",
-                        enums.name, variant.name, ref_type_id
-                    );
-                }
-            } else {
-                stop!(
-                    "Unknown type of data in scope of enum {} / {} ",
-                    enums.name, variant.name
-                );
-            };
+            let variant_type = self.get_enum_item_type(enums, variant, store);
             body = format!(
-                "{}{}{}?: {};\n",
+                "{}{}{}{}?: {};\n",
                 body,
+                self.doc(&self.deprecated_doc(&variant.doc, &variant.deprecated), level + 1),
                 self.spaces(level + 1),
                 variant.name,
                 variant_type
@@ -594,8 +752,9 @@ impl TypescriptRender {
         body = format!("{}{}}}\n", body, self.spaces(level));
         body = format!("{}\n", body);
         body = format!(
-            "{}{}export class {} extends Protocol.Primitives.Enum<I{}> {{\n",
+            "{}{}{}export class {} extends Protocol.Primitives.Enum<I{}> {{\n",
             body,
+            self.doc(&enums.doc, level),
             self.spaces(level),
             enums.name,
             enums.name
@@ -636,7 +795,7 @@ impl TypescriptRender {
             "{}{}public static getId(): number {{ return {}; }}\n",
             body,
             self.spaces(level + 1),
-            enums.id
+            enums.wire_id
         );
         body = format!(
             "{}{}public from(obj: any): I{} | Error {{\n",
@@ -661,7 +820,7 @@ impl TypescriptRender {
             "{}{}public getId(): number {{ return {}; }}\n",
             body,
             self.spaces(level + 1),
-            enums.id
+            enums.wire_id
         );
         body = format!(
             "{}{}public getAllowed(): string[] {{\n",
@@ -799,8 +958,48 @@ impl TypescriptRender {
                     "StrUTF8"
                 }
             }
-            _ => {
-                stop!("Unknown type ref {:?}", etype);
+            PrimitiveTypes::ETypes::Echar => {
+                if repeated {
+                    "ArrayChar"
+                } else {
+                    "char"
+                }
+            }
+            PrimitiveTypes::ETypes::Eisize => {
+                if repeated {
+                    "ArrayIsize"
+                } else {
+                    "isize"
+                }
+            }
+            PrimitiveTypes::ETypes::Eusize => {
+                if repeated {
+                    "ArrayUsize"
+                } else {
+                    "usize"
+                }
+            }
+            PrimitiveTypes::ETypes::Ebytes => "Bytes",
+            PrimitiveTypes::ETypes::Etimestamp => {
+                if repeated {
+                    "ArrayTimestamp"
+                } else {
+                    "timestamp"
+                }
+            }
+            PrimitiveTypes::ETypes::Eduration => {
+                if repeated {
+                    "ArrayDuration"
+                } else {
+                    "duration"
+                }
+            }
+            PrimitiveTypes::ETypes::Euuid => {
+                if repeated {
+                    "ArrayUuid"
+                } else {
+                    "uuid"
+                }
             }
         }
         .to_string()
@@ -892,8 +1091,48 @@ impl TypescriptRender {
                     "''"
                 }
             }
-            _ => {
-                stop!("Unknown type ref {:?}", etype);
+            PrimitiveTypes::ETypes::Echar => {
+                if repeated {
+                    "['\\0']"
+                } else {
+                    "'\\0'"
+                }
+            }
+            PrimitiveTypes::ETypes::Eisize => {
+                if repeated {
+                    "[BigInt(0)]"
+                } else {
+                    "BigInt(0)"
+                }
+            }
+            PrimitiveTypes::ETypes::Eusize => {
+                if repeated {
+                    "[BigInt(0)]"
+                } else {
+                    "BigInt(0)"
+                }
+            }
+            PrimitiveTypes::ETypes::Ebytes => "new Uint8Array()",
+            PrimitiveTypes::ETypes::Etimestamp => {
+                if repeated {
+                    "[new Date(0)]"
+                } else {
+                    "new Date(0)"
+                }
+            }
+            PrimitiveTypes::ETypes::Eduration => {
+                if repeated {
+                    "[0]"
+                } else {
+                    "0"
+                }
+            }
+            PrimitiveTypes::ETypes::Euuid => {
+                if repeated {
+                    "['00000000-0000-0000-0000-000000000000']"
+                } else {
+                    "'00000000-0000-0000-0000-000000000000'"
+                }
             }
         }
         .to_string()
@@ -985,8 +1224,48 @@ impl TypescriptRender {
                     "string"
                 }
             }
-            _ => {
-                stop!("Unknown type ref {:?}", etype);
+            PrimitiveTypes::ETypes::Echar => {
+                if repeated {
+                    "Array<string>"
+                } else {
+                    "string"
+                }
+            }
+            PrimitiveTypes::ETypes::Eisize => {
+                if repeated {
+                    "Array<bigint>"
+                } else {
+                    "bigint"
+                }
+            }
+            PrimitiveTypes::ETypes::Eusize => {
+                if repeated {
+                    "Array<bigint>"
+                } else {
+                    "bigint"
+                }
+            }
+            PrimitiveTypes::ETypes::Ebytes => "Uint8Array",
+            PrimitiveTypes::ETypes::Etimestamp => {
+                if repeated {
+                    "Array<Date>"
+                } else {
+                    "Date"
+                }
+            }
+            PrimitiveTypes::ETypes::Eduration => {
+                if repeated {
+                    "Array<number>"
+                } else {
+                    "number"
+                }
+            }
+            PrimitiveTypes::ETypes::Euuid => {
+                if repeated {
+                    "Array<string>"
+                } else {
+                    "string"
+                }
             }
         }
         .to_string()
@@ -1015,10 +1294,20 @@ impl TypescriptRender {
         let mut body = format!("{}: ", field.name);
         if field.optional {
             body = format!("{}undefined", body);
+        } else if field.key.is_some() {
+            body = format!("{}new Map()", body);
+        } else if let Some(size) = field.fixed {
+            let value = self.type_default_value(&field.kind).unwrap_or_else(|| stop!("Invalid type of fixed-size array {}", field.name));
+            body = format!("{}Array.from({{ length: {} }}, () => {})", body, size, value);
         } else if field.repeated {
             body = format!("{}[]", body);
+        } else if let Some(enums) = field.numeric.and_then(|id| store.get_enum(id)) {
+            // Numeric enum gets its first item
+            body = format!("{}{}.{}", body, store.get_enum_path(enums.id).join("."), enums.variants[0].name);
+        } else if let Some(value) = field.default.as_ref() {
+            body = format!("{}{}", body, self.newtype_value(field, &self.value(value, &field.kind), store));
         } else if let Some(default_value) = self.type_default_value(&field.kind) {
-            body = format!("{}{}", body, default_value);
+            body = format!("{}{}", body, self.newtype_value(field, default_value, store));
         } else if let Some(struct_id) = field.ref_type_id {
             body = format!(
                 "{}{}",
@@ -1029,18 +1318,52 @@ impl TypescriptRender {
         body
     }
 
+    // Value of wrapped type is casted to newtype
+    fn newtype_value(&self, field: &Field, value: &str, store: &Store) -> String {
+        if let Some(alias_id) = field.newtype {
+            format!("{} as {}", value, store.get_alias_path(alias_id).join("."))
+        } else {
+            value.to_string()
+        }
+    }
+
     fn get_field_map_def(&self, field: &Field, store: &mut Store, level: u8) -> String {
         let mut body: String = String::from("");
-        if let Some(entity_id) = field.ref_type_id {
+        let constraints = self.get_field_constraints(field);
+        if let Some(key) = field.key.as_ref() {
+            let (_, _, validator) = self.get_map_value_refs(field, store);
+            body = format!(
+                "{}\n{}{{ prop: '{}', types: Protocol.Primitives.Dictionary.getValidator(Protocol.Primitives.{}, {}), optional: {}{} }},",
+                body,
+                self.spaces(level),
+                field.name,
+                self.get_primitive_kind_ref(key),
+                validator,
+                if field.optional { "true" } else { "false" },
+                constraints
+            );
+        } else if field.optional_items {
+            let (_, _, validator) = self.get_value_refs(field, store);
+            body = format!(
+                "{}\n{}{{ prop: '{}', types: Protocol.Primitives.Nullable.getValidator({}), optional: {}{} }},",
+                body,
+                self.spaces(level),
+                field.name,
+                validator,
+                if field.optional { "true" } else { "false" },
+                constraints
+            );
+        } else if let Some(entity_id) = field.ref_type_id {
             if let Some(strct) = store.get_struct(entity_id) {
                 body = format!(
-                    "{}\n{}{{ prop: '{}', types: {}.getValidator({}), optional: {} }},",
+                    "{}\n{}{{ prop: '{}', types: {}.getValidator({}), optional: {}{} }},",
                     body,
                     self.spaces(level),
                     field.name,
                     store.get_struct_path(strct.id).join("."),
                     if field.repeated { "true" } else { "false" },
-                    if field.optional { "true" } else { "false" }
+                    if field.optional { "true" } else { "false" },
+                    constraints
                 );
             } else if let Some(enums) = store.get_enum(entity_id) {
                 body = format!(
@@ -1051,9 +1374,18 @@ impl TypescriptRender {
                     if field.optional { "true" } else { "false" }
                 );
                 for variant in &enums.variants {
-                    if let Some(struct_id) = variant.ref_type_id {
+                    if variant.optional || variant.optional_items {
+                        let (_, _, _, mut validator) = self.get_enum_item_refs(&enums, variant, store);
+                        if variant.optional_items {
+                            validator = format!("Protocol.Primitives.Nullable.getValidator({})", validator);
+                        }
+                        if variant.optional {
+                            validator = format!("Protocol.Primitives.Optional.getValidator({})", validator);
+                        }
+                        body = format!("{}\n{}{{ prop: '{}', types: {}, optional: false }},", body, self.spaces(level + 1), variant.name, validator);
+                    } else if let Some(struct_id) = variant.ref_type_id {
                         if let Some(strct) = store.get_struct(struct_id) {
-                            body = format!("{}\n{}{{ prop: '{}', types: {}.getValidator({}), optional: false }},", body, self.spaces(level + 1), variant.name, strct.name, if variant.repeated { "true" } else { "false" });
+                            body = format!("{}\n{}{{ prop: '{}', types: {}.getValidator({}), optional: false }},", body, self.spaces(level + 1), variant.name, self.get_struct_ref(&strct, store), if variant.repeated { "true" } else { "false" });
                         } else {
                             stop!("Nested enums aren't supported.");
                         }
@@ -1063,28 +1395,58 @@ impl TypescriptRender {
                         stop!("Incorrect option definition for enum {}", enums.name);
                     }
                 }
-                body = format!("{}\n{}] }},", body, self.spaces(level));
+                body = format!("{}\n{}]{} }},", body, self.spaces(level), constraints);
             }
+        } else if let Some(size) = field.fixed {
+            body = format!(
+                "{}\n{}{{ prop: '{}', types: Protocol.Primitives.Fixed.getValidator(Protocol.Primitives.{}, {}), optional: {}{} }},",
+                body,
+                self.spaces(level),
+                field.name,
+                self.get_primitive_ref(field),
+                size,
+                if field.optional { "true" } else { "false" },
+                constraints
+            );
         } else {
             body = format!(
-                "{}\n{}{{ prop: '{}', types: Protocol.Primitives.{}, optional: {}, }},",
+                "{}\n{}{{ prop: '{}', types: Protocol.Primitives.{}, optional: {}{}, }},",
                 body,
                 self.spaces(level),
                 field.name,
                 self.get_primitive_ref(field),
-                if field.optional { "true" } else { "false" }
+                if field.optional { "true" } else { "false" },
+                constraints
             );
         }
         body
     }
 
+    // Constraints of field as entry of validation scheme: ", constraints: { ... }"
+    fn get_field_constraints(&self, field: &Field) -> String {
+        if field.constraints.is_empty() {
+            return String::new();
+        }
+        let entries: Vec<String> = field.constraints.iter().map(|constraint| match constraint {
+            Constraint::MinLen(len) => format!("minLen: {}", len),
+            Constraint::MaxLen(len) => format!("maxLen: {}", len),
+            Constraint::Pattern(pattern) => format!("pattern: new RegExp({:?})", pattern),
+            Constraint::Range(min, max) => format!(
+                "range: [{}, {}]",
+                self.value(&EValue::Number(min.to_string()), &field.kind),
+                self.value(&EValue::Number(max.to_string()), &field.kind)
+            ),
+        }).collect();
+        format!(", constraints: {{ {} }}", entries.join(", "))
+    }
+
     fn get_field_decode_wrap(&self, field: &Field, store: &mut Store, level: u8) -> String {
         if field.optional {
             let mut body = format!(
                 "{}const {}Buf: ArrayBufferLike | undefined = storage.get({});",
                 self.spaces(level),
                 field.name,
-                field.id
+                field.wire_id
             );
             body = format!(
                 "{}\n{}if ({}Buf === undefined) {{",
@@ -1126,7 +1488,73 @@ impl TypescriptRender {
 
     fn get_field_decode(&self, field: &Field, store: &mut Store, level: u8) -> String {
         let mut body: String;
-        if let Some(entity_id) = field.ref_type_id {
+        if let Some(key) = field.key.as_ref() {
+            let type_str = self.get_map_type_ref(field, store);
+            let (_, decoder, _) = self.get_map_value_refs(field, store);
+            body = format!(
+                "{}const {}: {} | Error = this.getValue<{}>(storage, {}, (buf: ArrayBufferLike) => Protocol.Primitives.Dictionary.decode<{}>(buf, Protocol.Primitives.{}.decode, {}));",
+                self.spaces(level),
+                field.name,
+                type_str,
+                type_str,
+                field.wire_id,
+                self.get_map_generics(field, store),
+                self.get_primitive_kind_ref(key),
+                decoder
+            );
+            body = format!(
+                "{}\n{}if ({} instanceof Error) {{",
+                body,
+                self.spaces(level),
+                field.name
+            );
+            body = format!("{}\n{}return {};", body, self.spaces(level + 1), field.name);
+            body = format!("{}\n{}}} else {{", body, self.spaces(level));
+            body = format!(
+                "{}\n{}this.{} = {};",
+                body,
+                self.spaces(level + 1),
+                field.name,
+                field.name
+            );
+            body = format!("{}\n{}}}", body, self.spaces(level));
+        } else if field.optional_items {
+            let item_type = self.get_type_ref(field, &mut store.clone());
+            let type_str = format!("Array<{} | undefined>", item_type);
+            let (_, decoder, _) = self.get_value_refs(field, store);
+            body = format!(
+                "{}const {}: {} | Error = this.getValue<{}>(storage, {}, (buf: ArrayBufferLike) => Protocol.Primitives.Nullable.decode<{}>(buf, {}));",
+                self.spaces(level),
+                field.name,
+                type_str,
+                type_str,
+                field.wire_id,
+                item_type,
+                decoder
+            );
+            body = format!(
+                "{}\n{}if ({} instanceof Error) {{",
+                body,
+                self.spaces(level),
+                field.name
+            );
+            body = format!("{}\n{}return {};", body, self.spaces(level + 1), field.name);
+            body = format!("{}{}", body, self.get_numeric_check(field, store, level));
+            body = format!("{}\n{}}} else {{", body, self.spaces(level));
+            body = format!(
+                "{}\n{}this.{} = {}{};",
+                body,
+                self.spaces(level + 1),
+                field.name,
+                field.name,
+                if field.newtype.is_some() || field.numeric.is_some() {
+                    format!(" as {}", self.get_declare_type_ref(field, &mut store.clone()))
+                } else {
+                    String::new()
+                }
+            );
+            body = format!("{}\n{}}}", body, self.spaces(level));
+        } else if let Some(entity_id) = field.ref_type_id {
             if let Some(strct) = store.get_struct(entity_id) {
                 if field.repeated {
                     body = format!(
@@ -1136,7 +1564,7 @@ impl TypescriptRender {
                         store.get_struct_path(strct.id).join("."),
                         store.get_struct_path(strct.id).join(".")
                     );
-                    body = format!("{}\n{}const arr{}: Array<any> | Error = this.getValue<{}[]>(storage, {}, arr{}Inst.decodeSelfArray.bind(arr{}Inst));", body, self.spaces(level), field.name, strct.name, field.id, field.name, field.name);
+                    body = format!("{}\n{}const arr{}: Array<any> | Error = this.getValue<{}[]>(storage, {}, arr{}Inst.decodeSelfArray.bind(arr{}Inst));", body, self.spaces(level), field.name, self.get_struct_ref(&strct, store), field.wire_id, field.name, field.name);
                     body = format!(
                         "{}\n{}if (arr{} instanceof Error) {{",
                         body,
@@ -1172,7 +1600,7 @@ impl TypescriptRender {
                         body,
                         self.spaces(level),
                         field.name,
-                        field.id
+                        field.wire_id
                     );
                     body = format!(
                         "{}\n{}if ({}Buf instanceof Error) {{",
@@ -1224,7 +1652,7 @@ impl TypescriptRender {
                     body,
                     self.spaces(level),
                     field.name,
-                    field.id
+                    field.wire_id
                 );
                 body = format!(
                     "{}\n{}if ({}Buf === undefined) {{",
@@ -1284,10 +1712,17 @@ impl TypescriptRender {
         } else {
             let mut type_str = self.get_type_ref(field, &mut store.clone());
             let primitive = self.get_primitive_ref(field);
-            if field.repeated {
-                type_str = format!("Array<{}>", type_str);
+            if let Some(size) = field.fixed {
+                body = format!(
+                    "{}const {}: Array<{}> | Error = this.getValue<Array<{}>>(storage, {}, (buf: ArrayBufferLike) => Protocol.Primitives.Fixed.decode<{}>(buf, {}, Protocol.Primitives.{}.decode));",
+                    self.spaces(level), field.name, type_str, type_str, field.wire_id, type_str, size, primitive
+                );
+            } else {
+                if field.repeated {
+                    type_str = format!("Array<{}>", type_str);
+                }
+                body = format!("{}const {}: {} | Error = this.getValue<{}>(storage, {}, Protocol.Primitives.{}.decode);", self.spaces(level), field.name, type_str, type_str, field.wire_id, primitive);
             }
-            body = format!("{}const {}: {} | Error = this.getValue<{}>(storage, {}, Protocol.Primitives.{}.decode);", self.spaces(level), field.name, type_str, type_str, field.id, primitive);
             body = format!(
                 "{}\n{}if ({} instanceof Error) {{",
                 body,
@@ -1295,35 +1730,103 @@ impl TypescriptRender {
                 field.name
             );
             body = format!("{}\n{}return {};", body, self.spaces(level + 1), field.name);
+            body = format!("{}{}", body, self.get_numeric_check(field, store, level));
             body = format!("{}\n{}}} else {{", body, self.spaces(level));
             body = format!(
-                "{}\n{}this.{} = {};",
+                "{}\n{}this.{} = {}{};",
                 body,
                 self.spaces(level + 1),
                 field.name,
-                field.name
+                field.name,
+                if field.newtype.is_some() || field.numeric.is_some() {
+                    format!(" as {}", self.get_declare_type_ref(field, &mut store.clone()))
+                } else {
+                    String::new()
+                }
             );
             body = format!("{}\n{}}}", body, self.spaces(level));
         }
         body
     }
 
+    // Decoded discriminants of numeric enum should be declared items of it; TypeScript enum has
+    // reverse mapping, so undeclared value doesn't have a name
+    fn get_numeric_check(&self, field: &Field, store: &mut Store, level: u8) -> String {
+        let enums = if let Some(enums) = field.numeric.and_then(|id| store.get_enum(id)) {
+            enums
+        } else {
+            return String::new();
+        };
+        let path = store.get_enum_path(enums.id).join(".");
+        let mut body = format!(
+            "\n{}}} else if (([] as Array<number | undefined>).concat({}).some((value: number | undefined) => value !== undefined && {}[value] === undefined)) {{",
+            self.spaces(level),
+            field.name,
+            path
+        );
+        body = format!(
+            "{}\n{}return new Error(`Invalid value of enum {} in property \"{}\"`);",
+            body,
+            self.spaces(level + 1),
+            enums.name,
+            field.name
+        );
+        body
+    }
+
     fn get_field_encode(&self, field: &Field, store: &mut Store) -> String {
         let mut body: String;
-        if let Some(entity_id) = field.ref_type_id {
+        if let Some(key) = field.key.as_ref() {
+            let type_str = self.get_map_type_ref(field, store);
+            let (encoder, _, _) = self.get_map_value_refs(field, store);
+            body = format!(
+                "this.getBufferFromBuf<{}>({}, Protocol.ESize.u64, (map: {}) => Protocol.Primitives.Dictionary.encode<{}>(map, Protocol.Primitives.{}.encode, {}), this.{})",
+                type_str,
+                field.wire_id,
+                type_str,
+                self.get_map_generics(field, store),
+                self.get_primitive_kind_ref(key),
+                encoder,
+                field.name
+            );
+            if field.optional {
+                body = format!("() => this.{} === undefined ? this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()) : {}", field.name, field.wire_id, body);
+            } else {
+                body = format!("() => {}", body);
+            }
+        } else if field.optional_items {
+            let item_type = self.get_type_ref(field, &mut store.clone());
+            let type_str = format!("Array<{} | undefined>", item_type);
+            let (encoder, _, _) = self.get_value_refs(field, store);
+            body = format!(
+                "this.getBufferFromBuf<{}>({}, Protocol.ESize.u64, (arr: {}) => Protocol.Primitives.Nullable.encode<{}>(arr, {}), this.{})",
+                type_str,
+                field.wire_id,
+                type_str,
+                item_type,
+                encoder,
+                field.name
+            );
+            if field.optional {
+                body = format!("() => this.{} === undefined ? this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()) : {}", field.name, field.wire_id, body);
+            } else {
+                body = format!("() => {}", body);
+            }
+        } else if let Some(entity_id) = field.ref_type_id {
             let optional = if field.optional {
-                format!("if (this.{} === undefined) {{ return this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()); }}", field.name, field.id)
+                format!("if (this.{} === undefined) {{ return this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()); }}", field.name, field.wire_id)
             } else {
-                format!("")
+                String::new()
             };
             if let Some(strct) = store.get_struct(entity_id) {
                 if field.repeated {
-                    body = format!("() => {{{} const self: {} = {}.defaults(); return this.getBufferFromBuf<{}[]>({}, Protocol.ESize.u64, self.encodeSelfArray.bind(self), this.{}); }}", optional, strct.name, strct.name, strct.name, field.id, field.name);
+                    let strct = self.get_struct_ref(&strct, store);
+                    body = format!("() => {{{} const self: {} = {}.defaults(); return this.getBufferFromBuf<{}[]>({}, Protocol.ESize.u64, self.encodeSelfArray.bind(self), this.{}); }}", optional, strct, strct, strct, field.wire_id, field.name);
                 } else {
-                    body = format!("() => {{{} const buffer = this.{}.encode(); return this.getBuffer({}, Protocol.ESize.u64, BigInt(buffer.byteLength), buffer); }}", optional, field.name, field.id);
+                    body = format!("() => {{{} const buffer = this.{}.encode(); return this.getBuffer({}, Protocol.ESize.u64, BigInt(buffer.byteLength), buffer); }}", optional, field.name, field.wire_id);
                 }
             } else if store.get_enum(entity_id).is_some() {
-                body = format!("() => {{{} const buffer = this._{}.encode(); return this.getBuffer({}, Protocol.ESize.u64, BigInt(buffer.byteLength), buffer); }}", optional, field.name, field.id);
+                body = format!("() => {{{} const buffer = this._{}.encode(); return this.getBuffer({}, Protocol.ESize.u64, BigInt(buffer.byteLength), buffer); }}", optional, field.name, field.wire_id);
             } else {
                 stop!(
                     "Fail to find a type by ref {} for field {}",
@@ -1334,17 +1837,22 @@ impl TypescriptRender {
             let type_str = self.get_type_ref(field, &mut store.clone());
             let size_ref = self.get_size_ref(field);
             let primitive = self.get_primitive_ref(field);
-            if field.repeated {
-                body = format!("this.getBufferFromBuf<Array<{}>>({}, Protocol.ESize.u64, Protocol.Primitives.{}.encode, this.{})", type_str, field.id, primitive, field.name);
+            if let Some(size) = field.fixed {
+                body = format!(
+                    "this.getBufferFromBuf<Array<{}>>({}, {}, (arr: Array<{}>) => Protocol.Primitives.Fixed.encode<{}>(arr, {}, Protocol.Primitives.{}.encode), this.{})",
+                    type_str, field.wire_id, self.get_fixed_size_ref(field), type_str, type_str, size, primitive, field.name
+                );
+            } else if field.repeated {
+                body = format!("this.getBufferFromBuf<Array<{}>>({}, Protocol.ESize.u64, Protocol.Primitives.{}.encode, this.{})", type_str, field.wire_id, primitive, field.name);
             } else {
-                body = if field.kind == "str" {
-                    format!("this.getBufferFromBuf<string>({}, {}, Protocol.Primitives.{}.encode, this.{})", field.id, size_ref, primitive, field.name)
+                body = if field.kind == "str" || field.kind == "bytes" {
+                    format!("this.getBufferFromBuf<{}>({}, {}, Protocol.Primitives.{}.encode, this.{})", type_str, field.wire_id, size_ref, primitive, field.name)
                 } else {
-                    format!("this.getBuffer({}, {}, Protocol.Primitives.{}.getSize(), Protocol.Primitives.{}.encode(this.{}))", field.id, size_ref, primitive, primitive, field.name)
+                    format!("this.getBuffer({}, {}, Protocol.Primitives.{}.getSize(), Protocol.Primitives.{}.encode(this.{}))", field.wire_id, size_ref, primitive, primitive, field.name)
                 }
             }
             if field.optional {
-                body = format!("() => this.{} === undefined ? this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()) : {}", field.name, field.id, body);
+                body = format!("() => this.{} === undefined ? this.getBuffer({}, Protocol.ESize.u8, 0, new Uint8Array()) : {}", field.name, field.wire_id, body);
             } else {
                 body = format!("() => {}", body);
             }
@@ -1352,6 +1860,14 @@ impl TypescriptRender {
         body
     }
 
+    // Literal of value declared in protocol
+    fn value(&self, value: &EValue, kind: &str) -> String {
+        match value {
+            EValue::Number(value) if kind == "i64" || kind == "u64" || kind == "isize" || kind == "usize" => format!("BigInt(\"{}\")", value),
+            value => value.to_string(),
+        }
+    }
+
     fn type_default_value(&self, type_ref: &str) -> Option<&str> {
         match type_ref {
             "bool" => Some("true"),
@@ -1365,52 +1881,206 @@ impl TypescriptRender {
             "u64" => Some("BigInt(0)"),
             "f32" => Some("0"),
             "f64" => Some("0"),
+            "char" => Some("'\\0'"),
+            "isize" => Some("BigInt(0)"),
+            "usize" => Some("BigInt(0)"),
             "str" => Some("''"),
+            "bytes" => Some("new Uint8Array()"),
+            "timestamp" => Some("new Date(0)"),
+            "duration" => Some("0"),
+            "uuid" => Some("'00000000-0000-0000-0000-000000000000'"),
             _ => None,
         }
     }
 
     fn get_declare_type_ref(&self, field: &Field, store: &mut Store) -> String {
-        let mut type_str = self.get_type_ref(field, &mut store.clone());
+        let mut type_str = if field.key.is_some() {
+            self.get_map_type_ref(field, store)
+        } else if let Some(alias_id) = field.newtype {
+            store.get_alias_path(alias_id).join(".")
+        } else if let Some(enum_id) = field.numeric {
+            store.get_enum_path(enum_id).join(".")
+        } else {
+            self.get_type_ref(field, &mut store.clone())
+        };
+        if field.optional_items {
+            type_str = format!("{} | undefined", type_str);
+        }
+        if field.repeated && field.key.is_none() {
+            type_str = format!("Array<{}>", type_str);
+        }
         if field.optional {
             type_str = format!("{} | undefined", type_str);
         }
+        type_str
+    }
+
+    fn get_type_ref(&self, field: &Field, store: &mut Store) -> String {
+        if let Some(ref_type_id) = field.ref_type_id {
+            if store.get_struct(ref_type_id).is_some() {
+                store.get_struct_path(ref_type_id).join(".")
+            } else if let Some(enums) = store.get_enum(ref_type_id) {
+                self.get_enum_ref(&enums, store, true)
+            } else {
+                stop!(
+                    "Fail to find a struct/enum id: {} for field {}",
+                    ref_type_id, field.name
+                );
+            }
+        } else if let Some(type_ref) = self.get_primitive_type_ref(&field.kind) {
+            type_ref
+        } else {
+            stop!("Invalid type definition for field {}", field.name);
+        }
+    }
+
+    // Struct, declared inside of struct, is referred by full path, because class doesn't see
+    // members of namespace, which is merged with it
+    fn get_struct_ref(&self, strct: &Struct, store: &Store) -> String {
+        if store.is_scoped(strct.parent) {
+            store.get_struct_path(strct.id).join(".")
+        } else {
+            strct.name.clone()
+        }
+    }
+
+    // Same as get_struct_ref; interface of enum is prefixed with "I"
+    fn get_enum_ref(&self, enums: &Enum, store: &Store, interface: bool) -> String {
+        let prefix = if interface { "I" } else { "" };
+        if store.is_scoped(enums.parent) {
+            let mut path = store.get_enum_path(enums.id);
+            if let Some(name) = path.pop() {
+                path.push(format!("{}{}", prefix, name));
+            }
+            path.join(".")
+        } else {
+            format!("{}{}", prefix, enums.name)
+        }
+    }
+
+    fn get_primitive_type_ref(&self, kind: &str) -> Option<String> {
+        match kind {
+            "bool" => Some(String::from("boolean")),
+            "i8" => Some(String::from("number")),
+            "i16" => Some(String::from("number")),
+            "i32" => Some(String::from("number")),
+            "i64" => Some(String::from("bigint")),
+            "u8" => Some(String::from("number")),
+            "u16" => Some(String::from("number")),
+            "u32" => Some(String::from("number")),
+            "u64" => Some(String::from("bigint")),
+            "f32" => Some(String::from("number")),
+            "f64" => Some(String::from("number")),
+            "char" => Some(String::from("string")),
+            "isize" => Some(String::from("bigint")),
+            "usize" => Some(String::from("bigint")),
+            "str" => Some(String::from("string")),
+            "bytes" => Some(String::from("Uint8Array")),
+            "timestamp" => Some(String::from("Date")),
+            "duration" => Some(String::from("number")),
+            "uuid" => Some(String::from("string")),
+            _ => None,
+        }
+    }
+
+    fn get_map_type_ref(&self, field: &Field, store: &mut Store) -> String {
+        format!("Map<{}>", self.get_map_generics(field, store))
+    }
+
+    // Returns types of key and value of map: "K, V"
+    fn get_map_generics(&self, field: &Field, store: &mut Store) -> String {
+        let key = if let Some(key) = field.key.as_ref() {
+            key
+        } else {
+            stop!("Field {} isn't a map", field.name);
+        };
+        format!("{}, {}", self.get_key_type_ref(field, key), self.get_map_value_type_ref(field, store))
+    }
+
+    fn get_key_type_ref(&self, field: &Field, key: &str) -> String {
+        if let Some(type_ref) = self.get_primitive_type_ref(key) {
+            type_ref
+        } else {
+            stop!("Invalid type of key for field {}", field.name);
+        }
+    }
+
+    // Value of map can be array or map as well: map<K, map<K1, V[]>>
+    fn get_map_value_type_ref(&self, field: &Field, store: &mut Store) -> String {
+        let mut type_str = self.get_type_ref(field, &mut store.clone());
         if field.repeated {
             type_str = format!("Array<{}>", type_str);
         }
+        for key in field.value_keys.iter().rev() {
+            type_str = format!("Map<{}, {}>", self.get_key_type_ref(field, key), type_str);
+        }
         type_str
     }
 
-    fn get_type_ref(&self, field: &Field, store: &mut Store) -> String {
-        match field.kind.clone().as_str() {
-            "bool" => String::from("boolean"),
-            "i8" => String::from("number"),
-            "i16" => String::from("number"),
-            "i32" => String::from("number"),
-            "i64" => String::from("bigint"),
-            "u8" => String::from("number"),
-            "u16" => String::from("number"),
-            "u32" => String::from("number"),
-            "u64" => String::from("bigint"),
-            "f32" => String::from("number"),
-            "f64" => String::from("number"),
-            "str" => String::from("string"),
-            _ => {
-                if let Some(ref_type_id) = field.ref_type_id {
-                    if let Some(_) = store.get_struct(ref_type_id) {
-                        store.get_struct_path(ref_type_id).join(".")
-                    } else if let Some(enums) = store.get_enum(ref_type_id) {
-                        format!("I{}", enums.name)
-                    } else {
-                        stop!(
-                            "Fail to find a struct/enum id: {} for field {}",
-                            ref_type_id, field.name
-                        );
-                    }
-                } else {
-                    stop!("Invalid type definition for field {}", field.name);
-                }
+    // Returns encoder, decoder and validator of value of map
+    fn get_map_value_refs(&self, field: &Field, store: &mut Store) -> (String, String, String) {
+        let (mut encoder, mut decoder, mut validator) = if !field.repeated {
+            self.get_value_refs(field, store)
+        } else if let Some(strct) = field.ref_type_id.and_then(|id| store.get_struct(id)) {
+            let path = store.get_struct_path(strct.id).join(".");
+            (
+                format!("(value: Array<{}>) => {}.defaults().encodeSelfArray(value)", path, path),
+                format!("(buf: ArrayBufferLike) => {}.defaults().decodeSelfArray(buf) as Array<{}> | Error", path, path),
+                format!("{}.getValidator(true)", path),
+            )
+        } else {
+            let primitive = self.get_primitive_ref(field);
+            (
+                format!("Protocol.Primitives.{}.encode", primitive),
+                format!("Protocol.Primitives.{}.decode", primitive),
+                format!("Protocol.Primitives.{}", primitive),
+            )
+        };
+        let mut type_str = self.get_type_ref(field, &mut store.clone());
+        if field.repeated {
+            type_str = format!("Array<{}>", type_str);
+        }
+        for key in field.value_keys.iter().rev() {
+            let generics = format!("{}, {}", self.get_key_type_ref(field, key), type_str);
+            let primitive = self.get_primitive_kind_ref(key);
+            encoder = format!("(map: Map<{}>) => Protocol.Primitives.Dictionary.encode<{}>(map, Protocol.Primitives.{}.encode, {})", generics, generics, primitive, encoder);
+            decoder = format!("(buf: ArrayBufferLike) => Protocol.Primitives.Dictionary.decode<{}>(buf, Protocol.Primitives.{}.decode, {})", generics, primitive, decoder);
+            validator = format!("Protocol.Primitives.Dictionary.getValidator(Protocol.Primitives.{}, {})", primitive, validator);
+            type_str = format!("Map<{}>", generics);
+        }
+        (encoder, decoder, validator)
+    }
+
+    // Returns encoder, decoder and validator of single value: value of map or item of array with optional items
+    fn get_value_refs(&self, field: &Field, store: &mut Store) -> (String, String, String) {
+        if let Some(ref_type_id) = field.ref_type_id {
+            if store.get_struct(ref_type_id).is_some() {
+                let path = store.get_struct_path(ref_type_id).join(".");
+                (
+                    format!("(value: {}) => value.encode()", path),
+                    format!("{}.from", path),
+                    format!("{}.getValidator(false)", path),
+                )
+            } else if let Some(enums) = store.get_enum(ref_type_id) {
+                let (name, interface) = (self.get_enum_ref(&enums, store, false), self.get_enum_ref(&enums, store, true));
+                (
+                    format!("(value: {}) => {{ const inst = new {}(); const err = inst.set(value); return err instanceof Error ? err : inst.encode(); }}", interface, name),
+                    format!("{}.from", name),
+                    format!("{{ validate(value: any): Error | undefined {{ return (new {}()).set(value); }} }}", name),
+                )
+            } else {
+                stop!(
+                    "Fail to find a struct/enum id: {} for field {}",
+                    ref_type_id, field.name
+                );
             }
+        } else {
+            let primitive = self.get_primitive_kind_ref(&field.kind);
+            (
+                format!("Protocol.Primitives.{}.encode", primitive),
+                format!("Protocol.Primitives.{}.decode", primitive),
+                format!("Protocol.Primitives.{}", primitive),
+            )
         }
     }
 
@@ -1427,28 +2097,38 @@ impl TypescriptRender {
             "u64" => String::from("Protocol.ESize.u8"),
             "f32" => String::from("Protocol.ESize.u8"),
             "f64" => String::from("Protocol.ESize.u8"),
+            "char" => String::from("Protocol.ESize.u8"),
+            "isize" => String::from("Protocol.ESize.u8"),
+            "usize" => String::from("Protocol.ESize.u8"),
             "str" => String::from("Protocol.ESize.u64"),
+            "bytes" => String::from("Protocol.ESize.u64"),
+            "timestamp" => String::from("Protocol.ESize.u8"),
+            "duration" => String::from("Protocol.ESize.u8"),
+            "uuid" => String::from("Protocol.ESize.u8"),
             _ => String::from("Protocol.ESize.u64"),
         }
     }
 
+    // Fixed-size array has known length, so the smallest size marker is used
+    fn get_fixed_size_ref(&self, field: &Field) -> String {
+        let len = match (field.fixed, PrimitiveTypes::get_size(&field.kind)) {
+            (Some(size), Some(item)) => size * item,
+            _ => stop!("Field {} isn't fixed-size array of primitives", field.name),
+        };
+        if len <= u8::MAX as usize {
+            String::from("Protocol.ESize.u8")
+        } else if len <= u16::MAX as usize {
+            String::from("Protocol.ESize.u16")
+        } else if len <= u32::MAX as usize {
+            String::from("Protocol.ESize.u32")
+        } else {
+            String::from("Protocol.ESize.u64")
+        }
+    }
+
     fn get_primitive_ref(&self, field: &Field) -> String {
         if !field.repeated {
-            match field.kind.clone().as_str() {
-                "bool" => String::from("bool"),
-                "i8" => String::from("i8"),
-                "i16" => String::from("i16"),
-                "i32" => String::from("i32"),
-                "i64" => String::from("i64"),
-                "u8" => String::from("u8"),
-                "u16" => String::from("u16"),
-                "u32" => String::from("u32"),
-                "u64" => String::from("u64"),
-                "f32" => String::from("f32"),
-                "f64" => String::from("f64"),
-                "str" => String::from("StrUTF8"),
-                _ => stop!("{} type isn't recognized", field.kind),
-            }
+            self.get_primitive_kind_ref(&field.kind)
         } else {
             match field.kind.clone().as_str() {
                 "bool" => String::from("ArrayBool"),
@@ -1462,12 +2142,43 @@ impl TypescriptRender {
                 "u64" => String::from("ArrayU64"),
                 "f32" => String::from("ArrayF32"),
                 "f64" => String::from("ArrayF64"),
+                "char" => String::from("ArrayChar"),
+                "isize" => String::from("ArrayIsize"),
+                "usize" => String::from("ArrayUsize"),
                 "str" => String::from("ArrayStrUTF8"),
+                "timestamp" => String::from("ArrayTimestamp"),
+                "duration" => String::from("ArrayDuration"),
+                "uuid" => String::from("ArrayUuid"),
                 _ => stop!("{} type isn't recognized", field.kind),
             }
         }
     }
 
+    fn get_primitive_kind_ref(&self, kind: &str) -> String {
+        match kind {
+            "bool" => String::from("bool"),
+            "i8" => String::from("i8"),
+            "i16" => String::from("i16"),
+            "i32" => String::from("i32"),
+            "i64" => String::from("i64"),
+            "u8" => String::from("u8"),
+            "u16" => String::from("u16"),
+            "u32" => String::from("u32"),
+            "u64" => String::from("u64"),
+            "f32" => String::from("f32"),
+            "f64" => String::from("f64"),
+            "char" => String::from("char"),
+            "isize" => String::from("isize"),
+            "usize" => String::from("usize"),
+            "str" => String::from("StrUTF8"),
+            "bytes" => String::from("Bytes"),
+            "timestamp" => String::from("timestamp"),
+            "duration" => String::from("duration"),
+            "uuid" => String::from("uuid"),
+            _ => stop!("{} type isn't recognized", kind),
+        }
+    }
+
     fn get_messages_list(&self, group: Option<&Group>, store: &mut Store, level: u8) -> String {
         let mut body = String::from("");
         if let Some(group) = group {
@@ -1477,7 +2188,7 @@ impl TypescriptRender {
                 self.spaces(level)
             );
             for enum_id in &group.enums {
-                if let Some(enums) = store.get_enum(*enum_id) {
+                if let Some(enums) = store.get_enum(*enum_id).filter(|e| !e.numeric) {
                     body = format!(
                         "{}{}{}?: I{},\n",
                         body,
@@ -1500,7 +2211,7 @@ impl TypescriptRender {
             }
             let childs = store.get_child_groups(group.id);
             for child in childs {
-                if child.parent == group.id {
+                if child.parent == group.id && child.owner.is_none() {
                     body = format!(
                         "{}{}{}?: {}.IAvailableMessages,\n",
                         body,
@@ -1518,7 +2229,7 @@ impl TypescriptRender {
                 self.spaces(level)
             );
             for enums in &store.enums {
-                if enums.parent == 0 {
+                if enums.parent == 0 && !enums.numeric {
                     body = format!(
                         "{}{}{}?: I{},\n",
                         body,
@@ -1540,7 +2251,7 @@ impl TypescriptRender {
                 }
             }
             for group in &store.groups {
-                if group.parent == 0 {
+                if group.parent == 0 && group.owner.is_none() {
                     body = format!(
                         "{}{}{}?: {}.IAvailableMessages,\n",
                         body,
@@ -1605,7 +2316,10 @@ impl TypescriptRender {
         body = format!("{}{}let err: Error | undefined;\n", body, self.spaces(2));
         body = format!("{}{}switch (header.id) {{\n", body, self.spaces(2));
         for enums in &store.enums {
-            body = format!("{}{}case {}:\n", body, self.spaces(3), enums.id);
+            if store.is_scoped(enums.parent) || enums.numeric {
+                continue;
+            }
+            body = format!("{}{}case {}:\n", body, self.spaces(3), enums.wire_id);
             body = format!(
                 "{}{}instance = new {}();\n",
                 body,
@@ -1626,7 +2340,10 @@ impl TypescriptRender {
             body = format!("{}{}return {{ header: {{ id: header.id, sequence: header.sequence, timestamp: header.ts }}, msg: {{ {}}}, getRef: () => instance }};\n", body, self.spaces(4), self.get_available_entity(enums.parent, &enums.name, &mut store.clone()));
         }
         for structs in &store.structs {
-            body = format!("{}{}case {}:\n", body, self.spaces(3), structs.id);
+            if store.is_scoped(structs.parent) {
+                continue;
+            }
+            body = format!("{}{}case {}:\n", body, self.spaces(3), structs.wire_id);
             body = format!(
                 "{}{}instance = {}.defaults();\n",
                 body,
@@ -1644,7 +2361,7 @@ impl TypescriptRender {
 
     fn includes(&self) -> String {
         if self.embedded {
-            format!("{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}\n",
+            format!("{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}\n",
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.injection.embedded.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/tools/index.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/tools/tools.arraybuffer.ts")),
@@ -1661,6 +2378,12 @@ impl TypescriptRender {
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.f32.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.f64.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.bool.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.char.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.isize.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.usize.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.timestamp.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.duration.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.uuid.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.string.utf8.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.u8.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.u16.ts")),
@@ -1674,7 +2397,17 @@ impl TypescriptRender {
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.f64.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.bool.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.string.utf8.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.char.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.isize.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.usize.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.timestamp.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.duration.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.array.uuid.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.bytes.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.enum.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.dictionary.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.fixed.ts")),
+                self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.nullable.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.validator.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.primitives.ts")),
                 self.get_injectable(include_str!("../../../protocol/implementations/typescript/src/protocol.convertor.storage.ts")),
@@ -1695,6 +2428,33 @@ impl TypescriptRender {
         re_injectable.replace_all(content, "").to_string()
     }
 
+    // Renders JSDoc. "*/" inside of doc would close comment, so it's escaped
+    fn doc(&self, doc: &[String], level: u8) -> String {
+        if doc.is_empty() {
+            return String::new();
+        }
+        let mut body = format!("{}/**\n", self.spaces(level));
+        for line in doc {
+            if line.is_empty() {
+                body = format!("{}{} *\n", body, self.spaces(level));
+            } else {
+                body = format!("{}{} * {}\n", body, self.spaces(level), line.replace("*/", "*\\/"));
+            }
+        }
+        format!("{}{} */\n", body, self.spaces(level))
+    }
+
+    // Deprecation is rendered as JSDoc tag, so it's shown by IDE
+    fn deprecated_doc(&self, doc: &[String], deprecated: &Option<String>) -> Vec<String> {
+        let mut doc = doc.to_vec();
+        match deprecated {
+            Some(reason) if reason.is_empty() => doc.push(String::from("@deprecated")),
+            Some(reason) => doc.push(format!("@deprecated {}", reason)),
+            None => {}
+        }
+        doc
+    }
+
     fn spaces(&self, level: u8) -> String {
         "    ".repeat(level as usize)
     }
@@ -1715,6 +2475,16 @@ impl Render for TypescriptRender {
             body,
             self.get_messages_list(None, &mut store.clone(), 0)
         );
+        for consts in &store.consts {
+            if consts.parent == 0 {
+                body = format!("{}{}\n", body, self.consts(consts, 0));
+            }
+        }
+        for alias in &store.aliases {
+            if alias.parent == 0 {
+                body = format!("{}{}\n", body, self.aliases(alias, 0));
+            }
+        }
         for enums in &store.enums {
             if enums.parent == 0 {
                 body =
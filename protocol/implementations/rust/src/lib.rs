@@ -19,3 +19,14 @@ pub mod buffer;
 #[path = "./protocol.test.rs"]
 pub mod protocol_test;
 
+#[path = "./protocol.test.constraints.rs"]
+pub mod protocol_test_constraints;
+
+#[path = "./protocol.test.enums.rs"]
+pub mod protocol_test_enums;
+
+#[path = "./protocol.test.maps.rs"]
+pub mod protocol_test_maps;
+
+#[path = "./protocol.test.deprecated.rs"]
+pub mod protocol_test_deprecated;
@@ -1,7 +1,10 @@
 use super::{ sizes, storage };
 use storage::{ Storage };
 use std::io::Cursor;
+use std::convert::TryFrom;
 use bytes::{ Buf };
+use std::collections::HashMap;
+use std::time::{ SystemTime, UNIX_EPOCH };
 
 // injectable
 pub enum Source<'a> {
@@ -39,7 +42,7 @@ pub trait EnumDecode {
 pub trait DecodeEnum<T> {
 
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<T, String>;
-    fn get_buf_from_source(source: Source, id: Option<u16>) -> Result<&Vec<u8>, String> {
+    fn get_buf_from_source(source: Source<'_>, id: Option<u16>) -> Result<&Vec<u8>, String> {
         match source {
             Source::Storage(storage) => {
                 if let Some(id) = id {
@@ -106,7 +109,7 @@ impl<T> DecodeEnum<Vec<T>> for Vec<T> where T: EnumDecode {
 pub trait Decode<T> {
 
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<T, String>;
-    fn get_buf_from_source(source: Source, id: Option<u16>) -> Result<&Vec<u8>, String> {
+    fn get_buf_from_source(source: Source<'_>, id: Option<u16>) -> Result<&Vec<u8>, String> {
         match source {
             Source::Storage(storage) => {
                 if let Some(id) = id {
@@ -282,6 +285,96 @@ impl Decode<bool> for bool {
     }
 }
 
+impl Decode<char> for char {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<char, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::CHAR_LEN {
+                return Err(format!("To extract char value buffer should have length at least {} bytes, but length is {}", sizes::CHAR_LEN, buf.len()));
+            }
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let code = cursor.get_u32_le();
+            char::from_u32(code).ok_or(format!("Value {} isn't valid code point of char", code))
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<isize> for isize {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<isize, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::ISIZE_LEN {
+                return Err(format!("To extract isize value buffer should have length at least {} bytes, but length is {}", sizes::ISIZE_LEN, buf.len()));
+            }
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let value = cursor.get_i64_le();
+            isize::try_from(value).map_err(|_| format!("Value {} is out of range of isize on this platform", value))
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<usize> for usize {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<usize, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::USIZE_LEN {
+                return Err(format!("To extract usize value buffer should have length at least {} bytes, but length is {}", sizes::USIZE_LEN, buf.len()));
+            }
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let value = cursor.get_u64_le();
+            usize::try_from(value).map_err(|_| format!("Value {} is out of range of usize on this platform", value))
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+fn get_timestamp(ms: u64) -> Result<SystemTime, String> {
+    UNIX_EPOCH.checked_add(std::time::Duration::from_millis(ms)).ok_or(format!("Value {} is out of range of timestamp", ms))
+}
+
+impl Decode<SystemTime> for SystemTime {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<SystemTime, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::TIMESTAMP_LEN {
+                return Err(format!("To extract timestamp value buffer should have length at least {} bytes, but length is {}", sizes::TIMESTAMP_LEN, buf.len()));
+            }
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            get_timestamp(cursor.get_u64_le())
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<std::time::Duration> for std::time::Duration {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<std::time::Duration, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::DURATION_LEN {
+                return Err(format!("To extract duration value buffer should have length at least {} bytes, but length is {}", sizes::DURATION_LEN, buf.len()));
+            }
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            Ok(std::time::Duration::from_millis(cursor.get_u64_le()))
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<uuid::Uuid> for uuid::Uuid {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<uuid::Uuid, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.len() < sizes::UUID_LEN {
+                return Err(format!("To extract uuid value buffer should have length at least {} bytes, but length is {}", sizes::UUID_LEN, buf.len()));
+            }
+            uuid::Uuid::from_slice(&buf[0..sizes::UUID_LEN]).map_err(|e| e.to_string())
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
 impl Decode<String> for String {
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<String, String> {
         if let Ok(buf) = Self::get_buf_from_source(source, id) {
@@ -312,18 +405,11 @@ impl<T> Decode<T> for T where T: StructDecode,  {
     }
 }
 
+// Used for u8[] and bytes; buffer is copied as it is
 impl Decode<Vec<u8>> for Vec<u8> {
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<u8>, String> {
         if let Ok(buf) = Self::get_buf_from_source(source, id) {
-            let mut res: Vec<u8> = vec!();
-            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
-            loop {
-                if cursor.position() == buf.len() as u64 {
-                    break;
-                }
-                res.push(cursor.get_u8());
-            }
-            Ok(res)
+            Ok(buf.to_vec())
         } else {
             Err("Fail get buffer".to_string())
         }
@@ -542,6 +628,145 @@ impl Decode<Vec<bool>> for Vec<bool> {
     }
 }
 
+impl Decode<Vec<char>> for Vec<char> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<char>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<char> = vec!();
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let len = buf.len() as u64;
+            loop {
+                if cursor.position() == len {
+                    break;
+                }
+                if len - cursor.position() < sizes::CHAR_LEN as u64 {
+                    return Err(format!("To extract char value from array buffer should have length at least {} bytes, but length is {}", sizes::CHAR_LEN, buf.len()));
+                }
+                let code = cursor.get_u32_le();
+                match char::from_u32(code) {
+                    Some(c) => res.push(c),
+                    None => { return Err(format!("Value {} isn't valid code point of char", code)); },
+                }
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<Vec<isize>> for Vec<isize> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<isize>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<isize> = vec!();
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let len = buf.len() as u64;
+            loop {
+                if cursor.position() == len {
+                    break;
+                }
+                if len - cursor.position() < sizes::ISIZE_LEN as u64 {
+                    return Err(format!("To extract isize value from array buffer should have length at least {} bytes, but length is {}", sizes::ISIZE_LEN, buf.len()));
+                }
+                let value = cursor.get_i64_le();
+                match isize::try_from(value) {
+                    Ok(v) => res.push(v),
+                    Err(_) => { return Err(format!("Value {} is out of range of isize on this platform", value)); },
+                }
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<Vec<usize>> for Vec<usize> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<usize>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<usize> = vec!();
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let len = buf.len() as u64;
+            loop {
+                if cursor.position() == len {
+                    break;
+                }
+                if len - cursor.position() < sizes::USIZE_LEN as u64 {
+                    return Err(format!("To extract usize value from array buffer should have length at least {} bytes, but length is {}", sizes::USIZE_LEN, buf.len()));
+                }
+                let value = cursor.get_u64_le();
+                match usize::try_from(value) {
+                    Ok(v) => res.push(v),
+                    Err(_) => { return Err(format!("Value {} is out of range of usize on this platform", value)); },
+                }
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<Vec<SystemTime>> for Vec<SystemTime> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<SystemTime>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<SystemTime> = vec!();
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let len = buf.len() as u64;
+            loop {
+                if cursor.position() == len {
+                    break;
+                }
+                if len - cursor.position() < sizes::TIMESTAMP_LEN as u64 {
+                    return Err(format!("To extract timestamp value from array buffer should have length at least {} bytes, but length is {}", sizes::TIMESTAMP_LEN, buf.len()));
+                }
+                res.push(get_timestamp(cursor.get_u64_le())?);
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<Vec<std::time::Duration>> for Vec<std::time::Duration> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<std::time::Duration>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<std::time::Duration> = vec!();
+            let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+            let len = buf.len() as u64;
+            loop {
+                if cursor.position() == len {
+                    break;
+                }
+                if len - cursor.position() < sizes::DURATION_LEN as u64 {
+                    return Err(format!("To extract duration value from array buffer should have length at least {} bytes, but length is {}", sizes::DURATION_LEN, buf.len()));
+                }
+                res.push(std::time::Duration::from_millis(cursor.get_u64_le()));
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl Decode<Vec<uuid::Uuid>> for Vec<uuid::Uuid> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<uuid::Uuid>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<uuid::Uuid> = vec!();
+            if buf.len() % sizes::UUID_LEN != 0 {
+                return Err(format!("To extract uuid values from array buffer length should be multiple of {} bytes, but length is {}", sizes::UUID_LEN, buf.len()));
+            }
+            for chunk in buf.chunks(sizes::UUID_LEN) {
+                res.push(uuid::Uuid::from_slice(chunk).map_err(|e| e.to_string())?);
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
 impl Decode<Vec<String>> for Vec<String> {
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<String>, String> {
         if let Ok(buf) = Self::get_buf_from_source(source, id) {
@@ -613,6 +838,71 @@ impl<T> Decode<Vec<T>> for Vec<T> where T: StructDecode {
     }
 }
 
+// Splits buffer of map into items: [u64 length of key][key][u64 length of value][value]...
+// Items go by pairs: key, value.
+// usize::is_multiple_of isn't used, because it requires rust 1.87, but runtime is embedded into consumers
+#[allow(clippy::manual_is_multiple_of)]
+fn get_map_items(buf: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let mut items: Vec<Vec<u8>> = vec!();
+    let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+    let len = buf.len() as u64;
+    while cursor.position() < len {
+        if len - cursor.position() < sizes::U64_LEN as u64 {
+            return Err(format!("To extract length of map's item (u64) buffer should have length at least {} bytes, but rest of buffer is {}", sizes::U64_LEN, len - cursor.position()));
+        }
+        let item_len: u64 = cursor.get_u64_le();
+        if len - cursor.position() < item_len {
+            return Err(format!("Cannot extract map's item, because expecting {} bytes, but rest of buffer is {}", item_len, len - cursor.position()));
+        }
+        let from = cursor.position() as usize;
+        items.push(buf[from..(from + item_len as usize)].to_vec());
+        cursor.set_position(cursor.position() + item_len);
+    }
+    if items.len() % 2 != 0 {
+        return Err("Cannot extract map, because key doesn't have value".to_string());
+    }
+    Ok(items)
+}
+
+impl<K, V> DecodeEnum<HashMap<K, V>> for HashMap<K, V> where K: Decode<K> + Eq + std::hash::Hash, V: EnumDecode {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<HashMap<K, V>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: HashMap<K, V> = HashMap::new();
+            for pair in get_map_items(buf)?.chunks(2) {
+                res.insert(K::decode(&pair[0])?, V::extract(pair[1].clone())?);
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl<K, V> Decode<HashMap<K, V>> for HashMap<K, V> where K: Decode<K> + Eq + std::hash::Hash, V: Decode<V> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<HashMap<K, V>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: HashMap<K, V> = HashMap::new();
+            for pair in get_map_items(buf)?.chunks(2) {
+                res.insert(K::decode(&pair[0])?, V::decode(&pair[1])?);
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl<T, const N: usize> Decode<[T; N]> for [T; N] where Vec<T>: Decode<Vec<T>> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<[T; N], String> {
+        let items: Vec<T> = Vec::<T>::get_from_storage(source, id)?;
+        let len = items.len();
+        match <[T; N]>::try_from(items) {
+            Ok(items) => Ok(items),
+            Err(_) => Err(format!("Expecting fixed-size array with {} items, but found {} items", N, len)),
+        }
+    }
+}
+
 impl<T> Decode<Option<T>> for Option<T> where T: Decode<T> {
     fn get_from_storage(source: Source, id: Option<u16>) -> Result<Option<T>, String> {
         if let Ok(buf) = Self::get_buf_from_source(source, id) {
@@ -630,3 +920,75 @@ impl<T> Decode<Option<T>> for Option<T> where T: Decode<T> {
         
     }
 }
+
+impl<T> DecodeEnum<Option<T>> for Option<T> where T: DecodeEnum<T> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Option<T>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            if buf.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(T::get_from_storage(Source::Buffer(buf), id)?))
+            }
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+// Splits buffer of array with optional items: [u8 0] for None or [u8 1][u64 length of item][item]
+fn get_optional_items(buf: &[u8]) -> Result<Vec<Option<Vec<u8>>>, String> {
+    let mut items: Vec<Option<Vec<u8>>> = vec!();
+    let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
+    let len = buf.len() as u64;
+    while cursor.position() < len {
+        if cursor.get_u8() == 0 {
+            items.push(None);
+            continue;
+        }
+        if len - cursor.position() < sizes::U64_LEN as u64 {
+            return Err(format!("To extract length of array's item (u64) buffer should have length at least {} bytes, but rest of buffer is {}", sizes::U64_LEN, len - cursor.position()));
+        }
+        let item_len: u64 = cursor.get_u64_le();
+        if len - cursor.position() < item_len {
+            return Err(format!("Cannot extract array's item, because expecting {} bytes, but rest of buffer is {}", item_len, len - cursor.position()));
+        }
+        let from = cursor.position() as usize;
+        items.push(Some(buf[from..(from + item_len as usize)].to_vec()));
+        cursor.set_position(cursor.position() + item_len);
+    }
+    Ok(items)
+}
+
+impl<T> Decode<Vec<Option<T>>> for Vec<Option<T>> where T: Decode<T> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<Option<T>>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<Option<T>> = vec!();
+            for item in get_optional_items(buf)? {
+                res.push(match item {
+                    Some(item) => Some(T::decode(&item)?),
+                    None => None,
+                });
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
+
+impl<T> DecodeEnum<Vec<Option<T>>> for Vec<Option<T>> where T: EnumDecode {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<Option<T>>, String> {
+        if let Ok(buf) = Self::get_buf_from_source(source, id) {
+            let mut res: Vec<Option<T>> = vec!();
+            for item in get_optional_items(buf)? {
+                res.push(match item {
+                    Some(item) => Some(T::extract(item)?),
+                    None => None,
+                });
+            }
+            Ok(res)
+        } else {
+            Err("Fail get buffer".to_string())
+        }
+    }
+}
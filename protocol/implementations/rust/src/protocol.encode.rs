@@ -1,5 +1,7 @@
 use super::{ sizes };
 use sizes::{ ESize };
+use std::collections::HashMap;
+use std::time::{ SystemTime, UNIX_EPOCH };
 
 // injectable
 fn get_value_buffer(id: Option<u16>, size: ESize, mut value: Vec<u8>) -> Result<Vec<u8>, String> {
@@ -8,19 +10,19 @@ fn get_value_buffer(id: Option<u16>, size: ESize, mut value: Vec<u8>) -> Result<
         buffer.append(&mut id.to_le_bytes().to_vec());
         match size {
             ESize::U8(size) => {
-                buffer.append(&mut (8 as u8).to_le_bytes().to_vec());
+                buffer.append(&mut 8_u8.to_le_bytes().to_vec());
                 buffer.append(&mut size.to_le_bytes().to_vec());
             },
             ESize::U16(size) => {
-                buffer.append(&mut (16 as u8).to_le_bytes().to_vec());
+                buffer.append(&mut 16_u8.to_le_bytes().to_vec());
                 buffer.append(&mut size.to_le_bytes().to_vec());
             },
             ESize::U32(size) => {
-                buffer.append(&mut (32 as u8).to_le_bytes().to_vec());
+                buffer.append(&mut 32_u8.to_le_bytes().to_vec());
                 buffer.append(&mut size.to_le_bytes().to_vec());
             },
             ESize::U64(size) => {
-                buffer.append(&mut (64 as u8).to_le_bytes().to_vec());
+                buffer.append(&mut 64_u8.to_le_bytes().to_vec());
                 buffer.append(&mut size.to_le_bytes().to_vec());
             },
         };
@@ -155,6 +157,50 @@ impl Encode for bool {
     }
 }
 
+impl Encode for char {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::CHAR_LEN as u8), (*self as u32).to_le_bytes().to_vec())
+    }
+}
+
+impl Encode for isize {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::ISIZE_LEN as u8), (*self as i64).to_le_bytes().to_vec())
+    }
+}
+
+impl Encode for usize {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::USIZE_LEN as u8), (*self as u64).to_le_bytes().to_vec())
+    }
+}
+
+// Timestamp is sent as count of milliseconds since UNIX epoch
+fn get_timestamp_ms(value: &SystemTime) -> Result<u64, String> {
+    match value.duration_since(UNIX_EPOCH) {
+        Ok(duration) => Ok(duration.as_millis() as u64),
+        Err(_) => Err(String::from("Timestamp before UNIX epoch cannot be encoded")),
+    }
+}
+
+impl Encode for SystemTime {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::TIMESTAMP_LEN as u8), get_timestamp_ms(self)?.to_le_bytes().to_vec())
+    }
+}
+
+impl Encode for std::time::Duration {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::DURATION_LEN as u8), (self.as_millis() as u64).to_le_bytes().to_vec())
+    }
+}
+
+impl Encode for uuid::Uuid {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        get_value_buffer(id, ESize::U8(sizes::UUID_LEN as u8), self.as_bytes().to_vec())
+    }
+}
+
 impl Encode for String {
     fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
         let buf = self.as_bytes();
@@ -171,14 +217,10 @@ impl<T> Encode for T where T: StructEncode {
     }
 }
 
+// Used for u8[] and bytes; buffer is copied as it is
 impl Encode for Vec<u8> {
     fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
-        let len = self.len() * sizes::U8_LEN;
-        let mut buffer: Vec<u8> = vec!();
-        for val in self.iter() {
-            buffer.append(&mut val.to_le_bytes().to_vec());
-        }
-        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+        get_value_buffer(id, ESize::U64(self.len() as u64), self.clone())
     }
 }
 
@@ -281,6 +323,72 @@ impl Encode for Vec<f64> {
     }
 }
 
+impl Encode for Vec<char> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::CHAR_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut (*val as u32).to_le_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
+impl Encode for Vec<isize> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::ISIZE_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut (*val as i64).to_le_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
+impl Encode for Vec<usize> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::USIZE_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut (*val as u64).to_le_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
+impl Encode for Vec<SystemTime> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::TIMESTAMP_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut get_timestamp_ms(val)?.to_le_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
+impl Encode for Vec<std::time::Duration> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::DURATION_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut (val.as_millis() as u64).to_le_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
+impl Encode for Vec<uuid::Uuid> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let len = self.len() * sizes::UUID_LEN;
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter() {
+            buffer.append(&mut val.as_bytes().to_vec());
+        }
+        get_value_buffer(id, ESize::U64(len as u64), buffer.to_vec())
+    }
+}
+
 impl Encode for Vec<String> {
     fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
         let mut buffer: Vec<u8> = vec!();
@@ -298,7 +406,7 @@ impl Encode for Vec<bool> {
         let len = self.len() * sizes::U8_LEN;
         let mut buffer: Vec<u8> = vec!();
         for val in self.iter() {
-            let byte: u8 = if val.clone() {
+            let byte: u8 = if *val {
                 1
             } else {
                 0
@@ -309,6 +417,40 @@ impl Encode for Vec<bool> {
     }
 }
 
+// Each pair is written as: [u64 length of key][key][u64 length of value][value]
+fn get_pair_buffer(mut key: Vec<u8>, mut value: Vec<u8>) -> Vec<u8> {
+    let mut buffer: Vec<u8> = vec!();
+    buffer.append(&mut (key.len() as u64).to_le_bytes().to_vec());
+    buffer.append(&mut key);
+    buffer.append(&mut (value.len() as u64).to_le_bytes().to_vec());
+    buffer.append(&mut value);
+    buffer
+}
+
+impl<K, V> EncodeEnum for HashMap<K, V> where K: Encode + Clone, V: EnumEncode {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        for (key, val) in self.iter_mut() {
+            let key_as_bytes = key.clone().encode()?;
+            let val_as_bytes = val.abduct()?;
+            buffer.append(&mut get_pair_buffer(key_as_bytes, val_as_bytes));
+        }
+        get_value_buffer(id, ESize::U64(buffer.len() as u64), buffer.to_vec())
+    }
+}
+
+impl<K, V> Encode for HashMap<K, V> where K: Encode + Clone, V: Encode {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        for (key, val) in self.iter_mut() {
+            let key_as_bytes = key.clone().encode()?;
+            let val_as_bytes = val.encode()?;
+            buffer.append(&mut get_pair_buffer(key_as_bytes, val_as_bytes));
+        }
+        get_value_buffer(id, ESize::U64(buffer.len() as u64), buffer.to_vec())
+    }
+}
+
 impl<T> Encode for Vec<T> where T: StructEncode {
     fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
         let mut buffer: Vec<u8> = vec!();
@@ -324,6 +466,23 @@ impl<T> Encode for Vec<T> where T: StructEncode {
     }
 }
 
+// Fixed-size array is written as sequence of items without count of items, because count is defined by type
+impl<T, const N: usize> Encode for [T; N] where T: Clone, Vec<T>: Encode {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let buffer = self.to_vec().get_buf_to_store(None)?;
+        let size = if buffer.len() <= u8::MAX as usize {
+            ESize::U8(buffer.len() as u8)
+        } else if buffer.len() <= u16::MAX as usize {
+            ESize::U16(buffer.len() as u16)
+        } else if buffer.len() <= u32::MAX as usize {
+            ESize::U32(buffer.len() as u32)
+        } else {
+            ESize::U64(buffer.len() as u64)
+        };
+        get_value_buffer(id, size, buffer)
+    }
+}
+
 impl<T> Encode for Option<T> where T: Encode {
     fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
         match self {
@@ -332,3 +491,53 @@ impl<T> Encode for Option<T> where T: Encode {
         }
     }
 }
+
+impl<T> EncodeEnum for Option<T> where T: EncodeEnum {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        match self {
+            Some(v) => v.get_buf_to_store(id),
+            None => get_empty_buffer_val(id),
+        }
+    }
+}
+
+// Each item of array with optional items is written as: [u8 0] for None or [u8 1][u64 length of item][item]
+fn get_optional_item_buffer(item: Option<Vec<u8>>) -> Vec<u8> {
+    let mut buffer: Vec<u8> = vec!();
+    if let Some(mut item) = item {
+        buffer.append(&mut 1_u8.to_le_bytes().to_vec());
+        buffer.append(&mut (item.len() as u64).to_le_bytes().to_vec());
+        buffer.append(&mut item);
+    } else {
+        buffer.append(&mut 0_u8.to_le_bytes().to_vec());
+    }
+    buffer
+}
+
+impl<T> Encode for Vec<Option<T>> where T: Encode {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter_mut() {
+            let item = match val {
+                Some(v) => Some(v.encode()?),
+                None => None,
+            };
+            buffer.append(&mut get_optional_item_buffer(item));
+        }
+        get_value_buffer(id, ESize::U64(buffer.len() as u64), buffer.to_vec())
+    }
+}
+
+impl<T> EncodeEnum for Vec<Option<T>> where T: EnumEncode {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        for val in self.iter_mut() {
+            let item = match val {
+                Some(v) => Some(v.abduct()?),
+                None => None,
+            };
+            buffer.append(&mut get_optional_item_buffer(item));
+        }
+        get_value_buffer(id, ESize::U64(buffer.len() as u64), buffer.to_vec())
+    }
+}
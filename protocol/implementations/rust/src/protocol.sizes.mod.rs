@@ -13,6 +13,15 @@ pub mod sizes {
     pub const F32_LEN: usize = mem::size_of::<f32>();
     pub const F64_LEN: usize = mem::size_of::<f64>();
     pub const BOOL_LEN: usize = mem::size_of::<bool>();
+    // char is sent as u32 code point; isize and usize are always sent as 64 bits,
+    // so both sides agree on size regardless of platform
+    pub const CHAR_LEN: usize = mem::size_of::<u32>();
+    pub const ISIZE_LEN: usize = mem::size_of::<i64>();
+    pub const USIZE_LEN: usize = mem::size_of::<u64>();
+    // timestamp and duration are sent as u64 count of milliseconds, uuid as 16 raw bytes
+    pub const TIMESTAMP_LEN: usize = mem::size_of::<u64>();
+    pub const DURATION_LEN: usize = mem::size_of::<u64>();
+    pub const UUID_LEN: usize = 16;
 
 }
 
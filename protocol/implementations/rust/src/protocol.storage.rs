@@ -24,7 +24,7 @@ impl Storage {
         */
         let mut position: usize = 0;
         let mut map: HashMap<u16, Vec<u8>> = HashMap::new();
-        if buf.len() > 0 {
+        if !buf.is_empty() {
             loop {
                 match Storage::next(&buf, position) {
                     Ok((id, body, pos)) => {
@@ -46,7 +46,7 @@ impl Storage {
     }
 
     fn id(buf: &[u8], pos: usize) -> Result<(u16, usize), String> {
-        let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
+        let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
         if let Ok(pos) = u64::try_from(pos) {
             cursor.set_position(pos);
         } else {
@@ -57,7 +57,7 @@ impl Storage {
     }
 
     fn body(buf: &[u8], pos: usize) -> Result<(Vec<u8>, usize), String> {
-        let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
+        let mut cursor: Cursor<&[u8]> = Cursor::new(buf);
         if let Ok(pos) = u64::try_from(pos) {
             cursor.set_position(pos);
         } else {
@@ -68,17 +68,13 @@ impl Storage {
         let prop_rank_len: usize = 1;
         let prop_size_len: usize;
         match prop_body_len_rank {
-            8 => if let Ok(val) = usize::try_from(cursor.get_u8()) {
-                prop_body_len_usize = val;
+            8 => {
+                prop_body_len_usize = usize::from(cursor.get_u8());
                 prop_size_len = sizes::U8_LEN;
-            } else {
-                return Err("Fail convert length of name from u8 to usize".to_string());
-            }
-            16 => if let Ok(val) = usize::try_from(cursor.get_u16_le()) {
-                prop_body_len_usize = val;
+            },
+            16 => {
+                prop_body_len_usize = usize::from(cursor.get_u16_le());
                 prop_size_len = sizes::U16_LEN;
-            } else {
-                return Err("Fail convert length of name from u16 to usize".to_string());
             },
             32 => if let Ok(val) = usize::try_from(cursor.get_u32_le()) {
                 prop_body_len_usize = val;
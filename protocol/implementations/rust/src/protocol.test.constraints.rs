@@ -0,0 +1,94 @@
+#[allow(unused_imports)]
+use super::*;
+
+// Code in test/protocol_constraints.rs is generated by fiber-cli from lib-cli/test/protocol_constraints.prot; it's checked, that
+// validators of constraints are compiled and applied on decoding
+#[cfg(test)]
+#[allow(unused_imports)]
+#[allow(clippy::question_mark)]
+mod tests {
+    use super::*;
+    use encode::{ StructEncode, EnumEncode, Encode, EncodeEnum, get_empty_buffer_val };
+    use decode::{ StructDecode, EnumDecode, Decode, DecodeEnum, Source };
+    use storage::{ Storage };
+    use packing::{ PackingStruct, PackingEnum };
+    use buffer::{ DecodeBuffer, Buffer };
+    use std::collections::HashMap;
+
+    include!("./test/protocol_constraints.rs");
+
+    fn get_profile() -> Profile {
+        let mut counters: HashMap<String, u32> = HashMap::new();
+        counters.insert(String::from("posts"), 12);
+        Profile {
+            username: String::from("user_1"),
+            age: 130,
+            email: Some(String::from("user@example.com")),
+            rating: 5.0,
+            scores: vec![-100, 0, 100],
+            tags: vec![String::from("#rust")],
+            avatar: vec![1, 2, 3],
+            counters,
+            level: 99,
+            friends: vec![Friend { name: String::from("friend") }],
+        }
+    }
+
+    fn check(mut profile: Profile) -> Result<Profile, String> {
+        Profile::extract(profile.abduct()?)
+    }
+
+    #[test]
+    fn encode_decode() {
+        // Upper bounds of ranges are valid values
+        let mut profile = get_profile();
+        let mut buffer: Buffer<AvailableMessages> = Buffer::new();
+        if let Err(e) = buffer.chunk(&profile.pack(0, None).unwrap(), None) {
+            panic!("{:?}", e);
+        }
+        match buffer.next() {
+            Some(msg) => match msg.msg {
+                AvailableMessages::Profile(decoded) => assert_eq!(decoded, get_profile()),
+                msg => panic!("Unexpected message: {:?}", msg),
+            },
+            None => panic!("Message isn't decoded"),
+        }
+        let mut profile = get_profile();
+        profile.age = 0;
+        profile.rating = -1.5;
+        profile.email = None;
+        assert!(check(profile).is_ok());
+    }
+
+    #[test]
+    fn validation() {
+        let mut profile = get_profile();
+        profile.username = String::from("User");
+        assert!(check(profile).unwrap_err().contains("should match pattern"));
+        let mut profile = get_profile();
+        profile.username = String::from("ab");
+        assert!(check(profile).unwrap_err().contains("should have length at least 3"));
+        let mut profile = get_profile();
+        profile.age = 131;
+        assert_eq!(check(profile).unwrap_err(), "Field \"age\" of Profile should be in range 0..130, but value is 131");
+        let mut profile = get_profile();
+        profile.email = Some(String::from("user"));
+        assert!(check(profile).is_err());
+        let mut profile = get_profile();
+        profile.rating = 5.5;
+        assert!(check(profile).is_err());
+        let mut profile = get_profile();
+        profile.scores = vec![101];
+        assert!(check(profile).is_err());
+        let mut profile = get_profile();
+        profile.tags = vec![String::from("#rust"), String::from("rust")];
+        assert!(check(profile).is_err());
+        let mut profile = get_profile();
+        profile.level = 0;
+        assert!(check(profile).is_err());
+        let mut profile = get_profile();
+        profile.friends = vec![Friend { name: String::new() }];
+        assert!(check(profile).is_err());
+    }
+
+}
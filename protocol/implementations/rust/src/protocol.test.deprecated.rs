@@ -0,0 +1,56 @@
+#[allow(unused_imports)]
+use super::*;
+
+// Code in test/protocol_deprecated.rs is generated by fiber-cli from lib-cli/test/protocol_deprecated.prot; it's
+// checked, that code with deprecated entities is compiled with include! without warnings and still works
+#[cfg(test)]
+#[allow(unused_imports)]
+#[allow(non_snake_case)]
+#[allow(clippy::question_mark)]
+mod tests {
+    use super::*;
+    use encode::{ StructEncode, EnumEncode, Encode, EncodeEnum, get_empty_buffer_val };
+    use decode::{ StructDecode, EnumDecode, Decode, DecodeEnum, Source };
+    use storage::{ Storage };
+    use packing::{ PackingStruct, PackingEnum };
+    use buffer::{ DecodeBuffer, Buffer };
+    use std::io::Cursor;
+    use std::collections::HashMap;
+    use bytes::{ Buf };
+
+    include!("./test/protocol_deprecated.rs");
+
+    fn get_account() -> Account {
+        let mut account = Account::defaults();
+        account.id = 42;
+        account.uuid2 = String::from("a9f1");
+        account.level = 3;
+        account
+    }
+
+    #[test]
+    fn encode_decode() {
+        let mut buffer: Buffer<AvailableMessages> = Buffer::new();
+        if let Err(e) = buffer.chunk(&get_account().pack(0, None).unwrap(), None) {
+            panic!("{:?}", e);
+        }
+        if let Err(e) = buffer.chunk(&Role::Guest(String::from("visitor")).pack(1, None).unwrap(), None) {
+            panic!("{:?}", e);
+        }
+        match buffer.next() {
+            Some(msg) => match msg.msg {
+                AvailableMessages::Account(decoded) => assert_eq!(decoded, get_account()),
+                msg => panic!("Unexpected message: {:?}", msg),
+            },
+            None => panic!("Message isn't decoded"),
+        }
+        match buffer.next() {
+            Some(msg) => match msg.msg {
+                AvailableMessages::Role(Role::Guest(name)) => assert_eq!(name, "visitor"),
+                msg => panic!("Unexpected message: {:?}", msg),
+            },
+            None => panic!("Message isn't decoded"),
+        }
+    }
+
+}
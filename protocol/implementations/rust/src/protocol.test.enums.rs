@@ -0,0 +1,65 @@
+#[allow(unused_imports)]
+use super::*;
+
+// Code in test/protocol_enums_numeric.rs is generated by fiber-cli from lib-cli/test/protocol_enums_numeric.prot; it's checked, that
+// numeric enums are encoded as u16 and unknown values are rejected on decoding
+#[cfg(test)]
+#[allow(unused_imports)]
+#[allow(non_snake_case)]
+#[allow(clippy::question_mark)]
+mod tests {
+    use super::*;
+    use encode::{ StructEncode, EnumEncode, Encode, EncodeEnum, get_empty_buffer_val };
+    use decode::{ StructDecode, EnumDecode, Decode, DecodeEnum, Source };
+    use storage::{ Storage };
+    use packing::{ PackingStruct, PackingEnum };
+    use buffer::{ DecodeBuffer, Buffer };
+    use std::io::Cursor;
+    use std::collections::HashMap;
+    use bytes::{ Buf };
+
+    include!("./test/protocol_enums_numeric.rs");
+
+    fn get_user() -> User {
+        User {
+            name: String::from("user"),
+            role: Role::Guest,
+            former: Some(Role::User),
+            history: vec![Role::Admin, Role::Guest],
+            slots: vec![None, Some(Role::User)],
+            level: Chat::Level::High,
+            status: user::Status::Banned,
+            kind: Kind::Admin(String::from("admin")),
+        }
+    }
+
+    #[test]
+    fn encode_decode() {
+        let mut user = get_user();
+        let mut buffer: Buffer<AvailableMessages> = Buffer::new();
+        if let Err(e) = buffer.chunk(&user.pack(0, None).unwrap(), None) {
+            panic!("{:?}", e);
+        }
+        match buffer.next() {
+            Some(msg) => match msg.msg {
+                AvailableMessages::User(decoded) => assert_eq!(decoded, get_user()),
+                msg => panic!("Unexpected message: {:?}", msg),
+            },
+            None => panic!("Message isn't decoded"),
+        }
+        let mut user = get_user();
+        user.former = None;
+        user.history = vec![];
+        assert_eq!(User::extract(user.abduct().unwrap()).unwrap(), user);
+    }
+
+    #[test]
+    fn discriminants() {
+        // Item is encoded as its ID, like u16
+        assert_eq!(Role::Guest.encode().unwrap(), 10u16.encode().unwrap());
+        assert_eq!(Role::decode(&2u16.encode().unwrap()).unwrap(), Role::User);
+        assert_eq!(Role::decode(&3u16.encode().unwrap()).unwrap_err(), "Invalid value 3 of enum Role");
+        assert!(<Vec<Role>>::decode(&vec![1u16, 3u16].encode().unwrap()).is_err());
+    }
+
+}
@@ -0,0 +1,54 @@
+#[allow(unused_imports)]
+use super::*;
+
+// Code in test/protocol_maps.rs is generated by fiber-cli from lib-cli/test/protocol_maps.prot; it's checked, that
+// arrays and maps as values of map are encoded and decoded
+#[cfg(test)]
+#[allow(unused_imports)]
+#[allow(non_snake_case)]
+#[allow(clippy::question_mark)]
+mod tests {
+    use super::*;
+    use encode::{ StructEncode, EnumEncode, Encode, EncodeEnum, get_empty_buffer_val };
+    use decode::{ StructDecode, EnumDecode, Decode, DecodeEnum, Source };
+    use storage::{ Storage };
+    use packing::{ PackingStruct, PackingEnum };
+    use buffer::{ DecodeBuffer, Buffer };
+    use std::io::Cursor;
+    use std::collections::HashMap;
+    use bytes::{ Buf };
+
+    include!("./test/protocol_maps.rs");
+
+    fn get_index() -> Index {
+        let mut codes: HashMap<String, Vec<u8>> = HashMap::new();
+        codes.insert(String::from("a"), vec![1, 2, 3]);
+        codes.insert(String::from("b"), vec![]);
+        let mut members: HashMap<u32, Vec<User>> = HashMap::new();
+        members.insert(1, vec![User { name: String::from("first") }, User { name: String::from("second") }]);
+        members.insert(2, vec![]);
+        let mut labels: HashMap<String, HashMap<u16, String>> = HashMap::new();
+        labels.insert(String::from("en"), [(1, String::from("one")), (2, String::from("two"))].iter().cloned().collect());
+        labels.insert(String::from("empty"), HashMap::new());
+        let mut rooms: HashMap<String, HashMap<u8, Vec<Chat::Room>>> = HashMap::new();
+        rooms.insert(String::from("main"), [(0, vec![Chat::Room { title: String::from("lobby") }])].iter().cloned().collect());
+        Index { codes, members, labels, rooms }
+    }
+
+    #[test]
+    fn encode_decode() {
+        let mut index = get_index();
+        let mut buffer: Buffer<AvailableMessages> = Buffer::new();
+        if let Err(e) = buffer.chunk(&index.pack(0, None).unwrap(), None) {
+            panic!("{:?}", e);
+        }
+        match buffer.next() {
+            Some(msg) => match msg.msg {
+                AvailableMessages::Index(decoded) => assert_eq!(decoded, get_index()),
+                msg => panic!("Unexpected message: {:?}", msg),
+            },
+            None => panic!("Message isn't decoded"),
+        }
+    }
+
+}
@@ -2,6 +2,10 @@
 use super::*;
 
 #[cfg(test)]
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+#[allow(clippy::question_mark)]
+#[allow(clippy::large_enum_variant)]
 mod tests { 
     use super::*;
     use encode::{ StructEncode, EnumEncode, Encode, EncodeEnum, get_empty_buffer_val };
@@ -11,6 +15,7 @@ mod tests {
     use buffer::{ DecodeBuffer, Buffer };
     use sizes::{ U16_LEN };
     use std::io::Cursor;
+    use std::collections::HashMap;
     use bytes::{ Buf };
 
     #[derive(Debug, Clone, PartialEq)]
@@ -39,6 +44,8 @@ mod tests {
         Optionf32Vec(Vec<f32>),
         Optionf64Vec(Vec<f64>),
         OptionStructVec(Vec<Nested>),
+        OptionOptionalString(Option<String>),
+        OptionOptionalu32Vec(Vec<Option<u32>>),
         Defaults,
     }
 
@@ -74,6 +81,8 @@ mod tests {
                 Self::Optionf32Vec(v) => (v.encode(), 22),
                 Self::Optionf64Vec(v) => (v.encode(), 23),
                 Self::OptionStructVec(v) => (v.encode(), 24),
+                Self::OptionOptionalString(v) => (v.encode(), 25),
+                Self::OptionOptionalu32Vec(v) => (v.encode(), 26),
                 _ => { return Err(String::from("Not supportable option")); },
             };
             let mut buf = match buf {
@@ -93,7 +102,7 @@ mod tests {
         fn get_id(&self) -> u32 { 1001 }
 
         fn extract(buf: Vec<u8>) -> Result<TargetEnum, String> {
-            if buf.len() <= sizes::U16_LEN {
+            if buf.len() < sizes::U16_LEN {
                 return Err(String::from("Fail to extract value for TargetEnum because buffer too small"));
             }
             let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
@@ -197,6 +206,14 @@ mod tests {
                     Ok(v) => Ok(TargetEnum::OptionStructVec(v)),
                     Err(e) => Err(e),
                 },
+                25 => match Option::<String>::decode(&body_buf) {
+                    Ok(v) => Ok(TargetEnum::OptionOptionalString(v)),
+                    Err(e) => Err(e),
+                },
+                26 => match Vec::<Option<u32>>::decode(&body_buf) {
+                    Ok(v) => Ok(TargetEnum::OptionOptionalu32Vec(v)),
+                    Err(e) => Err(e),
+                },
                 _ => Err(String::from("Fail to find relevant value for TargetEnum"))
             }
         }
@@ -305,6 +322,26 @@ mod tests {
         pub prop_optional_enum: Option<TargetEnum>,
         pub prop_enum_vec: Vec<TargetEnum>,
         pub prop_optional_enum_vec: Option<Vec<TargetEnum>>,
+        pub prop_map_nested: HashMap<String, Nested>,
+        pub prop_map_enum: HashMap<u16, TargetEnum>,
+        pub prop_optional_map: Option<HashMap<i64, f64>>,
+        pub prop_char: char,
+        pub prop_isize: isize,
+        pub prop_usize: usize,
+        pub prop_char_vec: Vec<char>,
+        pub prop_isize_vec: Vec<isize>,
+        pub prop_usize_vec: Vec<usize>,
+        pub prop_timestamp: std::time::SystemTime,
+        pub prop_duration: std::time::Duration,
+        pub prop_uuid: uuid::Uuid,
+        pub prop_timestamp_vec: Vec<std::time::SystemTime>,
+        pub prop_duration_vec: Vec<std::time::Duration>,
+        pub prop_uuid_vec: Vec<uuid::Uuid>,
+        pub prop_u8_fixed: [u8; 4],
+        pub prop_f64_fixed: [f64; 3],
+        pub prop_optional_items: Vec<Option<String>>,
+        pub prop_optional_nested_items: Vec<Option<Nested>>,
+        pub prop_optional_enum_items: Vec<Option<TargetEnum>>,
     }
 
     impl StructDecode for Target {
@@ -342,6 +379,26 @@ mod tests {
                 prop_optional_enum: None,
                 prop_enum_vec: vec![],
                 prop_optional_enum_vec: None,
+                prop_map_nested: HashMap::new(),
+                prop_map_enum: HashMap::new(),
+                prop_optional_map: None,
+                prop_char: '\0',
+                prop_isize: 0,
+                prop_usize: 0,
+                prop_char_vec: vec![],
+                prop_isize_vec: vec![],
+                prop_usize_vec: vec![],
+                prop_timestamp: std::time::UNIX_EPOCH,
+                prop_duration: std::time::Duration::from_millis(0),
+                prop_uuid: uuid::Uuid::nil(),
+                prop_timestamp_vec: vec![],
+                prop_duration_vec: vec![],
+                prop_uuid_vec: vec![],
+                prop_u8_fixed: [0; 4],
+                prop_f64_fixed: [0.0; 3],
+                prop_optional_items: vec![],
+                prop_optional_nested_items: vec![],
+                prop_optional_enum_items: vec![],
             }
         }
         fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
@@ -477,6 +534,86 @@ mod tests {
             } else {
                 return Err("Buffer for property prop_optional_enum_vec isn\'t found".to_string())
             }
+            self.prop_map_nested = match HashMap::<String, Nested>::get_from_storage(Source::Storage(&mut storage), Some(30)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_map_enum = match HashMap::<u16, TargetEnum>::get_from_storage(Source::Storage(&mut storage), Some(31)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_optional_map = match Option::<HashMap<i64, f64>>::get_from_storage(Source::Storage(&mut storage), Some(32)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_char = match char::get_from_storage(Source::Storage(&mut storage), Some(33)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_isize = match isize::get_from_storage(Source::Storage(&mut storage), Some(34)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_usize = match usize::get_from_storage(Source::Storage(&mut storage), Some(35)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_char_vec = match Vec::<char>::get_from_storage(Source::Storage(&mut storage), Some(36)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_isize_vec = match Vec::<isize>::get_from_storage(Source::Storage(&mut storage), Some(37)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_usize_vec = match Vec::<usize>::get_from_storage(Source::Storage(&mut storage), Some(38)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_timestamp = match std::time::SystemTime::get_from_storage(Source::Storage(&mut storage), Some(39)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_duration = match std::time::Duration::get_from_storage(Source::Storage(&mut storage), Some(40)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_uuid = match uuid::Uuid::get_from_storage(Source::Storage(&mut storage), Some(41)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_timestamp_vec = match Vec::<std::time::SystemTime>::get_from_storage(Source::Storage(&mut storage), Some(42)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_duration_vec = match Vec::<std::time::Duration>::get_from_storage(Source::Storage(&mut storage), Some(43)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_uuid_vec = match Vec::<uuid::Uuid>::get_from_storage(Source::Storage(&mut storage), Some(44)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_u8_fixed = match <[u8; 4]>::get_from_storage(Source::Storage(&mut storage), Some(45)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_f64_fixed = match <[f64; 3]>::get_from_storage(Source::Storage(&mut storage), Some(46)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_optional_items = match Vec::<Option<String>>::get_from_storage(Source::Storage(&mut storage), Some(47)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_optional_nested_items = match Vec::<Option<Nested>>::get_from_storage(Source::Storage(&mut storage), Some(48)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            self.prop_optional_enum_items = match Vec::<Option<TargetEnum>>::get_from_storage(Source::Storage(&mut storage), Some(49)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
             Ok(())
         }
     }
@@ -621,6 +758,86 @@ mod tests {
                     Err(e) => { return  Err(e); }
                 };
             }
+            match self.prop_map_nested.get_buf_to_store(Some(30)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_map_enum.get_buf_to_store(Some(31)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_optional_map.get_buf_to_store(Some(32)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_char.get_buf_to_store(Some(33)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_isize.get_buf_to_store(Some(34)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_usize.get_buf_to_store(Some(35)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_char_vec.get_buf_to_store(Some(36)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_isize_vec.get_buf_to_store(Some(37)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_usize_vec.get_buf_to_store(Some(38)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_timestamp.get_buf_to_store(Some(39)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_duration.get_buf_to_store(Some(40)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_uuid.get_buf_to_store(Some(41)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_timestamp_vec.get_buf_to_store(Some(42)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_duration_vec.get_buf_to_store(Some(43)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_uuid_vec.get_buf_to_store(Some(44)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_u8_fixed.get_buf_to_store(Some(45)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_f64_fixed.get_buf_to_store(Some(46)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_optional_items.get_buf_to_store(Some(47)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_optional_nested_items.get_buf_to_store(Some(48)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
+            match self.prop_optional_enum_items.get_buf_to_store(Some(49)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); }
+            };
             Ok(buffer)
         }
 
@@ -734,7 +951,7 @@ mod tests {
             prop_i16: -2,
             prop_i32: -3,
             prop_i64: -4,
-            prop_u8_vec: vec![0, 1, 2, 3, 4],
+            prop_u8_vec: vec![0, 1, 2, 3, 4, 127, 128, 255],
             prop_u16_vec: vec![5, 6, 7, 8, 9],
             prop_u32_vec: vec![10, 11, 12, 13, 14],
             prop_u64_vec: vec![15, 16, 17, 18, 19],
@@ -778,7 +995,48 @@ mod tests {
             }),
             prop_optional_enum: Some(TargetEnum::OptionString(String::from("Hello, from Enum (666)!"))),
             prop_enum_vec: vec![TargetEnum::OptionString(String::from("Hello, from Enum (666)!"))],
-            prop_optional_enum_vec: Some(vec![TargetEnum::OptionString(String::from("Hello, from Enum (666)!")), TargetEnum::Optioni16(666)])
+            prop_optional_enum_vec: Some(vec![TargetEnum::OptionString(String::from("Hello, from Enum (666)!")), TargetEnum::Optioni16(666)]),
+            prop_map_nested: vec![
+                (String::from("first"), Nested {
+                    field_u16: 111,
+                    field_utf8_string: String::from("Hello, from Nested in map!"),
+                    field_optional: None,
+                }),
+                (String::from("second"), Nested {
+                    field_u16: 222,
+                    field_utf8_string: String::from("Hello, from Nested in map!"),
+                    field_optional: Some(2),
+                }),
+            ].into_iter().collect(),
+            prop_map_enum: vec![
+                (1, TargetEnum::OptionString(String::from("Hello, from Enum in map!"))),
+                (2, TargetEnum::Optionu64Vec(vec![1, 2, 3])),
+            ].into_iter().collect(),
+            prop_optional_map: Some(vec![(-1, 0.1), (1, 0.2)].into_iter().collect()),
+            prop_char: '\u{1F600}',
+            prop_isize: -9_000_000_000,
+            prop_usize: 9_000_000_000,
+            prop_char_vec: vec!['a', 'я', '\u{10FFFF}'],
+            prop_isize_vec: vec![isize::MIN, -1, 0, isize::MAX],
+            prop_usize_vec: vec![0, 1, usize::MAX],
+            prop_timestamp: std::time::UNIX_EPOCH + std::time::Duration::from_millis(1_593_561_600_123),
+            prop_duration: std::time::Duration::from_millis(90_061_001),
+            prop_uuid: uuid::Uuid::from_bytes([0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f, 0xe0, 0xc8]),
+            prop_timestamp_vec: vec![std::time::UNIX_EPOCH, std::time::UNIX_EPOCH + std::time::Duration::from_millis(u32::MAX as u64)],
+            prop_duration_vec: vec![std::time::Duration::from_millis(0), std::time::Duration::from_millis(1), std::time::Duration::from_millis(u64::MAX)],
+            prop_uuid_vec: vec![uuid::Uuid::nil(), uuid::Uuid::new_v4()],
+            prop_u8_fixed: [0, 127, 128, 255],
+            prop_f64_fixed: [-0.5, 0.0, f64::MAX],
+            prop_optional_items: vec![Some(String::from("first")), None, Some(String::from("third"))],
+            prop_optional_nested_items: vec![
+                None,
+                Some(Nested {
+                    field_u16: 777,
+                    field_utf8_string: String::from("Hello, from optional item!"),
+                    field_optional: None,
+                }),
+            ],
+            prop_optional_enum_items: vec![Some(TargetEnum::Optionu32(7)), None],
         };
         let buf = match StructEncode::abduct(&mut a) {
             Ok(buf) => buf,
@@ -837,6 +1095,26 @@ mod tests {
         assert_eq!(a.prop_optional_strct, b.prop_optional_strct);
         assert_eq!(a.prop_optional_enum, b.prop_optional_enum);
         assert_eq!(a.prop_optional_enum_vec, b.prop_optional_enum_vec);
+        assert_eq!(a.prop_map_nested, b.prop_map_nested);
+        assert_eq!(a.prop_map_enum, b.prop_map_enum);
+        assert_eq!(a.prop_optional_map, b.prop_optional_map);
+        assert_eq!(a.prop_char, b.prop_char);
+        assert_eq!(a.prop_isize, b.prop_isize);
+        assert_eq!(a.prop_usize, b.prop_usize);
+        assert_eq!(a.prop_char_vec, b.prop_char_vec);
+        assert_eq!(a.prop_isize_vec, b.prop_isize_vec);
+        assert_eq!(a.prop_usize_vec, b.prop_usize_vec);
+        assert_eq!(a.prop_timestamp, b.prop_timestamp);
+        assert_eq!(a.prop_duration, b.prop_duration);
+        assert_eq!(a.prop_uuid, b.prop_uuid);
+        assert_eq!(a.prop_timestamp_vec, b.prop_timestamp_vec);
+        assert_eq!(a.prop_duration_vec, b.prop_duration_vec);
+        assert_eq!(a.prop_uuid_vec, b.prop_uuid_vec);
+        assert_eq!(a.prop_u8_fixed, b.prop_u8_fixed);
+        assert_eq!(a.prop_f64_fixed, b.prop_f64_fixed);
+        assert_eq!(a.prop_optional_items, b.prop_optional_items);
+        assert_eq!(a.prop_optional_nested_items, b.prop_optional_nested_items);
+        assert_eq!(a.prop_optional_enum_items, b.prop_optional_enum_items);
         let enums: Vec<TargetEnum> = vec![
             TargetEnum::OptionString(String::from("Hello from enum!")),
             TargetEnum::Optionu8(1),
@@ -877,6 +1155,9 @@ mod tests {
                     field_optional: Some(2),
                 }
             ]),
+            TargetEnum::OptionOptionalString(Some(String::from("Hello from optional enum item!"))),
+            TargetEnum::OptionOptionalString(None),
+            TargetEnum::OptionOptionalu32Vec(vec![Some(1), None, Some(3)]),
         ];
         let mut enums_bufs: Vec<Vec<u8>> = vec![];
         for item in enums.iter() {
@@ -910,67 +1191,83 @@ mod tests {
             field_utf8_string: String::from("Hello, from Nested!"),
             field_optional: Some(2),
         };
-        buf.append(&mut a.pack(0).unwrap());
-        buf.append(&mut empty_a.pack(1).unwrap());
-        buf.append(&mut c.pack(2).unwrap());
+        buf.append(&mut a.pack(0, None).unwrap());
+        buf.append(&mut empty_a.pack(1, None).unwrap());
+        buf.append(&mut c.pack(2, None).unwrap());
         for item in enums.iter() {
             let mut item = item.clone();
-            buf.append(&mut item.pack(3).unwrap());
+            buf.append(&mut item.pack(3, None).unwrap());
         }
-        if let Err(e) = buffer.chunk(&buf) {
+        if let Err(e) = buffer.chunk(&buf, None) {
             println!("{:?}", e);
             assert_eq!(true, false);
         }
         let mut count = 10 + 20 + 30 + enums.len();
-        loop {
-            if let Some(msg) = buffer.next() {
-                match msg.msg {
-                    Messages::Nested(b) => {
-                        count -= 10;
-                        assert_eq!(a.prop_nested, b);
-                        assert_eq!(a.prop_nested.field_optional, b.field_optional);
-                    },
-                    Messages::Target(b) => {
-                        count -= 20;
-                        assert_eq!(a.prop_u8, b.prop_u8);
-                        assert_eq!(a.prop_u16, b.prop_u16);
-                        assert_eq!(a.prop_u32, b.prop_u32);
-                        assert_eq!(a.prop_u64, b.prop_u64);
-                        assert_eq!(a.prop_i8, b.prop_i8);
-                        assert_eq!(a.prop_i16, b.prop_i16);
-                        assert_eq!(a.prop_i32, b.prop_i32);
-                        assert_eq!(a.prop_i64, b.prop_i64);
-                        assert_eq!(a.prop_u8_vec, b.prop_u8_vec);
-                        assert_eq!(a.prop_u16_vec, b.prop_u16_vec);
-                        assert_eq!(a.prop_u32_vec, b.prop_u32_vec);
-                        assert_eq!(a.prop_u64_vec, b.prop_u64_vec);
-                        assert_eq!(a.prop_i8_vec, b.prop_i8_vec);
-                        assert_eq!(a.prop_i16_vec, b.prop_i16_vec);
-                        assert_eq!(a.prop_i32_vec, b.prop_i32_vec);
-                        assert_eq!(a.prop_i64_vec, b.prop_i64_vec);
-                        assert_eq!(a.prop_f32_vec, b.prop_f32_vec);
-                        assert_eq!(a.prop_f64_vec, b.prop_f64_vec);
-                        assert_eq!(a.prop_f32, b.prop_f32);
-                        assert_eq!(a.prop_f64, b.prop_f64);
-                        assert_eq!(a.prop_utf8_string_vec, b.prop_utf8_string_vec);
-                        assert_eq!(a.prop_nested, b.prop_nested);
-                        assert_eq!(a.prop_nested.field_optional, b.prop_nested.field_optional);
-                        assert_eq!(a.prop_nested_vec, b.prop_nested_vec);
-                        assert_eq!(a.prop_enum, b.prop_enum);
-                        assert_eq!(a.prop_optional_strct, b.prop_optional_strct);
-                        assert_eq!(a.prop_optional_enum, b.prop_optional_enum);
-                        assert_eq!(a.prop_optional_enum_vec, b.prop_optional_enum_vec);
-                    },
-                    Messages::Empty(b) => {
-                        count -= 30;
-                    },
-                    Messages::TargetEnum(pack) => {
-                        count -= 1;
-                    },
-                };
-            } else {
-                break;
-            }
+        while let Some(msg) = buffer.next() {
+            match msg.msg {
+                Messages::Nested(b) => {
+                    count -= 10;
+                    assert_eq!(a.prop_nested, b);
+                    assert_eq!(a.prop_nested.field_optional, b.field_optional);
+                },
+                Messages::Target(b) => {
+                    count -= 20;
+                    assert_eq!(a.prop_u8, b.prop_u8);
+                    assert_eq!(a.prop_u16, b.prop_u16);
+                    assert_eq!(a.prop_u32, b.prop_u32);
+                    assert_eq!(a.prop_u64, b.prop_u64);
+                    assert_eq!(a.prop_i8, b.prop_i8);
+                    assert_eq!(a.prop_i16, b.prop_i16);
+                    assert_eq!(a.prop_i32, b.prop_i32);
+                    assert_eq!(a.prop_i64, b.prop_i64);
+                    assert_eq!(a.prop_u8_vec, b.prop_u8_vec);
+                    assert_eq!(a.prop_u16_vec, b.prop_u16_vec);
+                    assert_eq!(a.prop_u32_vec, b.prop_u32_vec);
+                    assert_eq!(a.prop_u64_vec, b.prop_u64_vec);
+                    assert_eq!(a.prop_i8_vec, b.prop_i8_vec);
+                    assert_eq!(a.prop_i16_vec, b.prop_i16_vec);
+                    assert_eq!(a.prop_i32_vec, b.prop_i32_vec);
+                    assert_eq!(a.prop_i64_vec, b.prop_i64_vec);
+                    assert_eq!(a.prop_f32_vec, b.prop_f32_vec);
+                    assert_eq!(a.prop_f64_vec, b.prop_f64_vec);
+                    assert_eq!(a.prop_f32, b.prop_f32);
+                    assert_eq!(a.prop_f64, b.prop_f64);
+                    assert_eq!(a.prop_utf8_string_vec, b.prop_utf8_string_vec);
+                    assert_eq!(a.prop_nested, b.prop_nested);
+                    assert_eq!(a.prop_nested.field_optional, b.prop_nested.field_optional);
+                    assert_eq!(a.prop_nested_vec, b.prop_nested_vec);
+                    assert_eq!(a.prop_enum, b.prop_enum);
+                    assert_eq!(a.prop_optional_strct, b.prop_optional_strct);
+                    assert_eq!(a.prop_optional_enum, b.prop_optional_enum);
+                    assert_eq!(a.prop_optional_enum_vec, b.prop_optional_enum_vec);
+                    assert_eq!(a.prop_map_nested, b.prop_map_nested);
+                    assert_eq!(a.prop_map_enum, b.prop_map_enum);
+                    assert_eq!(a.prop_optional_map, b.prop_optional_map);
+                    assert_eq!(a.prop_char, b.prop_char);
+                    assert_eq!(a.prop_isize, b.prop_isize);
+                    assert_eq!(a.prop_usize, b.prop_usize);
+                    assert_eq!(a.prop_char_vec, b.prop_char_vec);
+                    assert_eq!(a.prop_isize_vec, b.prop_isize_vec);
+                    assert_eq!(a.prop_usize_vec, b.prop_usize_vec);
+                    assert_eq!(a.prop_timestamp, b.prop_timestamp);
+                    assert_eq!(a.prop_duration, b.prop_duration);
+                    assert_eq!(a.prop_uuid, b.prop_uuid);
+                    assert_eq!(a.prop_timestamp_vec, b.prop_timestamp_vec);
+                    assert_eq!(a.prop_duration_vec, b.prop_duration_vec);
+                    assert_eq!(a.prop_uuid_vec, b.prop_uuid_vec);
+                    assert_eq!(a.prop_u8_fixed, b.prop_u8_fixed);
+                    assert_eq!(a.prop_f64_fixed, b.prop_f64_fixed);
+                    assert_eq!(a.prop_optional_items, b.prop_optional_items);
+                    assert_eq!(a.prop_optional_nested_items, b.prop_optional_nested_items);
+                    assert_eq!(a.prop_optional_enum_items, b.prop_optional_enum_items);
+                },
+                Messages::Empty(b) => {
+                    count -= 30;
+                },
+                Messages::TargetEnum(pack) => {
+                    count -= 1;
+                },
+            };
         }
         assert_eq!(count, 0);
         assert_eq!(buffer.len(), 0);
@@ -0,0 +1,245 @@
+
+#[derive(Debug, Clone)]
+pub enum AvailableMessages {
+    Friend(Friend),
+    Profile(Profile),
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct Friend {
+    pub name: String,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for Friend {
+    fn get_id() -> u32 {
+        1
+    }
+    fn defaults() -> Friend {
+        Friend {
+            name: String::from(""),
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.name = match String::get_from_storage(Source::Storage(&mut storage), Some(2)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        if self.name.chars().count() < 1 {
+            return Err(format!("Field \"name\" of Friend should have length at least 1, but length is {}", self.name.chars().count()));
+        }
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for Friend {
+    fn get_id(&self) -> u32 { 1 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.name.get_buf_to_store(Some(2)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+impl PackingStruct for Friend { }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub username: String,
+    /// Range includes both bounds: 0 and 130 are valid
+    pub age: u8,
+    pub email: Option<String>,
+    pub rating: f64,
+    pub scores: Vec<i64>,
+    pub tags: Vec<String>,
+    pub avatar: Vec<u8>,
+    pub counters: HashMap<String, u32>,
+    pub level: u16,
+    pub friends: Vec<Friend>,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for Profile {
+    fn get_id() -> u32 {
+        3
+    }
+    fn defaults() -> Profile {
+        Profile {
+            username: String::from(""),
+            age: 0,
+            email: None,
+            rating: 0.0,
+            scores: vec![],
+            tags: vec![],
+            avatar: vec![],
+            counters: HashMap::new(),
+            level: 5,
+            friends: vec![],
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.username = match String::get_from_storage(Source::Storage(&mut storage), Some(4)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.age = match u8::get_from_storage(Source::Storage(&mut storage), Some(5)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.email = match Option::<String>::get_from_storage(Source::Storage(&mut storage), Some(6)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.rating = match f64::get_from_storage(Source::Storage(&mut storage), Some(7)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.scores = match Vec::<i64>::get_from_storage(Source::Storage(&mut storage), Some(8)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.tags = match Vec::<String>::get_from_storage(Source::Storage(&mut storage), Some(9)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.avatar = match Vec::<u8>::get_from_storage(Source::Storage(&mut storage), Some(10)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.counters = match HashMap::<String, u32>::get_from_storage(Source::Storage(&mut storage), Some(11)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.level = match u16::get_from_storage(Source::Storage(&mut storage), Some(12)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.friends = match Vec::<Friend>::get_from_storage(Source::Storage(&mut storage), Some(13)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        if self.username.chars().count() < 3 {
+            return Err(format!("Field \"username\" of Profile should have length at least 3, but length is {}", self.username.chars().count()));
+        }
+        if self.username.chars().count() > 32 {
+            return Err(format!("Field \"username\" of Profile should have length at most 32, but length is {}", self.username.chars().count()));
+        }
+        static PATTERN_USERNAME: std::sync::OnceLock<Result<regex::Regex, String>> = std::sync::OnceLock::new();
+        let pattern = PATTERN_USERNAME.get_or_init(|| regex::Regex::new("^[a-z0-9_]+$").map_err(|e| e.to_string())).as_ref().map_err(|e| e.clone())?;
+        if !pattern.is_match(&self.username) {
+            return Err(format!("Field \"username\" of Profile should match pattern {}, but value is {:?}", pattern, self.username));
+        }
+        if !(0..=130).contains(&self.age) {
+            return Err(format!("Field \"age\" of Profile should be in range 0..130, but value is {}", self.age));
+        }
+        if let Some(value) = self.email.as_ref() {
+            static PATTERN_EMAIL: std::sync::OnceLock<Result<regex::Regex, String>> = std::sync::OnceLock::new();
+            let pattern = PATTERN_EMAIL.get_or_init(|| regex::Regex::new("^[^@\\s]+@[^@\\s]+$").map_err(|e| e.to_string())).as_ref().map_err(|e| e.clone())?;
+            if !pattern.is_match(value) {
+                return Err(format!("Field \"email\" of Profile should match pattern {}, but value is {:?}", pattern, value));
+            }
+        }
+        if !(-1.5..=5.0).contains(&self.rating) {
+            return Err(format!("Field \"rating\" of Profile should be in range -1.5..5, but value is {}", self.rating));
+        }
+        if self.scores.len() > 10 {
+            return Err(format!("Field \"scores\" of Profile should have length at most 10, but length is {}", self.scores.len()));
+        }
+        if let Some(item) = self.scores.iter().find(|item| !(-100..=100).contains(*item)) {
+            return Err(format!("Field \"scores\" of Profile should be in range -100..100, but value is {}", item));
+        }
+        if self.tags.is_empty() {
+            return Err(format!("Field \"tags\" of Profile should have length at least 1, but length is {}", self.tags.len()));
+        }
+        static PATTERN_TAGS: std::sync::OnceLock<Result<regex::Regex, String>> = std::sync::OnceLock::new();
+        let pattern = PATTERN_TAGS.get_or_init(|| regex::Regex::new("^#\\w+$").map_err(|e| e.to_string())).as_ref().map_err(|e| e.clone())?;
+        if let Some(item) = self.tags.iter().find(|item| !pattern.is_match(item)) {
+            return Err(format!("Field \"tags\" of Profile should match pattern {}, but value is {:?}", pattern, item));
+        }
+        if self.avatar.len() > 65536 {
+            return Err(format!("Field \"avatar\" of Profile should have length at most 65536, but length is {}", self.avatar.len()));
+        }
+        if self.counters.len() > 8 {
+            return Err(format!("Field \"counters\" of Profile should have length at most 8, but length is {}", self.counters.len()));
+        }
+        if !(1..=99).contains(&self.level) {
+            return Err(format!("Field \"level\" of Profile should be in range 1..99, but value is {}", self.level));
+        }
+        if self.friends.len() > 3 {
+            return Err(format!("Field \"friends\" of Profile should have length at most 3, but length is {}", self.friends.len()));
+        }
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for Profile {
+    fn get_id(&self) -> u32 { 3 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.username.get_buf_to_store(Some(4)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.age.get_buf_to_store(Some(5)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.email.get_buf_to_store(Some(6)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.rating.get_buf_to_store(Some(7)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.scores.get_buf_to_store(Some(8)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.tags.get_buf_to_store(Some(9)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.avatar.get_buf_to_store(Some(10)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.counters.get_buf_to_store(Some(11)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.level.get_buf_to_store(Some(12)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.friends.get_buf_to_store(Some(13)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+impl PackingStruct for Profile { }
+
+impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {
+    fn get_msg(&self, id: u32, buf: &[u8]) -> Result<AvailableMessages, String> {
+        match id {
+            1 => match Friend::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Friend(m)),
+                Err(e) => Err(e),
+            },
+            3 => match Profile::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Profile(m)),
+                Err(e) => Err(e),
+            },
+            _ => Err(String::from("No message has been found"))
+        }
+    }
+    fn get_signature(&self) -> u16 { 0 }
+}
+
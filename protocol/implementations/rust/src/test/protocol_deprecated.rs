@@ -0,0 +1,283 @@
+
+#[allow(deprecated)]
+#[derive(Debug, Clone)]
+pub enum AvailableMessages {
+    Role(Role),
+    User(User),
+    Account(Account),
+    Chat(Chat::AvailableMessages),
+}
+#[allow(deprecated)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Role {
+    Admin(String),
+    #[deprecated(note = "use Admin")]
+    Root(String),
+    Guest(String),
+    Defaults,
+}
+#[allow(deprecated)]
+impl EnumDecode for Role {
+    fn get_id(&self) -> u32 { 9 }
+    fn extract(buf: Vec<u8>) -> Result<Role, String> {
+        if buf.len() < sizes::U16_LEN {
+            return Err(String::from("Fail to extract value for Role because buffer too small"));
+        }
+        let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
+        let index = cursor.get_u16_le();
+        let mut body_buf = vec![0; buf.len() - sizes::U16_LEN];
+        body_buf.copy_from_slice(&buf[sizes::U16_LEN..]);
+        match index {
+            0 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Role::Admin(v)),
+                Err(e) => Err(e)
+            },
+            1 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Role::Root(v)),
+                Err(e) => Err(e)
+            },
+            2 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Role::Guest(v)),
+                Err(e) => Err(e)
+            },
+            _ => Err(String::from("Fail to find relevant value for Role")),
+        }
+    }
+}
+#[allow(deprecated)]
+impl EnumEncode for Role {
+    fn get_id(&self) -> u32 { 9 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let (buf, index) = match self {
+            Self::Admin(v) => (v.encode(), 0),
+            Self::Root(v) => (v.encode(), 1),
+            Self::Guest(v) => (v.encode(), 2),
+            _ => { return Err(String::from("Not supportable option")); },
+        };
+        let mut buf = match buf {
+            Ok(buf) => buf,
+            Err(e) => { return Err(e); },
+        };
+        let mut buffer: Vec<u8> = vec!();
+        buffer.append(&mut (index as u16).to_le_bytes().to_vec());
+        buffer.append(&mut buf);
+        Ok(buffer)
+    }
+}
+#[allow(deprecated)]
+impl PackingEnum for Role {}
+
+/// Account of user
+#[deprecated(note = "use Account")]
+#[allow(deprecated)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+    pub name: String,
+}
+#[allow(deprecated)]
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for User {
+    fn get_id() -> u32 {
+        1
+    }
+    fn defaults() -> User {
+        User {
+            name: String::from(""),
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.name = match String::get_from_storage(Source::Storage(&mut storage), Some(2)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        Ok(())
+    }
+}
+#[allow(deprecated)]
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for User {
+    fn get_id(&self) -> u32 { 1 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.name.get_buf_to_store(Some(2)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+#[allow(deprecated)]
+impl PackingStruct for User { }
+
+#[allow(deprecated)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Account {
+    pub id: u64,
+    /// Unique identifier
+    #[deprecated(note = "use uuid2")]
+    pub uuid1: String,
+    pub uuid2: String,
+    #[deprecated]
+    pub tags: HashMap<String, u8>,
+    pub level: u8,
+}
+#[allow(deprecated)]
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for Account {
+    fn get_id() -> u32 {
+        3
+    }
+    fn defaults() -> Account {
+        Account {
+            id: 0,
+            uuid1: String::from(""),
+            uuid2: String::from(""),
+            tags: HashMap::new(),
+            level: 0,
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.id = match u64::get_from_storage(Source::Storage(&mut storage), Some(1)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.uuid1 = match String::get_from_storage(Source::Storage(&mut storage), Some(6)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.uuid2 = match String::get_from_storage(Source::Storage(&mut storage), Some(7)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.tags = match HashMap::<String, u8>::get_from_storage(Source::Storage(&mut storage), Some(8)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.level = match u8::get_from_storage(Source::Storage(&mut storage), Some(3)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        Ok(())
+    }
+}
+#[allow(deprecated)]
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for Account {
+    fn get_id(&self) -> u32 { 3 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.id.get_buf_to_store(Some(1)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.uuid1.get_buf_to_store(Some(6)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.uuid2.get_buf_to_store(Some(7)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.tags.get_buf_to_store(Some(8)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.level.get_buf_to_store(Some(3)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+#[allow(deprecated)]
+impl PackingStruct for Account { }
+
+pub mod Chat {
+    use super::*;
+    use std::io::Cursor;
+    use bytes::{ Buf };
+    #[allow(deprecated)]
+    #[derive(Debug, Clone)]
+    pub enum AvailableMessages {
+        Room(Room),
+    }
+
+    #[deprecated]
+    #[allow(deprecated)]
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Room {
+        pub title: String,
+    }
+    #[allow(deprecated)]
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructDecode for Room {
+        fn get_id() -> u32 {
+            11
+        }
+        fn defaults() -> Room {
+            Room {
+                title: String::from(""),
+            }
+        }
+        fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+            self.title = match String::get_from_storage(Source::Storage(&mut storage), Some(12)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            Ok(())
+        }
+    }
+    #[allow(deprecated)]
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructEncode for Room {
+        fn get_id(&self) -> u32 { 11 }
+        fn get_signature(&self) -> u16 { 0 }
+        fn abduct(&mut self) -> Result<Vec<u8>, String> {
+            let mut buffer: Vec<u8> = vec!();
+            match self.title.get_buf_to_store(Some(12)) {
+                Ok(mut buf) => { buffer.append(&mut buf); }
+                Err(e) => { return Err(e) },
+            };
+            Ok(buffer)
+        }
+    }
+    #[allow(deprecated)]
+    impl PackingStruct for Room { }
+
+}
+
+#[allow(deprecated)]
+impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {
+    fn get_msg(&self, id: u32, buf: &[u8]) -> Result<AvailableMessages, String> {
+        match id {
+            9 => match Role::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Role(m)),
+                Err(e) => Err(e),
+            },
+            1 => match User::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::User(m)),
+                Err(e) => Err(e),
+            },
+            3 => match Account::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Account(m)),
+                Err(e) => Err(e),
+            },
+            11 => match Chat::Room::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Chat(Chat::AvailableMessages::Room(m))),
+                Err(e) => Err(e),
+            },
+            _ => Err(String::from("No message has been found"))
+        }
+    }
+    fn get_signature(&self) -> u16 { 0 }
+}
+
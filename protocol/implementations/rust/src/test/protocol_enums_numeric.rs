@@ -0,0 +1,318 @@
+
+#[derive(Debug, Clone)]
+pub enum AvailableMessages {
+    Kind(Kind),
+    User(User),
+    Chat(Chat::AvailableMessages),
+}
+/// Role of user
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+pub enum Role {
+    Admin = 1,
+    /// Regular user
+    User = 2,
+    Guest = 10,
+}
+impl std::convert::TryFrom<u16> for Role {
+    type Error = String;
+    fn try_from(value: u16) -> Result<Role, String> {
+        match value {
+            1 => Ok(Role::Admin),
+            2 => Ok(Role::User),
+            10 => Ok(Role::Guest),
+            _ => Err(format!("Invalid value {} of enum Role", value)),
+        }
+    }
+}
+impl Encode for Role {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        (*self as u16).get_buf_to_store(id)
+    }
+}
+impl Decode<Role> for Role {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Role, String> {
+        <Role as std::convert::TryFrom<u16>>::try_from(<u16>::get_from_storage(source, id)?)
+    }
+}
+impl Encode for Vec<Role> {
+    fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+        let mut items: Vec<u16> = self.iter().map(|item| *item as u16).collect();
+        items.get_buf_to_store(id)
+    }
+}
+impl Decode<Vec<Role>> for Vec<Role> {
+    fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<Role>, String> {
+        <Vec<u16>>::get_from_storage(source, id)?.into_iter().map(<Role as std::convert::TryFrom<u16>>::try_from).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    Admin(String),
+    User(String),
+    Defaults,
+}
+impl EnumDecode for Kind {
+    fn get_id(&self) -> u32 { 2 }
+    fn extract(buf: Vec<u8>) -> Result<Kind, String> {
+        if buf.len() < sizes::U16_LEN {
+            return Err(String::from("Fail to extract value for Kind because buffer too small"));
+        }
+        let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
+        let index = cursor.get_u16_le();
+        let mut body_buf = vec![0; buf.len() - sizes::U16_LEN];
+        body_buf.copy_from_slice(&buf[sizes::U16_LEN..]);
+        match index {
+            1 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Kind::Admin(v)),
+                Err(e) => Err(e)
+            },
+            2 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Kind::User(v)),
+                Err(e) => Err(e)
+            },
+            _ => Err(String::from("Fail to find relevant value for Kind")),
+        }
+    }
+}
+impl EnumEncode for Kind {
+    fn get_id(&self) -> u32 { 2 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let (buf, index) = match self {
+            Self::Admin(v) => (v.encode(), 1),
+            Self::User(v) => (v.encode(), 2),
+            _ => { return Err(String::from("Not supportable option")); },
+        };
+        let mut buf = match buf {
+            Ok(buf) => buf,
+            Err(e) => { return Err(e); },
+        };
+        let mut buffer: Vec<u8> = vec!();
+        buffer.append(&mut (index as u16).to_le_bytes().to_vec());
+        buffer.append(&mut buf);
+        Ok(buffer)
+    }
+}
+impl PackingEnum for Kind {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+    pub name: String,
+    pub role: Role,
+    pub former: Option<Role>,
+    pub history: Vec<Role>,
+    pub slots: Vec<Option<Role>>,
+    pub level: Chat::Level,
+    pub status: user::Status,
+    pub kind: Kind,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for User {
+    fn get_id() -> u32 {
+        5
+    }
+    fn defaults() -> User {
+        User {
+            name: String::from(""),
+            role: Role::Admin,
+            former: None,
+            history: vec![],
+            slots: vec![],
+            level: Chat::Level::Low,
+            status: user::Status::Active,
+            kind: Kind::Defaults,
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.name = match String::get_from_storage(Source::Storage(&mut storage), Some(8)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.role = match Role::get_from_storage(Source::Storage(&mut storage), Some(9)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.former = match Option::<Role>::get_from_storage(Source::Storage(&mut storage), Some(10)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.history = match Vec::<Role>::get_from_storage(Source::Storage(&mut storage), Some(11)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.slots = match Vec::<Option<Role>>::get_from_storage(Source::Storage(&mut storage), Some(12)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.level = match Chat::Level::get_from_storage(Source::Storage(&mut storage), Some(13)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.status = match user::Status::get_from_storage(Source::Storage(&mut storage), Some(14)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.kind = match Kind::get_from_storage(Source::Storage(&mut storage), Some(15)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for User {
+    fn get_id(&self) -> u32 { 5 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.name.get_buf_to_store(Some(8)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.role.get_buf_to_store(Some(9)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.former.get_buf_to_store(Some(10)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.history.get_buf_to_store(Some(11)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.slots.get_buf_to_store(Some(12)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.level.get_buf_to_store(Some(13)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.status.get_buf_to_store(Some(14)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.kind.get_buf_to_store(Some(15)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+impl PackingStruct for User { }
+
+pub mod Chat {
+    use super::*;
+    use std::io::Cursor;
+    use bytes::{ Buf };
+    #[derive(Debug, Clone)]
+    pub enum AvailableMessages {
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(u16)]
+    pub enum Level {
+        Low = 0,
+        High = 1,
+    }
+    impl std::convert::TryFrom<u16> for Level {
+        type Error = String;
+        fn try_from(value: u16) -> Result<Level, String> {
+            match value {
+                0 => Ok(Level::Low),
+                1 => Ok(Level::High),
+                _ => Err(format!("Invalid value {} of enum Level", value)),
+            }
+        }
+    }
+    impl Encode for Level {
+        fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+            (*self as u16).get_buf_to_store(id)
+        }
+    }
+    impl Decode<Level> for Level {
+        fn get_from_storage(source: Source, id: Option<u16>) -> Result<Level, String> {
+            <Level as std::convert::TryFrom<u16>>::try_from(<u16>::get_from_storage(source, id)?)
+        }
+    }
+    impl Encode for Vec<Level> {
+        fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+            let mut items: Vec<u16> = self.iter().map(|item| *item as u16).collect();
+            items.get_buf_to_store(id)
+        }
+    }
+    impl Decode<Vec<Level>> for Vec<Level> {
+        fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<Level>, String> {
+            <Vec<u16>>::get_from_storage(source, id)?.into_iter().map(<Level as std::convert::TryFrom<u16>>::try_from).collect()
+        }
+    }
+
+}
+
+pub mod user {
+    use super::*;
+    use std::io::Cursor;
+    use bytes::{ Buf };
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(u16)]
+    pub enum Status {
+        Active = 1,
+        Banned = 2,
+    }
+    impl std::convert::TryFrom<u16> for Status {
+        type Error = String;
+        fn try_from(value: u16) -> Result<Status, String> {
+            match value {
+                1 => Ok(Status::Active),
+                2 => Ok(Status::Banned),
+                _ => Err(format!("Invalid value {} of enum Status", value)),
+            }
+        }
+    }
+    impl Encode for Status {
+        fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+            (*self as u16).get_buf_to_store(id)
+        }
+    }
+    impl Decode<Status> for Status {
+        fn get_from_storage(source: Source, id: Option<u16>) -> Result<Status, String> {
+            <Status as std::convert::TryFrom<u16>>::try_from(<u16>::get_from_storage(source, id)?)
+        }
+    }
+    impl Encode for Vec<Status> {
+        fn get_buf_to_store(&mut self, id: Option<u16>) -> Result<Vec<u8>, String> {
+            let mut items: Vec<u16> = self.iter().map(|item| *item as u16).collect();
+            items.get_buf_to_store(id)
+        }
+    }
+    impl Decode<Vec<Status>> for Vec<Status> {
+        fn get_from_storage(source: Source, id: Option<u16>) -> Result<Vec<Status>, String> {
+            <Vec<u16>>::get_from_storage(source, id)?.into_iter().map(<Status as std::convert::TryFrom<u16>>::try_from).collect()
+        }
+    }
+
+}
+
+impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {
+    fn get_msg(&self, id: u32, buf: &[u8]) -> Result<AvailableMessages, String> {
+        match id {
+            2 => match Kind::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Kind(m)),
+                Err(e) => Err(e),
+            },
+            5 => match User::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::User(m)),
+                Err(e) => Err(e),
+            },
+            _ => Err(String::from("No message has been found"))
+        }
+    }
+    fn get_signature(&self) -> u16 { 0 }
+}
+
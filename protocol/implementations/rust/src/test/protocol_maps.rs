@@ -0,0 +1,395 @@
+
+#[derive(Debug, Clone)]
+pub enum AvailableMessages {
+    Status(Status),
+    User(User),
+    Directory(Directory),
+    Index(Index),
+    Chat(Chat::AvailableMessages),
+}
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Online(String),
+    Offline(String),
+    Defaults,
+}
+impl EnumDecode for Status {
+    fn get_id(&self) -> u32 { 1 }
+    fn extract(buf: Vec<u8>) -> Result<Status, String> {
+        if buf.len() < sizes::U16_LEN {
+            return Err(String::from("Fail to extract value for Status because buffer too small"));
+        }
+        let mut cursor: Cursor<&[u8]> = Cursor::new(&buf);
+        let index = cursor.get_u16_le();
+        let mut body_buf = vec![0; buf.len() - sizes::U16_LEN];
+        body_buf.copy_from_slice(&buf[sizes::U16_LEN..]);
+        match index {
+            0 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Status::Online(v)),
+                Err(e) => Err(e)
+            },
+            1 => match String::decode(&body_buf) {
+                Ok(v) => Ok(Status::Offline(v)),
+                Err(e) => Err(e)
+            },
+            _ => Err(String::from("Fail to find relevant value for Status")),
+        }
+    }
+}
+impl EnumEncode for Status {
+    fn get_id(&self) -> u32 { 1 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let (buf, index) = match self {
+            Self::Online(v) => (v.encode(), 0),
+            Self::Offline(v) => (v.encode(), 1),
+            _ => { return Err(String::from("Not supportable option")); },
+        };
+        let mut buf = match buf {
+            Ok(buf) => buf,
+            Err(e) => { return Err(e); },
+        };
+        let mut buffer: Vec<u8> = vec!();
+        buffer.append(&mut (index as u16).to_le_bytes().to_vec());
+        buffer.append(&mut buf);
+        Ok(buffer)
+    }
+}
+impl PackingEnum for Status {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+    pub name: String,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for User {
+    fn get_id() -> u32 {
+        2
+    }
+    fn defaults() -> User {
+        User {
+            name: String::from(""),
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.name = match String::get_from_storage(Source::Storage(&mut storage), Some(3)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for User {
+    fn get_id(&self) -> u32 { 2 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.name.get_buf_to_store(Some(3)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+impl PackingStruct for User { }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directory {
+    pub users: HashMap<String, User>,
+    pub names: HashMap<u32, String>,
+    pub statuses: HashMap<i64, Status>,
+    pub rooms: HashMap<String, Chat::Room>,
+    pub flags: Option<HashMap<bool, f64>>,
+    pub optional_statuses: Option<HashMap<u8, Status>>,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for Directory {
+    fn get_id() -> u32 {
+        9
+    }
+    fn defaults() -> Directory {
+        Directory {
+            users: HashMap::new(),
+            names: HashMap::new(),
+            statuses: HashMap::new(),
+            rooms: HashMap::new(),
+            flags: None,
+            optional_statuses: None,
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.users = match HashMap::<String, User>::get_from_storage(Source::Storage(&mut storage), Some(10)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.names = match HashMap::<u32, String>::get_from_storage(Source::Storage(&mut storage), Some(11)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.statuses = match HashMap::<i64, Status>::get_from_storage(Source::Storage(&mut storage), Some(12)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.rooms = match HashMap::<String, Chat::Room>::get_from_storage(Source::Storage(&mut storage), Some(13)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.flags = match Option::<HashMap::<bool, f64>>::get_from_storage(Source::Storage(&mut storage), Some(14)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        if let Some(buf) = storage.get(15) {
+            if buf.is_empty() {
+                self.optional_statuses = None;
+            } else {
+                self.optional_statuses = match HashMap::<u8, Status>::get_from_storage(Source::Storage(&mut storage), Some(15)) {
+                    Ok(val) => Some(val),
+                    Err(e) => { return Err(e) },
+                };
+            }
+        } else {
+            return Err("Buffer for property optional_statuses isn't found".to_string());
+        }
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for Directory {
+    fn get_id(&self) -> u32 { 9 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.users.get_buf_to_store(Some(10)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.names.get_buf_to_store(Some(11)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.statuses.get_buf_to_store(Some(12)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.rooms.get_buf_to_store(Some(13)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.flags.get_buf_to_store(Some(14)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        if let Some(mut val) = self.optional_statuses.clone() {
+            match val.get_buf_to_store(Some(15)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); },
+            };
+        } else {
+            match get_empty_buffer_val(Some(15)) {
+                Ok(mut buf) => { buffer.append(&mut buf); },
+                Err(e) => { return  Err(e); },
+            };
+        }
+        Ok(buffer)
+    }
+}
+impl PackingStruct for Directory { }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Index {
+    pub codes: HashMap<String, Vec<u8>>,
+    pub members: HashMap<u32, Vec<User>>,
+    pub labels: HashMap<String, HashMap<u16, String>>,
+    pub rooms: HashMap<String, HashMap<u8, Vec<Chat::Room>>>,
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructDecode for Index {
+    fn get_id() -> u32 {
+        16
+    }
+    fn defaults() -> Index {
+        Index {
+            codes: HashMap::new(),
+            members: HashMap::new(),
+            labels: HashMap::new(),
+            rooms: HashMap::new(),
+        }
+    }
+    fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+        self.codes = match HashMap::<String, Vec::<u8>>::get_from_storage(Source::Storage(&mut storage), Some(17)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.members = match HashMap::<u32, Vec::<User>>::get_from_storage(Source::Storage(&mut storage), Some(18)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.labels = match HashMap::<String, HashMap::<u16, String>>::get_from_storage(Source::Storage(&mut storage), Some(19)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        self.rooms = match HashMap::<String, HashMap::<u8, Vec::<Chat::Room>>>::get_from_storage(Source::Storage(&mut storage), Some(20)) {
+            Ok(val) => val,
+            Err(e) => { return Err(e) },
+        };
+        Ok(())
+    }
+}
+#[allow(unused_variables)]
+#[allow(unused_mut)]
+impl StructEncode for Index {
+    fn get_id(&self) -> u32 { 16 }
+    fn get_signature(&self) -> u16 { 0 }
+    fn abduct(&mut self) -> Result<Vec<u8>, String> {
+        let mut buffer: Vec<u8> = vec!();
+        match self.codes.get_buf_to_store(Some(17)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.members.get_buf_to_store(Some(18)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.labels.get_buf_to_store(Some(19)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        match self.rooms.get_buf_to_store(Some(20)) {
+            Ok(mut buf) => { buffer.append(&mut buf); }
+            Err(e) => { return Err(e) },
+        };
+        Ok(buffer)
+    }
+}
+impl PackingStruct for Index { }
+
+pub mod Chat {
+    use super::*;
+    use std::io::Cursor;
+    use bytes::{ Buf };
+    #[derive(Debug, Clone)]
+    pub enum AvailableMessages {
+        Room(Room),
+        Lobby(Lobby),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Room {
+        pub title: String,
+    }
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructDecode for Room {
+        fn get_id() -> u32 {
+            5
+        }
+        fn defaults() -> Room {
+            Room {
+                title: String::from(""),
+            }
+        }
+        fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+            self.title = match String::get_from_storage(Source::Storage(&mut storage), Some(6)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            Ok(())
+        }
+    }
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructEncode for Room {
+        fn get_id(&self) -> u32 { 5 }
+        fn get_signature(&self) -> u16 { 0 }
+        fn abduct(&mut self) -> Result<Vec<u8>, String> {
+            let mut buffer: Vec<u8> = vec!();
+            match self.title.get_buf_to_store(Some(6)) {
+                Ok(mut buf) => { buffer.append(&mut buf); }
+                Err(e) => { return Err(e) },
+            };
+            Ok(buffer)
+        }
+    }
+    impl PackingStruct for Room { }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Lobby {
+        /// Rooms by ID
+        pub rooms: HashMap<u32, Room>,
+    }
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructDecode for Lobby {
+        fn get_id() -> u32 {
+            7
+        }
+        fn defaults() -> Lobby {
+            Lobby {
+                rooms: HashMap::new(),
+            }
+        }
+        fn extract_from_storage(&mut self, mut storage: Storage) -> Result<(), String> {
+            self.rooms = match HashMap::<u32, Room>::get_from_storage(Source::Storage(&mut storage), Some(8)) {
+                Ok(val) => val,
+                Err(e) => { return Err(e) },
+            };
+            Ok(())
+        }
+    }
+    #[allow(unused_variables)]
+    #[allow(unused_mut)]
+    impl StructEncode for Lobby {
+        fn get_id(&self) -> u32 { 7 }
+        fn get_signature(&self) -> u16 { 0 }
+        fn abduct(&mut self) -> Result<Vec<u8>, String> {
+            let mut buffer: Vec<u8> = vec!();
+            match self.rooms.get_buf_to_store(Some(8)) {
+                Ok(mut buf) => { buffer.append(&mut buf); }
+                Err(e) => { return Err(e) },
+            };
+            Ok(buffer)
+        }
+    }
+    impl PackingStruct for Lobby { }
+
+}
+
+impl DecodeBuffer<AvailableMessages> for Buffer<AvailableMessages> {
+    fn get_msg(&self, id: u32, buf: &[u8]) -> Result<AvailableMessages, String> {
+        match id {
+            1 => match Status::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Status(m)),
+                Err(e) => Err(e),
+            },
+            2 => match User::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::User(m)),
+                Err(e) => Err(e),
+            },
+            5 => match Chat::Room::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Chat(Chat::AvailableMessages::Room(m))),
+                Err(e) => Err(e),
+            },
+            7 => match Chat::Lobby::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Chat(Chat::AvailableMessages::Lobby(m))),
+                Err(e) => Err(e),
+            },
+            9 => match Directory::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Directory(m)),
+                Err(e) => Err(e),
+            },
+            16 => match Index::extract(buf.to_vec()) {
+                Ok(m) => Ok(AvailableMessages::Index(m)),
+                Err(e) => Err(e),
+            },
+            _ => Err(String::from("No message has been found"))
+        }
+    }
+    fn get_signature(&self) -> u16 { 0 }
+}
+
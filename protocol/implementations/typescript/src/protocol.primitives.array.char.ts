@@ -0,0 +1,88 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { char } from './protocol.primitives.char';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayChar extends Primitive<string[]> {
+
+    public static getSignature(): string {
+        return 'ArrayChar';
+    }
+
+    public static encode(value: string[]): ArrayBufferLike | Error {
+        const len: number = value.length * char.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                const code: number | undefined = val.codePointAt(0);
+                if (code === undefined) {
+                    throw new Error(`Empty string cannot be encoded as char`);
+                }
+                buffer.writeUInt32LE(code, offset);
+                offset += char.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): string[] | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < char.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${char.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: string[] = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                array.push(String.fromCodePoint(buffer.readUInt32LE(offset)));
+                offset += char.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = char.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayChar.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayChar.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): string[] | Error {
+        const value = ArrayChar.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
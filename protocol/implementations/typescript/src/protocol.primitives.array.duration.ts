@@ -0,0 +1,84 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { duration } from './protocol.primitives.duration';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayDuration extends Primitive<Array<number>> {
+
+    public static getSignature(): string {
+        return 'ArrayDuration';
+    }
+
+    public static encode(value: Array<number>): ArrayBufferLike | Error {
+        const len: number = value.length * duration.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                buffer.writeBigUInt64LE(BigInt(val), offset);
+                offset += duration.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Array<number> | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < duration.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${duration.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: Array<number> = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                array.push(Number(buffer.readBigUInt64LE(offset)));
+                offset += duration.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = duration.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayDuration.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayDuration.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<number> | Error {
+        const value = ArrayDuration.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
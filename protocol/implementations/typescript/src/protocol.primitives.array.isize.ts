@@ -0,0 +1,84 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { isize } from './protocol.primitives.isize';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayIsize extends Primitive<Array<bigint>> {
+
+    public static getSignature(): string {
+        return 'ArrayIsize';
+    }
+
+    public static encode(value: Array<bigint>): ArrayBufferLike | Error {
+        const len: number = value.length * isize.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                buffer.writeBigInt64LE(val, offset);
+                offset += isize.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Array<bigint> | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < isize.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${isize.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: Array<bigint> = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                array.push(buffer.readBigInt64LE(offset));
+                offset += isize.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = isize.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayIsize.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayIsize.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<bigint> | Error {
+        const value = ArrayIsize.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
@@ -0,0 +1,84 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { timestamp } from './protocol.primitives.timestamp';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayTimestamp extends Primitive<Array<Date>> {
+
+    public static getSignature(): string {
+        return 'ArrayTimestamp';
+    }
+
+    public static encode(value: Array<Date>): ArrayBufferLike | Error {
+        const len: number = value.length * timestamp.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                buffer.writeBigUInt64LE(BigInt(val.getTime()), offset);
+                offset += timestamp.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Array<Date> | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < timestamp.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${timestamp.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: Array<Date> = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                array.push(new Date(Number(buffer.readBigUInt64LE(offset))));
+                offset += timestamp.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = timestamp.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayTimestamp.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayTimestamp.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<Date> | Error {
+        const value = ArrayTimestamp.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
@@ -0,0 +1,84 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { usize } from './protocol.primitives.usize';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayUsize extends Primitive<Array<bigint>> {
+
+    public static getSignature(): string {
+        return 'ArrayUsize';
+    }
+
+    public static encode(value: Array<bigint>): ArrayBufferLike | Error {
+        const len: number = value.length * usize.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                buffer.writeBigUInt64LE(val, offset);
+                offset += usize.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Array<bigint> | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < usize.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${usize.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: Array<bigint> = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                array.push(buffer.readBigUInt64LE(offset));
+                offset += usize.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = usize.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayUsize.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayUsize.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<bigint> | Error {
+        const value = ArrayUsize.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
@@ -0,0 +1,92 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { uuid } from './protocol.primitives.uuid';
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class ArrayUuid extends Primitive<Array<string>> {
+
+    public static getSignature(): string {
+        return 'ArrayUuid';
+    }
+
+    public static encode(value: Array<string>): ArrayBufferLike | Error {
+        const len: number = value.length * uuid.getSize();
+        const buffer: Buffer = Buffer.alloc(len);
+        try {
+            let offset: number = 0;
+            value.forEach((val) => {
+                const err: Error | undefined = uuid.validate(val);
+                if (err instanceof Error) {
+                    throw err;
+                }
+                buffer.write(val.replace(/-/g, ''), offset, uuid.getSize(), 'hex');
+                offset += uuid.getSize();
+            });
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Array<string> | Error {
+        if (bytes.byteLength === 0) {
+            return [];
+        }
+        if (bytes.byteLength < uuid.getSize()) {
+            return new Error(`Invalid buffer size. Expected at least ${uuid.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            let offset: number = 0;
+            const array: Array<string> = [];
+            const buffer: Buffer = Buffer.from(bytes);
+            do {
+                const val = uuid.decode(buffer.slice(offset, offset + uuid.getSize()));
+                if (val instanceof Error) {
+                    throw val;
+                }
+                array.push(val);
+                offset += uuid.getSize();
+            } while (buffer.byteLength > offset);
+            return array;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Array)) {
+            return new Error(`Invalid type of variable`);
+        }
+        try {
+            value.forEach((val: any, index: number) => {
+                const err: Error | undefined = uuid.validate(val);
+                if (err instanceof Error) {
+                    throw new Error(`Error on index #${index}: ${err.message}`);
+                }
+            });
+        } catch (e) {
+            return e;
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return ArrayUuid.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return ArrayUuid.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<string> | Error {
+        const value = ArrayUuid.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
@@ -0,0 +1,50 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class Bytes extends Primitive<Uint8Array> {
+
+    public static getSignature(): string {
+        return 'Bytes';
+    }
+
+    // Opaque binary data is written as it is, without converting each byte
+    public static encode(value: Uint8Array): ArrayBufferLike | Error {
+        if (value.byteOffset === 0 && value.byteLength === value.buffer.byteLength) {
+            return value.buffer;
+        }
+        return value.buffer.slice(value.byteOffset, value.byteOffset + value.byteLength);
+    }
+
+    // Returns view on given buffer, bytes aren't copied
+    public static decode(bytes: ArrayBufferLike): Uint8Array | Error {
+        return new Uint8Array(bytes);
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Uint8Array)) {
+            return new Error(`Invalid type of variable. Expecting Uint8Array`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return Bytes.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return Bytes.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Uint8Array | Error {
+        const value = Bytes.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
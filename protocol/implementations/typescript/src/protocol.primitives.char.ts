@@ -0,0 +1,78 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+import { CBits } from './protocol.sizes';
+
+// injectable
+export class char extends Primitive<string> {
+
+    public static MIN: number = 0;
+    public static MAX: number = 0x10FFFF;
+
+    public static getSignature(): string {
+        return 'char';
+    }
+
+    // Char is sent as u32 code point
+    public static getSize(): number {
+        return 32 / CBits;
+    }
+
+    public static encode(value: string): ArrayBufferLike | Error {
+        const buffer: Buffer = Buffer.alloc(char.getSize());
+        try {
+            const code: number | undefined = value.codePointAt(0);
+            if (code === undefined) {
+                return new Error(`Empty string cannot be encoded as char`);
+            }
+            buffer.writeUInt32LE(code);
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): string | Error {
+        if (bytes.byteLength !== char.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${char.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const buffer: Buffer = Buffer.from(bytes);
+            return String.fromCodePoint(buffer.readUInt32LE(0));
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (typeof value !== 'string') {
+            return new Error(`Invalid type of variable`);
+        }
+        const code: number | undefined = value.codePointAt(0);
+        if (code === undefined || code < char.MIN || code > char.MAX) {
+            return new Error(`Out of range.`);
+        }
+        if (String.fromCodePoint(code) !== value) {
+            return new Error(`Expecting single char, but got "${value}"`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return char.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return char.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): string | Error {
+        const value = char.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
@@ -0,0 +1,110 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import * as Tools from './tools/index';
+
+import { u64 } from './protocol.primitives.u64';
+
+// injectable
+export class Dictionary {
+
+    public static getSignature(): string {
+        return 'Dictionary';
+    }
+
+    // Each pair is written as: [u64 length of key][key][u64 length of value][value]
+    public static encode<K, V>(
+        value: Map<K, V>,
+        keyEncoder: (key: K) => ArrayBufferLike | Error,
+        valueEncoder: (value: V) => ArrayBufferLike | Error,
+    ): ArrayBufferLike | Error {
+        const parts: ArrayBufferLike[] = [];
+        try {
+            value.forEach((val: V, key: K) => {
+                [keyEncoder(key), valueEncoder(val)].forEach((buf: ArrayBufferLike | Error) => {
+                    if (buf instanceof Error) {
+                        throw buf;
+                    }
+                    const len = u64.encode(BigInt(buf.byteLength));
+                    if (len instanceof Error) {
+                        throw len;
+                    }
+                    parts.push(len);
+                    parts.push(buf);
+                });
+            });
+        } catch (e) {
+            return e;
+        }
+        return Tools.append(parts);
+    }
+
+    public static decode<K, V>(
+        bytes: ArrayBufferLike,
+        keyDecoder: (bytes: ArrayBufferLike) => K | Error,
+        valueDecoder: (bytes: ArrayBufferLike) => V | Error,
+    ): Map<K, V> | Error {
+        const buffer = Buffer.from(bytes);
+        const map: Map<K, V> = new Map();
+        let offset: number = 0;
+        const next = (): Buffer | Error => {
+            if (buffer.byteLength - offset < u64.getSize()) {
+                return new Error(`Invalid size marker. Expecting u64 (size ${u64.getSize()} bytes), but rest of buffer: ${buffer.byteLength - offset} bytes.`);
+            }
+            const len = Number(buffer.readBigUInt64LE(offset));
+            offset += u64.getSize();
+            if (buffer.byteLength - offset < len) {
+                return new Error(`Cannot extract pair of map, because expecting ${len} bytes, but rest of buffer: ${buffer.byteLength - offset} bytes.`);
+            }
+            const body = buffer.slice(offset, offset + len);
+            offset += len;
+            return body;
+        };
+        while (offset < buffer.byteLength) {
+            const keyBuf = next();
+            if (keyBuf instanceof Error) {
+                return keyBuf;
+            }
+            const valueBuf = next();
+            if (valueBuf instanceof Error) {
+                return valueBuf;
+            }
+            const key = keyDecoder(keyBuf);
+            if (key instanceof Error) {
+                return key;
+            }
+            const val = valueDecoder(valueBuf);
+            if (val instanceof Error) {
+                return val;
+            }
+            map.set(key, val);
+        }
+        return map;
+    }
+
+    public static getValidator(
+        key: { validate(value: any): Error | undefined },
+        value: { validate(value: any): Error | undefined },
+    ): { validate(value: any): Error | undefined } {
+        return { validate(obj: any): Error | undefined {
+            if (!(obj instanceof Map)) {
+                return new Error(`Expecting Map`);
+            }
+            try {
+                obj.forEach((val: any, k: any) => {
+                    const keyErr: Error | undefined = key.validate(k);
+                    if (keyErr instanceof Error) {
+                        throw new Error(`Error on key ${k}: ${keyErr.message}`);
+                    }
+                    const valueErr: Error | undefined = value.validate(val);
+                    if (valueErr instanceof Error) {
+                        throw new Error(`Error on value of key ${k}: ${valueErr.message}`);
+                    }
+                });
+            } catch (e) {
+                return e;
+            }
+            return undefined;
+        }};
+    }
+
+}
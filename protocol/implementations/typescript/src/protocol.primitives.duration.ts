@@ -0,0 +1,73 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+import { CBits } from './protocol.sizes';
+
+// injectable
+export class duration extends Primitive<number> {
+
+    public static MIN: number = 0;
+    public static MAX: number = Number.MAX_SAFE_INTEGER;
+
+    public static getSignature(): string {
+        return 'duration';
+    }
+
+    // Duration is sent as u64 count of milliseconds
+    public static getSize(): number {
+        return 64 / CBits;
+    }
+
+    public static encode(value: number): ArrayBufferLike | Error {
+        const buffer: Buffer = Buffer.alloc(duration.getSize());
+        try {
+            buffer.writeBigUInt64LE(BigInt(value));
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): number | Error {
+        if (bytes.byteLength !== duration.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${duration.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const buffer: Buffer = Buffer.from(bytes);
+            return Number(buffer.readBigUInt64LE(0));
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (typeof value !== 'number') {
+            return new Error(`Invalid type of variable`);
+        }
+        if (!Number.isInteger(value)) {
+            return new Error(`Duration should be integer count of milliseconds: ${value}`);
+        }
+        if (value < duration.MIN || value > duration.MAX) {
+            return new Error(`Out of range.`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return duration.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return duration.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): number | Error {
+        const value = duration.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
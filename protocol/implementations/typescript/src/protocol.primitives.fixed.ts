@@ -0,0 +1,53 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+
+// injectable
+export class Fixed {
+
+    public static getSignature(): string {
+        return 'Fixed';
+    }
+
+    // Fixed-size array is written as sequence of items without count of items, because count is defined by type
+    public static encode<T>(
+        value: T[],
+        length: number,
+        encoder: (value: T[]) => ArrayBufferLike | Error,
+    ): ArrayBufferLike | Error {
+        if (!(value instanceof Array) || value.length !== length) {
+            return new Error(`Expecting array with ${length} items, but ${value instanceof Array ? value.length : 'none'} items were found`);
+        }
+        return encoder(value);
+    }
+
+    public static decode<T>(
+        bytes: ArrayBufferLike,
+        length: number,
+        decoder: (bytes: ArrayBufferLike) => T[] | Error,
+    ): T[] | Error {
+        const value = decoder(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        if (value.length !== length) {
+            return new Error(`Expecting array with ${length} items, but ${value.length} items were found`);
+        }
+        return value;
+    }
+
+    public static getValidator(
+        items: { validate(value: any): Error | undefined },
+        length: number,
+    ): { validate(value: any): Error | undefined } {
+        return { validate(obj: any): Error | undefined {
+            if (!(obj instanceof Array)) {
+                return new Error(`Expecting Array`);
+            }
+            if (obj.length !== length) {
+                return new Error(`Expecting array with ${length} items, but ${obj.length} items were found`);
+            }
+            return items.validate(obj);
+        }};
+    }
+
+}
@@ -0,0 +1,70 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+import { CBits } from './protocol.sizes';
+
+// injectable
+export class isize extends Primitive<bigint> {
+
+    public static MIN: number = -Number.MAX_SAFE_INTEGER;
+    public static MAX: number = Number.MAX_SAFE_INTEGER;
+
+    public static getSignature(): string {
+        return 'isize';
+    }
+
+    // Always 64 bits, regardless of platform
+    public static getSize(): number {
+        return 64 / CBits;
+    }
+
+    public static encode(value: bigint): ArrayBufferLike | Error {
+        const buffer: Buffer = Buffer.alloc(isize.getSize());
+        try {
+            buffer.writeBigInt64LE(value);
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): bigint | Error {
+        if (bytes.byteLength !== isize.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${isize.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const buffer: Buffer = Buffer.from(bytes);
+            return buffer.readBigInt64LE(0);
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (typeof value !== 'bigint') {
+            return new Error(`Invalid type of variable`);
+        }
+        if (value < isize.MIN || value > isize.MAX) {
+            return new Error(`Out of range.`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return isize.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return isize.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): bigint | Error {
+        const value = isize.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
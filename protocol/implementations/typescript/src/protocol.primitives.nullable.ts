@@ -0,0 +1,185 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import * as Tools from './tools/index';
+
+import { ISigned } from './protocol.primitives.interface';
+import { u8 } from './protocol.primitives.u8';
+import { u64 } from './protocol.primitives.u64';
+
+// injectable
+export class Nullable<T> implements ISigned<Array<T | undefined>> {
+
+    public static getSignature(items: string): string {
+        return `Nullable<${items}>`;
+    }
+
+    // Each item is written as: [u8 0] for undefined or [u8 1][u64 length of item][item]
+    public static encode<T>(
+        value: Array<T | undefined>,
+        encoder: (value: T) => ArrayBufferLike | Error,
+    ): ArrayBufferLike | Error {
+        const parts: ArrayBufferLike[] = [];
+        try {
+            value.forEach((val: T | undefined) => {
+                const flag = u8.encode(val === undefined ? 0 : 1);
+                if (flag instanceof Error) {
+                    throw flag;
+                }
+                parts.push(flag);
+                if (val === undefined) {
+                    return;
+                }
+                const buf = encoder(val);
+                if (buf instanceof Error) {
+                    throw buf;
+                }
+                const len = u64.encode(BigInt(buf.byteLength));
+                if (len instanceof Error) {
+                    throw len;
+                }
+                parts.push(len);
+                parts.push(buf);
+            });
+        } catch (e) {
+            return e;
+        }
+        return Tools.append(parts);
+    }
+
+    public static decode<T>(
+        bytes: ArrayBufferLike,
+        decoder: (bytes: ArrayBufferLike) => T | Error,
+    ): Array<T | undefined> | Error {
+        const buffer = Buffer.from(bytes);
+        const items: Array<T | undefined> = [];
+        let offset: number = 0;
+        while (offset < buffer.byteLength) {
+            const flag = buffer.readUInt8(offset);
+            offset += u8.getSize();
+            if (flag === 0) {
+                items.push(undefined);
+                continue;
+            }
+            if (buffer.byteLength - offset < u64.getSize()) {
+                return new Error(`Invalid size marker. Expecting u64 (size ${u64.getSize()} bytes), but rest of buffer: ${buffer.byteLength - offset} bytes.`);
+            }
+            const len = Number(buffer.readBigUInt64LE(offset));
+            offset += u64.getSize();
+            if (buffer.byteLength - offset < len) {
+                return new Error(`Cannot extract item of array, because expecting ${len} bytes, but rest of buffer: ${buffer.byteLength - offset} bytes.`);
+            }
+            const item = decoder(buffer.slice(offset, offset + len));
+            if (item instanceof Error) {
+                return item;
+            }
+            items.push(item);
+            offset += len;
+        }
+        return items;
+    }
+
+    public static getValidator(
+        items: { validate(value: any): Error | undefined },
+    ): { validate(value: any): Error | undefined } {
+        return { validate(obj: any): Error | undefined {
+            if (!(obj instanceof Array)) {
+                return new Error(`Expecting Array`);
+            }
+            for (let index = 0; index < obj.length; index += 1) {
+                if (obj[index] === undefined) {
+                    continue;
+                }
+                const err: Error | undefined = items.validate(obj[index]);
+                if (err instanceof Error) {
+                    return new Error(`Error on index #${index}: ${err.message}`);
+                }
+            }
+            return undefined;
+        }};
+    }
+
+    private _value: Array<T | undefined>;
+    private _signature: string;
+    private _encoder: (value: T) => ArrayBufferLike | Error;
+    private _decoder: (bytes: ArrayBufferLike) => T | Error;
+
+    // Instance is used as value of enum's item
+    constructor(
+        value: Array<T | undefined>,
+        signature: string,
+        encoder: (value: T) => ArrayBufferLike | Error,
+        decoder: (bytes: ArrayBufferLike) => T | Error,
+    ) {
+        this._value = value;
+        this._signature = Nullable.getSignature(signature);
+        this._encoder = encoder;
+        this._decoder = decoder;
+    }
+
+    public getSignature(): string {
+        return this._signature;
+    }
+
+    public get(): Array<T | undefined> {
+        return this._value;
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return Nullable.encode<T>(this._value, this._encoder);
+    }
+
+    public decode(bytes: ArrayBufferLike): Array<T | undefined> | Error {
+        const value = Nullable.decode<T>(bytes, this._decoder);
+        if (value instanceof Error) {
+            return value;
+        }
+        this._value = value;
+        return value;
+    }
+
+}
+
+// Value of enum's item, which can be null; null is written as empty buffer
+export class Optional<T> implements ISigned<T | null> {
+
+    public static getValidator(
+        value: { validate(value: any): Error | undefined },
+    ): { validate(value: any): Error | undefined } {
+        return { validate(obj: any): Error | undefined {
+            return obj === null ? undefined : value.validate(obj);
+        }};
+    }
+
+    private _value: ISigned<T>;
+    private _empty: boolean;
+
+    constructor(value: ISigned<T>, empty: boolean = false) {
+        this._value = value;
+        this._empty = empty;
+    }
+
+    public getSignature(): string {
+        return this._value.getSignature();
+    }
+
+    public get(): T | null {
+        return this._empty ? null : this._value.get();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return this._empty ? new Uint8Array() : this._value.encode();
+    }
+
+    public decode(bytes: ArrayBufferLike): T | null | Error {
+        this._empty = bytes.byteLength === 0;
+        if (this._empty) {
+            return null;
+        }
+        const err = this._value.decode(bytes);
+        if (err instanceof Error) {
+            return err;
+        }
+        return this._value.get();
+    }
+
+}
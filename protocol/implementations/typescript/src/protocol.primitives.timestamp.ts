@@ -0,0 +1,70 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+import { CBits } from './protocol.sizes';
+
+// injectable
+export class timestamp extends Primitive<Date> {
+
+    public static getSignature(): string {
+        return 'timestamp';
+    }
+
+    // Timestamp is sent as u64 count of milliseconds since UNIX epoch
+    public static getSize(): number {
+        return 64 / CBits;
+    }
+
+    public static encode(value: Date): ArrayBufferLike | Error {
+        const buffer: Buffer = Buffer.alloc(timestamp.getSize());
+        try {
+            buffer.writeBigUInt64LE(BigInt(value.getTime()));
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): Date | Error {
+        if (bytes.byteLength !== timestamp.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${timestamp.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const buffer: Buffer = Buffer.from(bytes);
+            return new Date(Number(buffer.readBigUInt64LE(0)));
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (!(value instanceof Date)) {
+            return new Error(`Invalid type of variable. Expecting Date`);
+        }
+        if (isNaN(value.getTime())) {
+            return new Error(`Invalid date`);
+        }
+        if (value.getTime() < 0) {
+            return new Error(`Date before UNIX epoch cannot be sent`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return timestamp.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return timestamp.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): Date | Error {
+        const value = timestamp.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
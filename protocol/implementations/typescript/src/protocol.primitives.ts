@@ -11,6 +11,12 @@ export { i64 } from './protocol.primitives.i64';
 export { f32 } from './protocol.primitives.f32';
 export { f64 } from './protocol.primitives.f64';
 export { bool } from './protocol.primitives.bool';
+export { char } from './protocol.primitives.char';
+export { isize } from './protocol.primitives.isize';
+export { usize } from './protocol.primitives.usize';
+export { timestamp } from './protocol.primitives.timestamp';
+export { duration } from './protocol.primitives.duration';
+export { uuid } from './protocol.primitives.uuid';
 export { StrUTF8 } from './protocol.primitives.string.utf8';
 export { ArrayU8 } from './protocol.primitives.array.u8';
 export { ArrayU16 } from './protocol.primitives.array.u16';
@@ -24,7 +30,17 @@ export { ArrayF32 } from './protocol.primitives.array.f32';
 export { ArrayF64 } from './protocol.primitives.array.f64';
 export { ArrayBool } from './protocol.primitives.array.bool';
 export { ArrayStrUTF8 } from './protocol.primitives.array.string.utf8';
+export { ArrayChar } from './protocol.primitives.array.char';
+export { ArrayIsize } from './protocol.primitives.array.isize';
+export { ArrayUsize } from './protocol.primitives.array.usize';
+export { ArrayTimestamp } from './protocol.primitives.array.timestamp';
+export { ArrayDuration } from './protocol.primitives.array.duration';
+export { ArrayUuid } from './protocol.primitives.array.uuid';
+export { Bytes } from './protocol.primitives.bytes';
 export { Option, Enum } from './protocol.primitives.enum';
+export { Dictionary } from './protocol.primitives.dictionary';
+export { Fixed } from './protocol.primitives.fixed';
+export { Nullable, Optional } from './protocol.primitives.nullable';
 
 import { u8 } from './protocol.primitives.u8';
 import { u16 } from './protocol.primitives.u16';
@@ -37,6 +53,12 @@ import { i64 } from './protocol.primitives.i64';
 import { f32 } from './protocol.primitives.f32';
 import { f64 } from './protocol.primitives.f64';
 import { bool } from './protocol.primitives.bool';
+import { char } from './protocol.primitives.char';
+import { isize } from './protocol.primitives.isize';
+import { usize } from './protocol.primitives.usize';
+import { timestamp } from './protocol.primitives.timestamp';
+import { duration } from './protocol.primitives.duration';
+import { uuid } from './protocol.primitives.uuid';
 import { StrUTF8 } from './protocol.primitives.string.utf8';
 import { ArrayU8 } from './protocol.primitives.array.u8';
 import { ArrayU16 } from './protocol.primitives.array.u16';
@@ -50,7 +72,17 @@ import { ArrayF32 } from './protocol.primitives.array.f32';
 import { ArrayF64 } from './protocol.primitives.array.f64';
 import { ArrayBool } from './protocol.primitives.array.bool';
 import { ArrayStrUTF8 } from './protocol.primitives.array.string.utf8';
+import { ArrayChar } from './protocol.primitives.array.char';
+import { ArrayIsize } from './protocol.primitives.array.isize';
+import { ArrayUsize } from './protocol.primitives.array.usize';
+import { ArrayTimestamp } from './protocol.primitives.array.timestamp';
+import { ArrayDuration } from './protocol.primitives.array.duration';
+import { ArrayUuid } from './protocol.primitives.array.uuid';
+import { Bytes } from './protocol.primitives.bytes';
 import { Option, Enum } from './protocol.primitives.enum';
+import { Dictionary } from './protocol.primitives.dictionary';
+import { Fixed } from './protocol.primitives.fixed';
+import { Nullable, Optional } from './protocol.primitives.nullable';
 import { Primitive } from './protocol.primitives.interface';
 
 // injectable
@@ -65,6 +97,12 @@ type i64Alias = i64; const i64Alias = i64;
 type f32Alias = f32; const f32Alias = f32;
 type f64Alias = f64; const f64Alias = f64;
 type boolAlias = bool; const boolAlias = bool;
+type charAlias = char; const charAlias = char;
+type isizeAlias = isize; const isizeAlias = isize;
+type usizeAlias = usize; const usizeAlias = usize;
+type timestampAlias = timestamp; const timestampAlias = timestamp;
+type durationAlias = duration; const durationAlias = duration;
+type uuidAlias = uuid; const uuidAlias = uuid;
 type StrUTF8Alias = StrUTF8; const StrUTF8Alias = StrUTF8;
 type ArrayU8Alias = ArrayU8; const ArrayU8Alias = ArrayU8;
 type ArrayU16Alias = ArrayU16; const ArrayU16Alias = ArrayU16;
@@ -78,8 +116,19 @@ type ArrayF32Alias = ArrayF32; const ArrayF32Alias = ArrayF32;
 type ArrayF64Alias = ArrayF64; const ArrayF64Alias = ArrayF64;
 type ArrayBoolAlias = ArrayBool; const ArrayBoolAlias = ArrayBool;
 type ArrayStrUTF8Alias = ArrayStrUTF8; const ArrayStrUTF8Alias = ArrayStrUTF8;
+type ArrayCharAlias = ArrayChar; const ArrayCharAlias = ArrayChar;
+type ArrayIsizeAlias = ArrayIsize; const ArrayIsizeAlias = ArrayIsize;
+type ArrayUsizeAlias = ArrayUsize; const ArrayUsizeAlias = ArrayUsize;
+type ArrayTimestampAlias = ArrayTimestamp; const ArrayTimestampAlias = ArrayTimestamp;
+type ArrayDurationAlias = ArrayDuration; const ArrayDurationAlias = ArrayDuration;
+type ArrayUuidAlias = ArrayUuid; const ArrayUuidAlias = ArrayUuid;
+type BytesAlias = Bytes; const BytesAlias = Bytes;
 type OptionAlias = Option<any>; const OptionAlias = Option;
 type EnumAlias = Enum<any>; const EnumAlias = Enum;
+type DictionaryAlias = Dictionary; const DictionaryAlias = Dictionary;
+type FixedAlias = Fixed; const FixedAlias = Fixed;
+type NullableAlias = Nullable<any>; const NullableAlias = Nullable;
+type OptionalAlias = Optional<any>; const OptionalAlias = Optional;
 type PrimitiveAlias = Primitive<any>; const PrimitiveAlias = Primitive;
 
 export namespace Primitives {
@@ -94,6 +143,12 @@ export namespace Primitives {
     export const f32 = f32Alias; export type f32 = f32Alias;
     export const f64 = f64Alias; export type f64 = f64Alias;
     export const bool = boolAlias; export type bool = boolAlias;
+    export const char = charAlias; export type char = charAlias;
+    export const isize = isizeAlias; export type isize = isizeAlias;
+    export const usize = usizeAlias; export type usize = usizeAlias;
+    export const timestamp = timestampAlias; export type timestamp = timestampAlias;
+    export const duration = durationAlias; export type duration = durationAlias;
+    export const uuid = uuidAlias; export type uuid = uuidAlias;
     export const StrUTF8 = StrUTF8Alias; export type StrUTF8 = StrUTF8Alias;
     export const ArrayU8 = ArrayU8Alias; export type ArrayU8 = ArrayU8Alias;
     export const ArrayU16 = ArrayU16Alias; export type ArrayU16 = ArrayU16Alias;
@@ -107,7 +162,18 @@ export namespace Primitives {
     export const ArrayF64 = ArrayF64Alias; export type ArrayF64 = ArrayF64Alias;
     export const ArrayBool = ArrayBoolAlias; export type ArrayBool = ArrayBoolAlias;
     export const ArrayStrUTF8 = ArrayStrUTF8Alias; export type ArrayStrUTF8 = ArrayStrUTF8Alias;
+    export const ArrayChar = ArrayCharAlias; export type ArrayChar = ArrayCharAlias;
+    export const ArrayIsize = ArrayIsizeAlias; export type ArrayIsize = ArrayIsizeAlias;
+    export const ArrayUsize = ArrayUsizeAlias; export type ArrayUsize = ArrayUsizeAlias;
+    export const ArrayTimestamp = ArrayTimestampAlias; export type ArrayTimestamp = ArrayTimestampAlias;
+    export const ArrayDuration = ArrayDurationAlias; export type ArrayDuration = ArrayDurationAlias;
+    export const ArrayUuid = ArrayUuidAlias; export type ArrayUuid = ArrayUuidAlias;
+    export const Bytes = BytesAlias; export type Bytes = BytesAlias;
     export const Option = OptionAlias; export type Option = OptionAlias;
     export const Enum = EnumAlias; export type Enum = EnumAlias;
+    export const Dictionary = DictionaryAlias; export type Dictionary = DictionaryAlias;
+    export const Fixed = FixedAlias; export type Fixed = FixedAlias;
+    export const Nullable = NullableAlias; export type Nullable = NullableAlias;
+    export const Optional = OptionalAlias; export type Optional = OptionalAlias;
     export const Primitive = PrimitiveAlias; export type Primitive = PrimitiveAlias;
 }
@@ -0,0 +1,69 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+import { CBits } from './protocol.sizes';
+
+// injectable
+export class usize extends Primitive<bigint> {
+
+    public static MIN: number = 0;
+    public static MAX: number = Number.MAX_SAFE_INTEGER;
+
+    public static getSignature(): string {
+        return 'usize';
+    }
+
+    // Always 64 bits, regardless of platform
+    public static getSize(): number {
+        return 64 / CBits;
+    }
+
+    public static encode(value: bigint): ArrayBufferLike | Error {
+        const buffer: Buffer = Buffer.alloc(usize.getSize());
+        try {
+            buffer.writeBigUInt64LE(value);
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): bigint | Error {
+        if (bytes.byteLength !== usize.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${usize.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const buffer: Buffer = Buffer.from(bytes);
+            return buffer.readBigUInt64LE(0);
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (typeof value !== 'bigint') {
+            return new Error(`Invalid type of variable`);
+        }
+        if (value < usize.MIN || value > usize.MAX) {
+            return new Error(`Out of range.`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return usize.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return usize.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): bigint | Error {
+        const value = usize.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+}
@@ -0,0 +1,71 @@
+// tslint:disable: class-name
+// tslint:disable: max-classes-per-file
+import { Primitive } from './protocol.primitives.interface';
+
+// injectable
+export class uuid extends Primitive<string> {
+
+    public static PATTERN: RegExp = /^[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}$/i;
+
+    public static getSignature(): string {
+        return 'uuid';
+    }
+
+    // UUID is sent as 16 raw bytes instead of 36 chars string
+    public static getSize(): number {
+        return 16;
+    }
+
+    public static encode(value: string): ArrayBufferLike | Error {
+        if (!uuid.PATTERN.test(value)) {
+            return new Error(`Invalid UUID: ${value}`);
+        }
+        const buffer: Buffer = Buffer.alloc(uuid.getSize());
+        try {
+            buffer.write(value.replace(/-/g, ''), 'hex');
+            return buffer.buffer;
+        } catch (err) {
+            return err;
+        }
+    }
+
+    public static decode(bytes: ArrayBufferLike): string | Error {
+        if (bytes.byteLength !== uuid.getSize()) {
+            return new Error(`Invalid buffer size. Expected ${uuid.getSize()} bytes, actual ${bytes.byteLength} bytes`);
+        }
+        try {
+            const hex: string = Buffer.from(bytes).toString('hex');
+            return `${hex.substr(0, 8)}-${hex.substr(8, 4)}-${hex.substr(12, 4)}-${hex.substr(16, 4)}-${hex.substr(20)}`;
+        } catch (e) {
+            return e;
+        }
+    }
+
+    public static validate(value: any): Error | undefined {
+        if (typeof value !== 'string') {
+            return new Error(`Invalid type of variable`);
+        }
+        if (!uuid.PATTERN.test(value)) {
+            return new Error(`Invalid UUID: ${value}`);
+        }
+        return undefined;
+    }
+
+    public getSignature(): string {
+        return uuid.getSignature();
+    }
+
+    public encode(): ArrayBufferLike | Error {
+        return uuid.encode(this.get());
+    }
+
+    public decode(bytes: ArrayBufferLike): string | Error {
+        const value = uuid.decode(bytes);
+        if (value instanceof Error) {
+            return value;
+        }
+        this.set(value);
+        return value;
+    }
+
+}
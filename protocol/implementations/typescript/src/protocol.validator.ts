@@ -8,6 +8,46 @@ export interface IPropScheme {
     optional?: boolean;
     types?: Required<IValidator>,
     options?: IPropScheme[],
+    constraints?: IConstraints,
+}
+
+export interface IConstraints {
+    minLen?: number;
+    maxLen?: number;
+    pattern?: RegExp;
+    range?: [number | bigint, number | bigint];
+}
+
+function getLength(value: any): number | undefined {
+    if (typeof value === 'string') {
+        return Array.from(value).length;
+    } else if (value instanceof Array || value instanceof Uint8Array) {
+        return value.length;
+    } else if (value instanceof Map) {
+        return value.size;
+    }
+    return undefined;
+}
+
+// Pattern and range are checked for each item of array
+function checkConstraints(prop: string, value: any, constraints: IConstraints): string | undefined {
+    const length: number | undefined = getLength(value);
+    if (constraints.minLen !== undefined && length !== undefined && length < constraints.minLen) {
+        return `Property "${prop}" should have length at least ${constraints.minLen}, but length is ${length}`;
+    }
+    if (constraints.maxLen !== undefined && length !== undefined && length > constraints.maxLen) {
+        return `Property "${prop}" should have length at most ${constraints.maxLen}, but length is ${length}`;
+    }
+    const items: any[] = value instanceof Array ? value : [value];
+    for (const item of items) {
+        if (constraints.pattern !== undefined && !constraints.pattern.test(item)) {
+            return `Property "${prop}" should match pattern ${constraints.pattern.source}, but value is "${item}"`;
+        }
+        if (constraints.range !== undefined && (item < constraints.range[0] || item > constraints.range[1])) {
+            return `Property "${prop}" should be in range ${constraints.range[0]}..${constraints.range[1]}, but value is ${item}`;
+        }
+    }
+    return undefined;
 }
 
 export function validate(obj: any, scheme: IPropScheme[]): Error | undefined {
@@ -22,6 +62,8 @@ export function validate(obj: any, scheme: IPropScheme[]): Error | undefined {
             const err: Error | undefined = property.types.validate(obj[property.prop]);
             if (err instanceof Error) {
                 return err.message;
+            } else if (property.constraints !== undefined) {
+                return checkConstraints(property.prop, obj[property.prop], property.constraints);
             } else {
                 return undefined;
             }
@@ -50,6 +92,9 @@ export function validate(obj: any, scheme: IPropScheme[]): Error | undefined {
             if (options.length > 1) {
                 return `Enum should have only one definition or nothing. Found values for: ${options.join(', ')}`;
             }
+            if (property.constraints !== undefined) {
+                return checkConstraints(property.prop, target, property.constraints);
+            }
             return undefined;
         } else {
             return `Invalid map definition for property ${property.prop}`